@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use geo_svg_io::geo_svg_writer::{ToSvg, ToSvgString};
+use geo_types::{Coordinate, LineString, Polygon};
+
+fn polygon_with_vertices(vertices: usize) -> Polygon<f64> {
+    let exterior: LineString<f64> = (0..=vertices)
+        .map(|i| {
+            let angle = i as f64 / vertices as f64 * std::f64::consts::PI * 2.;
+            Coordinate {
+                x: 500. * angle.cos(),
+                y: 500. * angle.sin(),
+            }
+        })
+        .collect();
+    Polygon::new(exterior, vec![])
+}
+
+fn serialize_polygon(c: &mut Criterion) {
+    let poly = polygon_with_vertices(10_000);
+    c.bench_function("polygon to_svg 10k vertices", |b| {
+        b.iter(|| black_box(&poly).to_svg())
+    });
+    c.bench_function("polygon to_svg_string 10k vertices", |b| {
+        b.iter(|| black_box(&poly).to_svg_string())
+    });
+}
+
+criterion_group!(benches, serialize_polygon);
+criterion_main!(benches);
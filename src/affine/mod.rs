@@ -0,0 +1,259 @@
+extern crate geo_types;
+
+use geo_types::{
+    CoordFloat, CoordNum, Coordinate, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Triangle,
+};
+use std::fmt;
+
+/// A 2D affine transformation, stored as the six values of an SVG `matrix(a b c d e f)`
+///
+/// A coordinate `(x, y)` is mapped to `(a * x + c * y + e, b * x + d * y + f)`. The reader
+/// applies the `transform` attribute of an element with this type, and it is written as an SVG
+/// `matrix(...)` by its `Display` implementation, e.g., for the `<use>` elements of
+/// `geo_svg_writer::svg_defs_with_uses`.
+///
+/// # Examples
+///
+/// Converting from a y-up coordinate system into SVG's y-down one:
+///
+/// ```rust
+/// use geo_svg_io::affine::Affine2;
+/// use geo_types::Coordinate;
+///
+/// let to_svg = Affine2::flip_y(100.0).then(&Affine2::translate(10.0, 0.0));
+/// assert_eq!(Coordinate { x: 15.0, y: 80.0 }, to_svg.apply(Coordinate { x: 5.0, y: 20.0 }));
+/// assert_eq!("matrix(1 0 0 -1 10 100)", to_svg.to_string());
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine2<T: CoordNum> {
+    pub a: T,
+    pub b: T,
+    pub c: T,
+    pub d: T,
+    pub e: T,
+    pub f: T,
+}
+
+impl<T: CoordNum> Affine2<T> {
+    /// Returns the transformation from the six values of an SVG `matrix(a b c d e f)`
+    pub fn new(a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+        Affine2 { a, b, c, d, e, f }
+    }
+
+    /// Returns the transformation leaving every coordinate unchanged
+    pub fn identity() -> Self {
+        Affine2::scale(T::one(), T::one())
+    }
+
+    /// Returns the transformation moving every coordinate by `tx` and `ty`
+    pub fn translate(tx: T, ty: T) -> Self {
+        Affine2::new(T::one(), T::zero(), T::zero(), T::one(), tx, ty)
+    }
+
+    /// Returns the transformation scaling every coordinate by `sx` and `sy` about the origin
+    pub fn scale(sx: T, sy: T) -> Self {
+        Affine2::new(sx, T::zero(), T::zero(), sy, T::zero(), T::zero())
+    }
+
+    /// Returns the transformation mirroring every coordinate within a canvas of the given height,
+    /// mapping `y` to `height - y`, which converts between y-up and SVG's y-down coordinates
+    pub fn flip_y(height: T) -> Self {
+        Affine2::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero() - T::one(),
+            T::zero(),
+            height,
+        )
+    }
+
+    /// Returns the transformation applying this one first and `next` second
+    pub fn then(&self, next: &Affine2<T>) -> Self {
+        Affine2::new(
+            next.a * self.a + next.c * self.b,
+            next.b * self.a + next.d * self.b,
+            next.a * self.c + next.c * self.d,
+            next.b * self.c + next.d * self.d,
+            next.a * self.e + next.c * self.f + next.e,
+            next.b * self.e + next.d * self.f + next.f,
+        )
+    }
+
+    /// Returns the transformed coordinate
+    pub fn apply(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        Coordinate {
+            x: self.a * coord.x + self.c * coord.y + self.e,
+            y: self.b * coord.x + self.d * coord.y + self.f,
+        }
+    }
+
+    /// Returns the Geometry with every coordinate transformed
+    ///
+    /// A `Rect` can be rotated or skewed, so it is returned as a `Polygon`.
+    ///
+    pub fn apply_geometry(&self, geom: &Geometry<T>) -> Geometry<T> {
+        let line_string = |ls: &LineString<T>| {
+            ls.0.iter()
+                .map(|c| self.apply(*c))
+                .collect::<LineString<T>>()
+        };
+        let polygon = |poly: &Polygon<T>| {
+            Polygon::new(
+                line_string(poly.exterior()),
+                poly.interiors().iter().map(line_string).collect(),
+            )
+        };
+        match geom {
+            Geometry::Point(p) => Point(self.apply(p.0)).into(),
+            Geometry::Line(l) => Line::new(self.apply(l.start), self.apply(l.end)).into(),
+            Geometry::LineString(ls) => line_string(ls).into(),
+            Geometry::Polygon(poly) => polygon(poly).into(),
+            Geometry::MultiPoint(mp) => {
+                MultiPoint(mp.0.iter().map(|p| Point(self.apply(p.0))).collect()).into()
+            }
+            Geometry::MultiLineString(mls) => {
+                MultiLineString(mls.0.iter().map(line_string).collect()).into()
+            }
+            Geometry::MultiPolygon(mp) => MultiPolygon(mp.0.iter().map(polygon).collect()).into(),
+            Geometry::GeometryCollection(gc) => Geometry::GeometryCollection(GeometryCollection(
+                gc.0.iter().map(|g| self.apply_geometry(g)).collect(),
+            )),
+            Geometry::Rect(r) => polygon(&r.to_polygon()).into(),
+            Geometry::Triangle(t) => {
+                Triangle(self.apply(t.0), self.apply(t.1), self.apply(t.2)).into()
+            }
+        }
+    }
+}
+
+impl<T: CoordFloat> Affine2<T> {
+    /// Returns the transformation rotating every coordinate by `angle` (in radians) about the
+    /// origin, which is clockwise on screen since SVG is y-down
+    pub fn rotate(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Affine2::new(cos, sin, -sin, cos, T::zero(), T::zero())
+    }
+}
+
+impl<T: CoordNum> Default for Affine2<T> {
+    fn default() -> Self {
+        Affine2::identity()
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for Affine2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "matrix({} {} {} {} {} {})",
+            self.a, self.b, self.c, self.d, self.e, self.f
+        )
+    }
+}
+
+/** Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::{line_string, Rect};
+
+    fn assert_coord_eq(expected: Coordinate<f64>, actual: Coordinate<f64>) {
+        assert!((expected.x - actual.x).abs() < 1e-9, "{:?}", actual);
+        assert!((expected.y - actual.y).abs() < 1e-9, "{:?}", actual);
+    }
+
+    #[test]
+    fn can_apply_constructors() {
+        let coord = Coordinate { x: 2.0, y: 3.0 };
+        assert_eq!(coord, Affine2::identity().apply(coord));
+        assert_eq!(
+            Coordinate { x: 12.0, y: 1.0 },
+            Affine2::translate(10.0, -2.0).apply(coord)
+        );
+        assert_eq!(
+            Coordinate { x: 4.0, y: -9.0 },
+            Affine2::scale(2.0, -3.0).apply(coord)
+        );
+        assert_eq!(
+            Coordinate { x: 2.0, y: 97.0 },
+            Affine2::flip_y(100.0).apply(coord)
+        );
+        assert_coord_eq(
+            Coordinate { x: -3.0, y: 2.0 },
+            Affine2::rotate(std::f64::consts::FRAC_PI_2).apply(coord),
+        );
+        assert_eq!(
+            Affine2::new(1, 0, 0, 1, 5, 6).apply(Coordinate { x: 1, y: 2 }),
+            Coordinate { x: 6, y: 8 }
+        );
+    }
+
+    #[test]
+    fn can_compose_in_order() {
+        let coord = Coordinate { x: 1.0, y: 0.0 };
+        let translate = Affine2::translate(10.0, 0.0);
+        let scale = Affine2::scale(2.0, 2.0);
+
+        // Translating first also scales the translation
+        assert_eq!(
+            Coordinate { x: 22.0, y: 0.0 },
+            translate.then(&scale).apply(coord)
+        );
+        assert_eq!(
+            Coordinate { x: 12.0, y: 0.0 },
+            scale.then(&translate).apply(coord)
+        );
+
+        let rotate = Affine2::rotate(std::f64::consts::FRAC_PI_2);
+        assert_coord_eq(
+            Coordinate { x: 0.0, y: 11.0 },
+            translate.then(&rotate).apply(coord),
+        );
+        assert_coord_eq(
+            Coordinate { x: 10.0, y: 1.0 },
+            rotate.then(&translate).apply(coord),
+        );
+
+        // Composing applies exactly as applying one after the other
+        let flip = Affine2::flip_y(50.0);
+        let composed = rotate.then(&scale).then(&flip);
+        assert_coord_eq(
+            flip.apply(scale.apply(rotate.apply(coord))),
+            composed.apply(coord),
+        );
+        assert_eq!(scale, Affine2::identity().then(&scale));
+        assert_eq!(scale, scale.then(&Affine2::identity()));
+    }
+
+    #[test]
+    fn can_apply_to_geometry() {
+        let flip = Affine2::flip_y(10.0);
+        let line = Geometry::LineString(line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 2.0)]);
+        assert_eq!(
+            Geometry::LineString(line_string![(x: 0.0, y: 10.0), (x: 5.0, y: 8.0)]),
+            flip.apply_geometry(&line)
+        );
+
+        // A Rect is transformed as its Polygon
+        let rect = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 2.0, y: 1.0 });
+        assert_eq!(
+            flip.apply_geometry(&Geometry::Polygon(rect.to_polygon())),
+            flip.apply_geometry(&Geometry::Rect(rect))
+        );
+    }
+
+    #[test]
+    fn can_format_as_svg_matrix() {
+        assert_eq!(
+            "matrix(2 0 0 -1 0.5 10)",
+            Affine2::scale(2.0, 1.0)
+                .then(&Affine2::flip_y(10.0))
+                .then(&Affine2::translate(0.5, 0.0))
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+
+/// The rule deciding which parts of a shape with overlapping or nested rings are inside it
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FillRule {
+    /// Inside if the rings around it do not wind the same number of times in both directions
+    #[default]
+    NonZero,
+    /// Inside if there is an odd number of rings around it
+    EvenOdd,
+}
+
+impl fmt::Display for FillRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FillRule::NonZero => write!(f, "nonzero"),
+            FillRule::EvenOdd => write!(f, "evenodd"),
+        }
+    }
+}
+
+/// A unit of length that can be attached to the `width` and `height` of an SVG document
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SvgUnit {
+    Px,
+    In,
+    Cm,
+    Mm,
+    Pt,
+    Pc,
+    Em,
+    Ex,
+    Percent,
+}
+
+impl fmt::Display for SvgUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let suffix = match self {
+            SvgUnit::Px => "px",
+            SvgUnit::In => "in",
+            SvgUnit::Cm => "cm",
+            SvgUnit::Mm => "mm",
+            SvgUnit::Pt => "pt",
+            SvgUnit::Pc => "pc",
+            SvgUnit::Em => "em",
+            SvgUnit::Ex => "ex",
+            SvgUnit::Percent => "%",
+        };
+        write!(f, "{}", suffix)
+    }
+}
+
+/// The physical size of an SVG document, as given by the `width` and `height` of its root
+/// `<svg>` element, which the `viewBox` is stretched to fill
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgSize<T> {
+    pub width: T,
+    pub height: T,
+    /// The unit of `width` and `height`, which are unitless user units without one
+    pub unit: Option<SvgUnit>,
+}
@@ -2,22 +2,37 @@ extern crate geo_booleanop;
 extern crate geo_normalized;
 extern crate geo_types;
 
+use crate::affine::Affine2;
+pub use crate::attributes::SvgUnit;
+use crate::attributes::{FillRule, SvgSize};
+#[cfg(feature = "serde")]
+use crate::geo_svg_writer::ToSvg;
+use crate::geo_svg_writer::ToSvgString;
 use flo_curves::bezier::{de_casteljau3, de_casteljau4};
 use flo_curves::{Coord2, Coordinate2D};
 use geo_types::{
-    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPolygon,
-    Polygon, Rect,
+    CoordFloat, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use std::collections::{HashMap, HashSet};
 use std::convert::From;
 use std::fmt;
-use svgtypes::{PathParser, PathSegment, PointsParser};
+use std::io::Read;
+use std::str::FromStr;
+use svgtypes::{PathParser, PathSegment, Transform};
+use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
+#[derive(Debug)]
 pub enum SvgError {
     ParseError(std::num::ParseFloatError),
     SvgInvalidType(SvgUnsupportedGeometryTypeError),
     SvgGeomCollectionForGeometry(SvgGeometryCollectionForGeometryError),
     InvalidSvgError(InvalidSvgError),
+    LossyOperation { what: String },
+    PathParseError(svgtypes::Error),
+    InvalidOption { what: String },
+    CoordinateOverflow { value: f64 },
 }
 
 impl From<std::num::ParseFloatError> for SvgError {
@@ -26,12 +41,74 @@ impl From<std::num::ParseFloatError> for SvgError {
     }
 }
 
-pub struct SvgUnsupportedGeometryTypeError;
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // user-facing output
+        match self {
+            SvgError::ParseError(error) => write!(f, "A number in the SVG is invalid: {}", error),
+            SvgError::SvgInvalidType(error) => write!(
+                f,
+                "The SVG could not be parsed to a valid Geometry type: {}",
+                error
+            ),
+            SvgError::SvgGeomCollectionForGeometry(error) => write!(f, "{}", error),
+            SvgError::InvalidSvgError(error) => write!(f, "The SVG input is invalid: {}", error),
+            SvgError::LossyOperation { what } => {
+                write!(f, "The SVG could not be parsed without {}", what)
+            }
+            SvgError::PathParseError(error) => write!(f, "The SVG path data is invalid: {}", error),
+            SvgError::InvalidOption { what } => {
+                write!(f, "The parse options are invalid: {}", what)
+            }
+            SvgError::CoordinateOverflow { value } => write!(
+                f,
+                "The number {:e} does not fit into the coordinate type",
+                value
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SvgError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SvgError::ParseError(error) => Some(error),
+            SvgError::PathParseError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl SvgError {
+    // Names the element an InvalidSvgError occurred in, unless that is already known
+    fn on_element(self, element: &str) -> Self {
+        match self {
+            SvgError::InvalidSvgError(error) if error.element.is_none() => {
+                SvgError::InvalidSvgError(InvalidSvgError {
+                    element: Some(element.into()),
+                    ..error
+                })
+            }
+            _ => self,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgUnsupportedGeometryTypeError {
+    /// The first element that is not a supported shape, or `None` if the input has no elements
+    /// besides \<svg\> and \<g\> containers
+    pub element: Option<String>,
+}
 
 // Implement std::fmt::Display for AppError
 impl fmt::Display for SvgUnsupportedGeometryTypeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "The SVG could not be parsed to a valid Geometry type") // user-facing output
+        // user-facing output
+        match &self.element {
+            Some(element) => write!(f, "unsupported element <{}>", element),
+            None => write!(f, "no shape element found"),
+        }
     }
 }
 
@@ -45,40 +122,70 @@ impl fmt::Display for SvgGeometryCollectionForGeometryError {
 }
 
 // Implement std::fmt::Debug for AppError
-impl fmt::Debug for SvgUnsupportedGeometryTypeError {
+impl fmt::Debug for SvgGeometryCollectionForGeometryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{{ file: {}, line: {} }}", file!(), line!()) // programmer-facing output
     }
 }
 
-pub struct InvalidSvgError;
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidSvgError {
+    /// The element that is invalid, if the error occurred in one
+    pub element: Option<String>,
+    /// The attribute that is invalid, if the error occurred in one
+    pub attribute: Option<String>,
+    /// What is wrong, e.g., `missing` for an attribute
+    pub reason: String,
+}
 
-// Implement std::fmt::Display for AppError
-impl fmt::Display for InvalidSvgError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "The SVG input is invalid") // user-facing output
+impl InvalidSvgError {
+    fn new(reason: &str) -> Self {
+        InvalidSvgError {
+            element: None,
+            attribute: None,
+            reason: reason.into(),
+        }
+    }
+
+    fn attribute(attribute: &str, reason: &str) -> Self {
+        InvalidSvgError {
+            attribute: Some(attribute.into()),
+            ..InvalidSvgError::new(reason)
+        }
     }
 }
 
-// Implement std::fmt::Debug for AppError
-impl fmt::Debug for InvalidSvgError {
+// Implement std::fmt::Display for AppError
+impl fmt::Display for InvalidSvgError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{{ file: {}, line: {} }}", file!(), line!()) // programmer-facing output
+        // user-facing output, e.g., `missing attribute "width" on <rect>`
+        write!(f, "{}", self.reason)?;
+        if let Some(attribute) = &self.attribute {
+            write!(f, " attribute \"{}\"", attribute)?;
+        }
+        if let Some(element) = &self.element {
+            write!(f, " on <{}>", element)?;
+        }
+        Ok(())
     }
 }
 
 /// Returns a GeometryCollection parsed from the submitted SVG element
 ///
-/// **Note** this function does not parse a full SVG string (e.g., `<svg xmlns="http://www.w3.org/2000/svg"><path d="M0 0L10 0L10 10L0 10Z"/></svg>`), it only parses the individual shape elements (e.g., `<path d="M0 0L10 0L10 10L0 10Z"/>`).  The following SVG elements are supported and produce the specified Geometry types:
+/// **Note** this function does not parse a full SVG string (e.g., `<svg xmlns="http://www.w3.org/2000/svg"><path d="M0 0L10 0L10 10L0 10Z"/></svg>`), it only parses the individual shape elements (e.g., `<path d="M0 0L10 0L10 10L0 10Z"/>`); use `svg_document_to_geometry_collection` to parse every shape in a full SVG.  The following SVG elements are supported and produce the specified Geometry types:
 ///
 /// * \<path\> &rarr; GeometryCollection
 /// * \<polygon\> &rarr; GeometryCollection with a single Polygon
 /// * \<polyline\> &rarr; GeometryCollection with a single LineString
 /// * \<rect\> &rarr; GeometryCollection with a single Polygon
 /// * \<line\> &rarr; GeometryCollection with a single Line
+/// * \<circle\> &rarr; GeometryCollection with a single Polygon approximating the circle
+/// * \<ellipse\> &rarr; GeometryCollection with a single Polygon approximating the ellipse
+///
+/// A `transform` attribute on the element is applied to all of its coordinates.
 ///
-/// **Note** also that the current parsing of curves in a `<path>`is rather simple right now,
-/// it just finds 100 points along the curve.
+/// **Note** also that curves in a `<path>` are flattened into straight line segments that lie
+/// within 0.1 units of the true curve.
 ///
 /// # Examples
 ///
@@ -153,127 +260,651 @@ impl fmt::Debug for InvalidSvgError {
 /// ```
 ///
 pub fn svg_to_geometry_collection(svg: &str) -> Result<GeometryCollection<f64>, SvgError> {
-    let parser = EventReader::new(svg.as_bytes());
+    svg_to_geometry_collection_from_reader(svg.as_bytes())
+}
+
+/// Returns a GeometryCollection parsed from the submitted SVG element, using the supplied
+/// `ParseOptions`
+///
+/// This parses the same elements as `svg_to_geometry_collection`. With `prefer_rect` set,
+/// polygons that are axis-aligned rectangles (whether from a \<path\>, \<polygon\> or
+/// \<rect\>) are returned as a `Rect`.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::{ Coordinate, Geometry, Rect };
+/// use geo_svg_io::geo_svg_reader::{svg_to_geometry_collection_with_options, ParseOptions};
+///
+/// let mut options = ParseOptions::default();
+/// options.prefer_rect = true;
+///
+/// let parsed_svg = svg_to_geometry_collection_with_options(r#"<path d="M0 0H60V30H0Z"/>"#, &options);
+/// assert!(parsed_svg.is_ok());
+///
+/// let geom = parsed_svg.ok().unwrap();
+/// let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 60., y: 30. });
+/// assert_eq!(Geometry::Rect(rect), geom.0[0]);
+/// ```
+///
+pub fn svg_to_geometry_collection_with_options(
+    svg: &str,
+    options: &ParseOptions,
+) -> Result<GeometryCollection<f64>, SvgError> {
+    let (geom, _) = parse_first_element(svg.as_bytes(), options)?;
+    Ok(geom)
+}
+
+/// Returns a GeometryCollection parsed from the first SVG element read from `reader`
+///
+/// This parses the same elements as `svg_to_geometry_collection`, but streams the XML from any
+/// `std::io::Read` source (e.g., a `File`), so the input does not have to be read into a
+/// `String` first. Reading stops at the first supported element.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use geo_svg_io::geo_svg_reader::svg_to_geometry_collection_from_reader;
+///
+/// let reader = Cursor::new(r#"<polygon points="0, 0 60, 0 60, 60 0, 60 0, 0"/>"#);
+///
+/// let parsed_svg = svg_to_geometry_collection_from_reader(reader);
+/// assert!(parsed_svg.is_ok());
+///
+/// let geom = parsed_svg.ok().unwrap();
+/// assert_eq!(1, geom.0.len());
+/// assert!(geom.0[0].clone().into_polygon().is_some());
+/// ```
+///
+pub fn svg_to_geometry_collection_from_reader<R: Read>(
+    reader: R,
+) -> Result<GeometryCollection<f64>, SvgError> {
+    let (geom, _) = parse_first_element(reader, &ParseOptions::default())?;
+    Ok(geom)
+}
+
+/// A summary of what happened while parsing SVG input
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseReport {
+    /// The number of Geometries produced
+    pub shapes: usize,
+    /// The number of Bézier curves that were flattened into coordinates
+    pub curves_flattened: usize,
+    /// The total number of coordinates in the produced Geometries
+    pub vertices: usize,
+    /// Anything that was skipped or could not be represented (e.g., "skipped unsupported element
+    /// \<text\>")
+    pub warnings: Vec<String>,
+}
+
+/// Returns a GeometryCollection parsed from the submitted SVG element, along with a
+/// `ParseReport` describing the parse
+///
+/// This parses the same elements as `svg_to_geometry_collection`, but additionally reports how
+/// many shapes and vertices were produced, how many curves were flattened, and a warning for
+/// every element that was skipped on the way to the first supported shape.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_to_geometry_collection_reported;
+///
+/// let svg_string = r#"<svg><text>Label</text><path d="M0 0C0 10 10 10 10 0"/></svg>"#;
+///
+/// let parsed_svg = svg_to_geometry_collection_reported(svg_string);
+/// assert!(parsed_svg.is_ok());
+///
+/// let (geom, report) = parsed_svg.ok().unwrap();
+/// assert_eq!(1, geom.0.len());
+/// assert_eq!(1, report.shapes);
+/// assert_eq!(1, report.curves_flattened);
+/// assert_eq!(17, report.vertices);
+/// assert_eq!(vec![String::from("skipped unsupported element <text>")], report.warnings);
+/// ```
+///
+pub fn svg_to_geometry_collection_reported(
+    svg: &str,
+) -> Result<(GeometryCollection<f64>, ParseReport), SvgError> {
+    parse_first_element(svg.as_bytes(), &ParseOptions::default())
+}
+
+fn parse_first_element<R: Read>(
+    reader: R,
+    options: &ParseOptions,
+) -> Result<(GeometryCollection<f64>, ParseReport), SvgError> {
+    let mut report = ParseReport::default();
+    let mut unsupported: Option<String> = None;
+    // The options with the viewport of the root <svg>, if it sets the one they lack
+    let mut root_options: Option<ParseOptions> = None;
+    let parser = EventReader::new(reader);
     for e in parser {
         if let Ok(XmlEvent::StartElement {
             name, attributes, ..
         }) = e
         {
-            // An SVG path element
-            if name.local_name == "path" {
-                for attr in attributes {
-                    if attr.name.local_name == "d" {
-                        let res = svg_d_path_to_geometry_collection(&attr.value)?;
-                        return Ok(res);
-                    }
-                }
+            if name.local_name == "svg" && options.viewport.is_none() && root_options.is_none() {
+                root_options = root_viewport(&attributes).map(|viewport| ParseOptions {
+                    viewport: Some(viewport),
+                    ..options.clone()
+                });
             }
-            // An SVG polygon
-            else if name.local_name == "polygon" {
-                for attr in attributes {
-                    if attr.name.local_name == "points" {
-                        let res = svg_polygon_to_geometry(&attr.value)?;
-                        return Ok(res.into());
-                    }
-                }
+            if let Some(res) = svg_element_to_geometry_collection(
+                &name.local_name,
+                attributes,
+                root_options.as_ref().unwrap_or(options),
+                &mut report,
+            )? {
+                report.shapes = res.0.len();
+                report.vertices = res.0.iter().map(count_vertices).sum();
+                return Ok((res, report));
             }
-            // An SVG polyline
-            else if name.local_name == "polyline" {
-                for attr in attributes {
-                    if attr.name.local_name == "points" {
-                        let res = svg_polyline_to_geometry(&attr.value)?;
-                        return Ok(res.into());
-                    }
-                }
+            if unsupported.is_none() && name.local_name != "svg" && name.local_name != "g" {
+                unsupported = Some(name.local_name);
             }
-            // An SVG rect
-            else if name.local_name == "rect" {
-                let mut x: Option<f64> = None;
-                let mut y: Option<f64> = None;
-                let mut width: Option<f64> = None;
-                let mut height: Option<f64> = None;
+        }
+    }
 
-                for attr in attributes {
-                    if attr.name.local_name == "x" {
-                        let x_val = attr.value.parse::<f64>()?;
-                        x = Some(x_val);
-                    } else if attr.name.local_name == "y" {
-                        let y_val = attr.value.parse::<f64>()?;
-                        y = Some(y_val);
-                    } else if attr.name.local_name == "width" {
-                        let width_val = attr.value.parse::<f64>()?;
-                        width = Some(width_val);
-                    } else if attr.name.local_name == "height" {
-                        let height_val = attr.value.parse::<f64>()?;
-                        height = Some(height_val);
-                    }
-                }
+    Err(SvgError::SvgInvalidType(SvgUnsupportedGeometryTypeError {
+        element: unsupported,
+    }))
+}
 
-                if x.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
-                }
-                if y.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
-                }
-                if width.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
+fn svg_element_to_geometry_collection(
+    name: &str,
+    attributes: Vec<OwnedAttribute>,
+    options: &ParseOptions,
+    report: &mut ParseReport,
+) -> Result<Option<GeometryCollection<f64>>, SvgError> {
+    let attrs = attributes
+        .into_iter()
+        .map(|attr| (attr.name.local_name, attr.value))
+        .collect::<HashMap<String, String>>();
+    attrs_to_geometry_collection(name, &attrs, options, report).map_err(|e| e.on_element(name))
+}
+
+fn attrs_to_geometry_collection(
+    name: &str,
+    attrs: &HashMap<String, String>,
+    options: &ParseOptions,
+    report: &mut ParseReport,
+) -> Result<Option<GeometryCollection<f64>>, SvgError> {
+    let normalized: HashMap<String, String>;
+    let attrs = if options.normalize_unicode {
+        normalized = attrs
+            .iter()
+            .map(|(k, v)| (k.clone(), normalize_unicode(v)))
+            .collect();
+        &normalized
+    } else {
+        attrs
+    };
+    check_curve_flattening(&options.curve_flattening)?;
+    // A transform applies to every shape element, so it is read before building any geometry
+    let transform = match attrs.get("transform") {
+        Some(value) => Some(parse_transform(value)?),
+        None => None,
+    };
+
+    // An SVG path element
+    let geom: Option<GeometryCollection<f64>> = if name == "path" {
+        match shape_attr(attrs, name, "d", options, report)? {
+            Some(d) => {
+                let fill_rule = match presentation_attr(attrs, "fill-rule") {
+                    Some("nonzero") => FillRule::NonZero,
+                    Some("evenodd") => FillRule::EvenOdd,
+                    _ => options.fill_rule,
+                };
+                let options = ParseOptions {
+                    fill_rule,
+                    ..options.clone()
+                };
+                Some(parse_d_path(d, &options, report)?)
+            }
+            None => None,
+        }
+    }
+    // An SVG polygon
+    else if name == "polygon" {
+        match shape_attr(attrs, name, "points", options, report)? {
+            Some(points) => Some(svg_polygon_to_geometry(points)?.into()),
+            None => None,
+        }
+    }
+    // An SVG polyline
+    else if name == "polyline" {
+        match shape_attr(attrs, name, "points", options, report)? {
+            Some(points) => {
+                let mut line = svg_polyline_to_geometry(points)?;
+                if options.close_coincident_polylines && is_coincidently_closed(&line) {
+                    // Snap the end onto the start, so the ring is closed exactly
+                    let last = line.0.len() - 1;
+                    line.0[last] = line.0[0];
+                    Some(Polygon::new(line, vec![]).into())
+                } else {
+                    Some(line.into())
                 }
-                if height.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
+            }
+            None => None,
+        }
+    }
+    // An SVG rect, whose missing corner coordinates default to 0
+    else if name == "rect" {
+        let x = optional_f64_attr(attrs, "x", options)?;
+        let y = optional_f64_attr(attrs, "y", options)?;
+        let width = rect_size_attr(attrs, "width", options)?;
+        let height = rect_size_attr(attrs, "height", options)?;
+        // A rect without an area is not rendered
+        if width == 0. || height == 0. {
+            return Ok(Some(GeometryCollection(vec![])));
+        }
+        let rect = match rect_corner_radii(attrs, width, height, options)? {
+            Some((rx, ry)) => {
+                if options.lossless {
+                    return Err(SvgError::LossyOperation {
+                        what: String::from("flattening the rounded corners of a <rect>"),
+                    });
                 }
-                let rect =
-                    svg_rect_to_geometry(x.unwrap(), y.unwrap(), width.unwrap(), height.unwrap())?;
-
-                return Ok(rect.into());
+                svg_rounded_rect_to_geometry(
+                    x,
+                    y,
+                    width,
+                    height,
+                    (rx, ry),
+                    &options.curve_flattening,
+                )
             }
-            // An SVG line
-            else if name.local_name == "line" {
-                let mut start_x: Option<f64> = None;
-                let mut start_y: Option<f64> = None;
-                let mut end_x: Option<f64> = None;
-                let mut end_y: Option<f64> = None;
+            None => svg_rect_to_geometry(x, y, width, height),
+        };
 
-                for attr in attributes {
-                    if attr.name.local_name == "x1" {
-                        let start_x_val = attr.value.parse::<f64>()?;
-                        start_x = Some(start_x_val);
-                    } else if attr.name.local_name == "y1" {
-                        let start_y_val = attr.value.parse::<f64>()?;
-                        start_y = Some(start_y_val);
-                    } else if attr.name.local_name == "x2" {
-                        let end_x_val = attr.value.parse::<f64>()?;
-                        end_x = Some(end_x_val);
-                    } else if attr.name.local_name == "y2" {
-                        let end_y_val = attr.value.parse::<f64>()?;
-                        end_y = Some(end_y_val);
-                    }
-                }
+        Some(rect.into())
+    }
+    // An SVG circle
+    else if name == "circle" {
+        if options.lossless {
+            return Err(SvgError::LossyOperation {
+                what: String::from("flattening a <circle>"),
+            });
+        }
+        let r = required_f64_attr(attrs, "r", options)?;
+        let cx = optional_f64_attr(attrs, "cx", options)?;
+        let cy = optional_f64_attr(attrs, "cy", options)?;
+        Some(svg_circle_to_geometry(cx, cy, r, &options.curve_flattening)?.into())
+    }
+    // An SVG ellipse
+    else if name == "ellipse" {
+        if options.lossless {
+            return Err(SvgError::LossyOperation {
+                what: String::from("flattening an <ellipse>"),
+            });
+        }
+        let rx = required_f64_attr(attrs, "rx", options)?;
+        let ry = required_f64_attr(attrs, "ry", options)?;
+        let cx = optional_f64_attr(attrs, "cx", options)?;
+        let cy = optional_f64_attr(attrs, "cy", options)?;
+        Some(svg_ellipse_to_geometry(cx, cy, rx, ry, &options.curve_flattening)?.into())
+    }
+    // An SVG line, whose missing endpoint coordinates default to 0
+    else if name == "line" {
+        let line = svg_line_to_geometry(
+            &optional_f64_attr(attrs, "x1", options)?,
+            &optional_f64_attr(attrs, "y1", options)?,
+            &optional_f64_attr(attrs, "x2", options)?,
+            &optional_f64_attr(attrs, "y2", options)?,
+        );
+        // A line that ends where it starts has no extent, the same as a degenerate path subpath
+        if line.start == line.end {
+            return Ok(Some(GeometryCollection(vec![])));
+        }
+        Some(line.into())
+    } else {
+        // Containers hold the shapes, so are not skipped as such
+        if name != "svg" && name != "g" {
+            report
+                .warnings
+                .push(format!("skipped unsupported element <{}>", name));
+        }
+        None
+    };
+    if options.validate {
+        if let Some(geom) = &geom {
+            validate_polygons(geom)?;
+        }
+    }
 
-                if start_x.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
-                }
-                if start_y.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
+    Ok(geom.map(|geom| {
+        let geom = match transform {
+            Some(transform) => GeometryCollection(
+                geom.0
+                    .into_iter()
+                    .map(|g| transform.apply_geometry(&g))
+                    .collect(),
+            ),
+            None => geom,
+        };
+        let unfilled = presentation_attr(attrs, "fill") == Some("none");
+        if options.unfilled_as_lines && unfilled {
+            return polygons_to_outlines(geom);
+        }
+        prefer_rects(geom, options)
+    }))
+}
+
+// Checks that every ring of the Polygons is closed and has at least 4 coordinates, as expected
+// by `geo` algorithms
+fn validate_polygons(geom: &GeometryCollection<f64>) -> Result<(), SvgError> {
+    let polygons = geom.0.iter().flat_map(|g| match g {
+        Geometry::Polygon(poly) => vec![poly],
+        Geometry::MultiPolygon(polys) => polys.0.iter().collect(),
+        _ => vec![],
+    });
+    for poly in polygons {
+        validate_ring(poly.exterior(), "exterior ring")?;
+        for (i, interior) in poly.interiors().iter().enumerate() {
+            validate_ring(interior, &format!("interior ring {}", i))?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_ring(ring: &LineString<f64>, which: &str) -> Result<(), SvgError> {
+    let reason = if ring.0.len() < 4 {
+        format!(
+            "Polygon {} with {} coordinates (at least 4 are needed)",
+            which,
+            ring.0.len()
+        )
+    } else if ring.0.first() != ring.0.last() {
+        format!("Polygon {} that is not closed", which)
+    } else {
+        return Ok(());
+    };
+    Err(SvgError::InvalidSvgError(InvalidSvgError::new(&reason)))
+}
+
+// Replaces every Polygon with the LineStrings of its rings
+fn polygons_to_outlines(geom: GeometryCollection<f64>) -> GeometryCollection<f64> {
+    let rings = |poly: Polygon<f64>| {
+        let (exterior, interiors) = poly.into_inner();
+        std::iter::once(exterior).chain(interiors)
+    };
+    GeometryCollection(
+        geom.0
+            .into_iter()
+            .map(|g| match g {
+                Geometry::Polygon(poly) => map_line_strings_to_geometry(&rings(poly).collect()),
+                Geometry::MultiPolygon(polys) => {
+                    map_line_strings_to_geometry(&polys.0.into_iter().flat_map(rings).collect())
                 }
-                if end_x.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
+                _ => g,
+            })
+            .collect(),
+    )
+}
+
+// Replaces Unicode lookalikes of the minus sign and of whitespace with their ASCII equivalents,
+// as commonly introduced by copying SVG through a word processor
+fn normalize_unicode(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, '\u{200B}' | '\u{FEFF}'))
+        .map(|c| match c {
+            '\u{2212}' | '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}'
+            | '\u{FE63}' | '\u{FF0D}' => '-',
+            '\u{00A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => ' ',
+            _ => c,
+        })
+        .collect()
+}
+
+// The attribute holding the shape of an element, e.g., the `d` of a <path>. Without it, the
+// element is an error, or skipped with a warning in lenient mode.
+fn shape_attr<'a>(
+    attrs: &'a HashMap<String, String>,
+    element: &str,
+    name: &str,
+    options: &ParseOptions,
+    report: &mut ParseReport,
+) -> Result<Option<&'a String>, SvgError> {
+    match attrs.get(name) {
+        Some(value) => Ok(Some(value)),
+        None if options.lenient => {
+            report.warnings.push(format!(
+                "skipped <{}> without a {} attribute",
+                element, name
+            ));
+            Ok(None)
+        }
+        None => Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+            name, "missing",
+        ))),
+    }
+}
+
+// The value of a presentation attribute such as `fill-rule`, which a declaration of the same
+// property in the `style` attribute overrides
+fn presentation_attr<'a>(attrs: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    // The last declaration of a property wins
+    let declared = attrs.get("style").and_then(|style| {
+        style
+            .rsplit(';')
+            .filter_map(|declaration| {
+                let (property, value) = declaration.split_once(':')?;
+                Some((property.trim(), value.trim()))
+            })
+            .find(|(property, _)| *property == name)
+            .map(|(_, value)| value)
+    });
+    declared.or_else(|| attrs.get(name).map(|value| value.trim()))
+}
+
+fn parse_transform(value: &str) -> Result<Affine2<f64>, SvgError> {
+    let t = value.parse::<Transform>().map_err(|_| {
+        SvgError::InvalidSvgError(InvalidSvgError::attribute("transform", "invalid"))
+    })?;
+    Ok(Affine2::new(t.a, t.b, t.c, t.d, t.e, t.f))
+}
+
+fn required_f64_attr(
+    attrs: &HashMap<String, String>,
+    name: &str,
+    options: &ParseOptions,
+) -> Result<f64, SvgError> {
+    match attrs.get(name) {
+        Some(value) => {
+            // Attribute values may be padded with whitespace. Exponents (`1e2`) and a missing
+            // leading zero (`.5`) are valid numbers for Rust as well as for SVG.
+            let value = value.trim();
+            if value.is_empty() {
+                return Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+                    name, "empty",
+                )));
+            }
+            // A length with a unit would have to be converted to user units, losing the unit
+            if options.lossless {
+                if let Ok(length) = value.parse::<svgtypes::Length>() {
+                    if length.unit != svgtypes::LengthUnit::None {
+                        return Err(SvgError::LossyOperation {
+                            what: format!("converting the {} length {} to user units", name, value),
+                        });
+                    }
                 }
-                if end_y.is_none() {
-                    return Err(SvgError::InvalidSvgError(InvalidSvgError));
+            }
+            // `px` are user units, and percentages are of the viewport width (for horizontal
+            // attributes), height (for vertical ones), or normalized diagonal (for `r`)
+            if let Some(number) = value.strip_suffix("px") {
+                Ok(number.parse::<f64>()?)
+            } else if let Some(number) = value.strip_suffix('%') {
+                let percent = number.parse::<f64>()?;
+                match options.viewport {
+                    Some((width, height)) => {
+                        Ok(percent / 100. * viewport_length(name, width, height))
+                    }
+                    None => Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+                        name,
+                        "percentage without a viewport in",
+                    ))),
                 }
-
-                return Ok(svg_line_to_geometry(
-                    &start_x.unwrap(),
-                    &start_y.unwrap(),
-                    &end_x.unwrap(),
-                    &end_y.unwrap(),
-                )
-                .into());
+            } else {
+                Ok(value.parse::<f64>()?)
             }
         }
+        None => Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+            name, "missing",
+        ))),
+    }
+}
+
+// The viewport established by a root <svg>, against which percentages are resolved: the size of
+// its viewBox, or else its width and height if both are in user units
+fn root_viewport(attributes: &[OwnedAttribute]) -> Option<(f64, f64)> {
+    let attr = |name: &str| {
+        attributes
+            .iter()
+            .find(|attr| attr.name.local_name == name)
+            .map(|attr| attr.value.as_str())
+    };
+    if let Some(view_box) =
+        attr("viewBox").and_then(|value| value.parse::<svgtypes::ViewBox>().ok())
+    {
+        return Some((view_box.w, view_box.h));
+    }
+    let user_units = |name: &str| {
+        let length = attr(name)?.parse::<svgtypes::Length>().ok()?;
+        match length.unit {
+            svgtypes::LengthUnit::None | svgtypes::LengthUnit::Px => Some(length.num),
+            _ => None,
+        }
+    };
+    Some((user_units("width")?, user_units("height")?))
+}
+
+// The viewport length that a percentage in the attribute is relative to
+fn viewport_length(name: &str, width: f64, height: f64) -> f64 {
+    match name {
+        "y" | "cy" | "y1" | "y2" | "height" | "ry" => height,
+        "r" => ((width * width + height * height) / 2.).sqrt(),
+        _ => width,
+    }
+}
+
+// Missing coordinates default to 0, as specified for e.g. `cx` and `cy`
+fn optional_f64_attr(
+    attrs: &HashMap<String, String>,
+    name: &str,
+    options: &ParseOptions,
+) -> Result<f64, SvgError> {
+    if attrs.contains_key(name) {
+        required_f64_attr(attrs, name, options)
+    } else {
+        Ok(0.)
+    }
+}
+
+/// Returns a GeometryCollection for an SVG element that has already been parsed into its name
+/// and a map of its attributes
+///
+/// This allows geometry to be built from the elements of another SVG or XML library without
+/// serializing them again. The element names and attributes supported by
+/// `svg_to_geometry_collection` produce the same Geometry types here, any other element
+/// produces an `SvgUnsupportedGeometryTypeError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use geo_types::line_string;
+/// use geo_svg_io::geo_svg_reader::element_attrs_to_geometry;
+///
+/// let mut attrs = HashMap::new();
+/// attrs.insert(String::from("points"), String::from("0,0 0,60 60,60"));
+///
+/// let parsed_svg = element_attrs_to_geometry("polyline", &attrs);
+/// assert!(parsed_svg.is_ok());
+///
+/// let geom = parsed_svg.ok().unwrap();
+/// let ls = geom.0[0].clone().into_line_string();
+/// assert_eq!(Some(line_string![(x: 0., y: 0.), (x: 0., y: 60.), (x: 60., y: 60.)]), ls);
+/// ```
+///
+pub fn element_attrs_to_geometry(
+    name: &str,
+    attrs: &HashMap<String, String>,
+) -> Result<GeometryCollection<f64>, SvgError> {
+    match attrs_to_geometry_collection(
+        name,
+        attrs,
+        &ParseOptions::default(),
+        &mut ParseReport::default(),
+    )
+    .map_err(|e| e.on_element(name))?
+    {
+        Some(res) => Ok(res),
+        None => Err(SvgError::SvgInvalidType(SvgUnsupportedGeometryTypeError {
+            element: Some(name.into()),
+        })),
+    }
+}
+
+// Replaces every Polygon that is an axis-aligned rectangle with a Rect, if requested
+fn prefer_rects(geom: GeometryCollection<f64>, options: &ParseOptions) -> GeometryCollection<f64> {
+    if !options.prefer_rect {
+        return geom;
+    }
+    GeometryCollection(
+        geom.0
+            .into_iter()
+            .map(|g| match g {
+                Geometry::Polygon(ref poly) => polygon_to_rect(poly).map_or(g, |r| r.into()),
+                _ => g,
+            })
+            .collect(),
+    )
+}
+
+fn polygon_to_rect(poly: &Polygon<f64>) -> Option<Rect<f64>> {
+    let ring = &poly.exterior().0;
+    // A closed ring of four corners
+    if !poly.interiors().is_empty() || ring.len() != 5 || ring[0] != ring[4] {
+        return None;
+    }
+    // Every side must be axis-aligned, alternating between horizontal and vertical
+    let horizontal = |a: Coordinate<f64>, b: Coordinate<f64>| a.y == b.y && a.x != b.x;
+    let vertical = |a: Coordinate<f64>, b: Coordinate<f64>| a.x == b.x && a.y != b.y;
+    let starts_horizontal = horizontal(ring[0], ring[1]);
+    let is_rect = (0..4).all(|i| {
+        if (i % 2 == 0) == starts_horizontal {
+            horizontal(ring[i], ring[i + 1])
+        } else {
+            vertical(ring[i], ring[i + 1])
+        }
+    });
+    if is_rect {
+        Some(Rect::new(ring[0], ring[2]))
+    } else {
+        None
     }
+}
 
-    Err(SvgError::SvgInvalidType(SvgUnsupportedGeometryTypeError))
+fn count_vertices(geom: &Geometry<f64>) -> usize {
+    match geom {
+        Geometry::Point(_) => 1,
+        Geometry::Line(_) => 2,
+        Geometry::LineString(ls) => ls.0.len(),
+        Geometry::Polygon(poly) => {
+            poly.exterior().0.len() + poly.interiors().iter().map(|i| i.0.len()).sum::<usize>()
+        }
+        Geometry::MultiPoint(mp) => mp.0.len(),
+        Geometry::MultiLineString(mls) => mls.0.iter().map(|ls| ls.0.len()).sum(),
+        Geometry::MultiPolygon(mp) => {
+            mp.0.iter()
+                .map(|poly| count_vertices(&poly.clone().into()))
+                .sum()
+        }
+        Geometry::GeometryCollection(gc) => gc.0.iter().map(count_vertices).sum(),
+        Geometry::Rect(_) => 4,
+        Geometry::Triangle(_) => 3,
+    }
 }
 
 /// Returns a Geometry parsed from the submitted SVG element
@@ -285,9 +916,11 @@ pub fn svg_to_geometry_collection(svg: &str) -> Result<GeometryCollection<f64>,
 /// * \<polyline\> &rarr; LineString
 /// * \<rect\> &rarr; Polygon
 /// * \<line\> &rarr; Line
+/// * \<circle\> &rarr; Polygon approximating the circle
+/// * \<ellipse\> &rarr; Polygon approximating the ellipse
 ///
-/// **Note** also that the current parsing of curves in a `<path>`is rather simple right now,
-/// it just finds 100 points along the curve.
+/// **Note** also that curves in a `<path>` are flattened into straight line segments that lie
+/// within 0.1 units of the true curve.
 ///
 /// # Examples
 ///
@@ -356,705 +989,5522 @@ pub fn svg_to_geometry(svg: &str) -> Result<Geometry<f64>, SvgError> {
     ))
 }
 
-fn svg_polygon_to_geometry(point_string: &str) -> Result<Polygon<f64>, SvgError> {
-    let points = PointsParser::from(point_string);
-    let polygon = Polygon::new(
-        LineString(
-            points
-                .map(|(x, y)| Coordinate { x, y })
-                .collect::<Vec<Coordinate<f64>>>(),
-        ),
-        vec![],
-    );
-
-    if polygon.exterior().num_coords() == 0 {
-        return Err(SvgError::InvalidSvgError(InvalidSvgError));
-    }
-    Ok(polygon)
-}
-
-fn svg_polyline_to_geometry(point_string: &str) -> Result<LineString<f64>, SvgError> {
-    let points = PointsParser::from(point_string);
-    let linestring = LineString(
-        points
-            .map(|(x, y)| Coordinate { x, y })
-            .collect::<Vec<Coordinate<f64>>>(),
-    );
-
-    if linestring.num_coords() == 0 {
-        return Err(SvgError::InvalidSvgError(InvalidSvgError));
-    }
-    Ok(linestring)
-}
-
-fn svg_rect_to_geometry(x: f64, y: f64, width: f64, height: f64) -> Result<Polygon<f64>, SvgError> {
-    let max_x = x + width;
-    let max_y = y + height;
-    if x > max_x {
-        return Err(SvgError::InvalidSvgError(InvalidSvgError));
+/// Normalizes a Geometry by writing it as SVG path data and parsing that back
+///
+/// The reader rebuilds Polygons from their rings, so holes are assigned to the exteriors that
+/// contain them and, e.g., a self-intersecting ring is kept as a single ring. Normalizing a
+/// normalized Geometry returns it unchanged. Path data parsing to several Geometries, e.g., for a
+/// MultiLineString mixing open and closed lines, is returned as a GeometryCollection.
+///
+/// Points and MultiPoints have no extent to write as path data, so they are returned unchanged.
+/// The members of a GeometryCollection are normalized one by one, so that, e.g., a Polygon
+/// inside another one stays a member of its own instead of becoming a hole.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::{polygon, Geometry};
+/// use geo_svg_io::geo_svg_reader::normalize_via_svg;
+///
+/// let poly = Geometry::Polygon(polygon![(x: 0.0, y: 0.0), (x: 0.0, y: 10.0), (x: 10.0, y: 0.0)]);
+/// let normalized = normalize_via_svg(&poly);
+/// assert!(normalized.is_ok());
+/// assert_eq!(poly, normalized.ok().unwrap());
+/// ```
+///
+pub fn normalize_via_svg(geom: &Geometry<f64>) -> Result<Geometry<f64>, SvgError> {
+    match geom {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => return Ok(geom.clone()),
+        Geometry::GeometryCollection(gc) => {
+            return gc
+                .0
+                .iter()
+                .map(normalize_via_svg)
+                .collect::<Result<_, _>>()
+                .map(|members| Geometry::GeometryCollection(GeometryCollection(members)));
+        }
+        _ => {}
     }
-    if y > max_y {
-        return Err(SvgError::InvalidSvgError(InvalidSvgError));
+    let mut gc = svg_d_path_to_geometry_collection(&geom.to_svg_string())?;
+    if gc.0.len() == 1 {
+        return Ok(gc.0.remove(0));
     }
-
-    // geo_types::Rect is not part of the enum Geometry, so we cast it to Polygon upon return
-    Ok(Polygon::from(Rect::new(
-        Coordinate::<f64> { x, y },
-        Coordinate::<f64> { x: max_x, y: max_y },
-    )))
+    Ok(Geometry::GeometryCollection(gc))
 }
 
-fn svg_line_to_geometry(start_x: &f64, start_y: &f64, end_x: &f64, end_y: &f64) -> Line<f64> {
-    Line::new(
-        Coordinate::<f64> {
-            x: *start_x,
-            y: *start_y,
-        },
-        Coordinate::<f64> {
-            x: *end_x,
-            y: *end_y,
-        },
-    )
+/// The type of Geometry an SVG element converts to, as returned by `svg_element_geometry_kind`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeometryKind {
+    /// The element converts to no Geometry, e.g., a \<rect\> without area
+    Empty,
+    Line,
+    LineString,
+    Polygon,
+    MultiLineString,
+    MultiPolygon,
+    /// The element converts to Geometries of several types, e.g., a \<path\> mixing open and
+    /// closed subpaths
+    GeometryCollection,
 }
 
-/// Parses the `d`-string from an SVG `<path>` element into a GeometryCollection
+/// Returns the type of Geometry the submitted SVG element converts to with
+/// `svg_to_geometry_collection`
 ///
-/// **Note** that the current parsing of curves is rather simple right now, it just finds
-/// 100 points along the curve.
+/// Curves are flattened as `svg_to_geometry_collection` flattens them, as whether a ring lies
+/// within another, and so whether a \<path\> is a Polygon with holes or a MultiPolygon, can
+/// depend on how far its curves bulge. As with `svg_to_geometry_collection`, the first supported
+/// element is used, and invalid attributes produce an `SvgError`.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use geo_svg_io::geo_svg_reader::svg_d_path_to_geometry_collection;
-/// use geo_types::polygon;
+/// use geo_svg_io::geo_svg_reader::{svg_element_geometry_kind, GeometryKind};
 ///
-/// let poly = polygon!(
-///         exterior: [
-///             (x: 0.0, y: 0.0),
-///             (x: 0.0, y: 60.0),
-///             (x: 60.0, y: 60.0),
-///             (x: 60.0, y: 0.0),
-///             (x: 0.0, y: 0.0),],
-///         interiors:[[
-///             (x: 10.0, y: 10.0),
-///             (x: 40.0, y: 1.0),
-///             (x: 40.0, y: 40.0),
-///             (x: 10.50, y: 40.0),
-///             (x: 10.0, y: 10.0),]
-///             ]
-///         );
-///
-/// let svg_string = String::from("M0 0l0 60l60 0L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10");
-/// let parsed_svg = svg_d_path_to_geometry_collection(&svg_string);
-/// assert_eq!(parsed_svg.is_ok(), true);
-///
-/// // Unwrap the GeometryCollection result
-/// let geom = parsed_svg.ok().unwrap();
-/// assert_eq!(1, geom.0.len());
+/// let kind = svg_element_geometry_kind(r#"<path d="M0 0C0 10 10 10 10 0Z"/>"#);
+/// assert!(kind.is_ok());
+/// assert_eq!(GeometryKind::Polygon, kind.ok().unwrap());
 ///
-/// // Read the geometry as a Polygon
-/// let pl = geom.0[0].clone().into_polygon();
-/// assert_eq!(true, pl.is_some());
-/// assert_eq!(pl.unwrap(), poly);
+/// let kind = svg_element_geometry_kind(r#"<polyline points="0,0 10,10 20,0"/>"#);
+/// assert_eq!(GeometryKind::LineString, kind.ok().unwrap());
 /// ```
 ///
-pub fn svg_d_path_to_geometry_collection(svg: &str) -> Result<GeometryCollection<f64>, SvgError> {
-    // We will collect the separate paths (from M to M) into segments for parsing
-    let mut path_segments = vec![] as Vec<Vec<Coordinate<f64>>>;
-    let mut segment_count = 0;
-    let mut first_segment = true;
-    let zero_coord = Coordinate { x: 0_f64, y: 0_f64 }; // Default values to be added to relative coords
-    let mut last_point: Option<Coordinate<f64>> = None; // Store last point for relative coordinates
-    let mut last_control_point: Option<Coord2> = None; // Store last control point for S and T coordinates
-    let p = PathParser::from(svg);
-    for token in p {
-        let t = token.unwrap();
-        match t {
-            PathSegment::MoveTo { .. } => {
-                path_segments.push(vec![] as Vec<Coordinate<f64>>);
-                if !first_segment {
-                    segment_count += 1;
-                } else {
-                    first_segment = false;
-                }
-                let coord = Coordinate {
-                    x: if t.is_relative() {
-                        t.x().unwrap() + last_point.unwrap_or(zero_coord).x
-                    } else {
-                        t.x().unwrap()
-                    },
-                    y: if t.is_relative() {
-                        t.y().unwrap() + last_point.unwrap_or(zero_coord).y
-                    } else {
-                        t.y().unwrap()
-                    },
-                };
-                last_point = Some(coord);
-                path_segments[segment_count].push(coord);
-            }
-            PathSegment::LineTo { .. } => {
-                let coord = Coordinate {
-                    x: if t.is_relative() {
-                        t.x().unwrap() + last_point.unwrap_or(zero_coord).x
-                    } else {
-                        t.x().unwrap()
-                    },
-                    y: if t.is_relative() {
-                        t.y().unwrap() + last_point.unwrap_or(zero_coord).y
-                    } else {
-                        t.y().unwrap()
-                    },
-                };
-                last_point = Some(coord);
-                path_segments[segment_count].push(coord);
-            }
-            PathSegment::HorizontalLineTo { .. } => {
-                let coord = Coordinate {
-                    x: if t.is_relative() {
-                        t.x().unwrap() + last_point.unwrap_or(zero_coord).x
-                    } else {
-                        t.x().unwrap()
-                    },
-                    y: last_point.unwrap_or(zero_coord).y,
-                };
-                last_point = Some(coord);
-                path_segments[segment_count].push(coord);
-            }
-            PathSegment::VerticalLineTo { .. } => {
-                let coord = Coordinate {
-                    x: last_point.unwrap_or(zero_coord).x,
-                    y: if t.is_relative() {
-                        t.y().unwrap() + last_point.unwrap_or(zero_coord).y
-                    } else {
-                        t.y().unwrap()
-                    },
-                };
-                last_point = Some(coord);
-                path_segments[segment_count].push(coord);
-            }
-            PathSegment::CurveTo {
-                x,
-                x1,
-                x2,
-                y,
-                y1,
-                y2,
-                abs,
-            } => {
-                let last = last_point.unwrap_or(zero_coord);
-                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
-                let control_1 = calculate_svg_coord2(x1, y1, last, abs);
-                let control_2 = calculate_svg_coord2(x2, y2, last, abs);
-                last_control_point = Some(control_2);
-                let end_point = calculate_svg_coord2(x, y, last, abs);
-                let end = Coordinate {
-                    x: end_point.x(),
-                    y: end_point.y(),
-                };
-                last_point = Some(end);
-                // TODO: it is not great to just pick an arbitrary number of points along the curve
-                // update this to use a recursive function instead to create more points until
-                // they are collinear (enough)
-                for x in 1..100 {
-                    let arc_point = de_casteljau4(
-                        x as f64 / 100_f64,
-                        start_point,
-                        control_1,
-                        control_2,
-                        end_point,
-                    );
-                    path_segments[segment_count].push(Coordinate {
-                        x: arc_point.x(),
-                        y: arc_point.y(),
-                    });
-                }
-                path_segments[segment_count].push(end);
-            }
-            PathSegment::SmoothCurveTo { x2, x, y2, y, abs } => {
-                let last = last_point.unwrap_or(zero_coord);
-                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
-                let control_1 = reflect_point(last, last_control_point.unwrap_or(Coord2(0., 0.)));
-                let control_2 = calculate_svg_coord2(x2, y2, last, abs);
-                last_control_point = Some(control_2);
-                let end_point = calculate_svg_coord2(x, y, last, abs);
-                let end = Coordinate {
-                    x: end_point.x(),
-                    y: end_point.y(),
-                };
-                last_point = Some(end);
-                // TODO: it is not great to just pick an arbitrary number of points along the curve
-                // update this to use a recursive function instead to create more points until
-                // they are collinear (enough)
-                for x in 1..100 {
-                    let arc_point = de_casteljau4(
-                        x as f64 / 100_f64,
-                        start_point,
-                        control_1,
-                        control_2,
-                        end_point,
-                    );
-                    path_segments[segment_count].push(Coordinate {
-                        x: arc_point.x(),
-                        y: arc_point.y(),
-                    });
-                }
-                path_segments[segment_count].push(end);
-            }
-            PathSegment::Quadratic { x1, x, y1, y, abs } => {
-                let last = last_point.unwrap_or(zero_coord);
-                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
-                let control_1 = calculate_svg_coord2(x1, y1, last, abs);
-                last_control_point = Some(control_1);
-                let end_point = calculate_svg_coord2(x, y, last, abs);
-                let end = Coordinate {
-                    x: end_point.x(),
-                    y: end_point.y(),
-                };
-                last_point = Some(end);
-                // TODO: it is not great to just pick an arbitrary number of points along the curve
-                // update this to use a recursive function instead to create more points until
-                // they are collinear (enough)
-                for x in 1..100 {
-                    let arc_point =
-                        de_casteljau3(x as f64 / 100_f64, start_point, control_1, end_point);
-                    path_segments[segment_count].push(Coordinate {
-                        x: arc_point.x(),
-                        y: arc_point.y(),
-                    });
-                }
-                path_segments[segment_count].push(end);
-            }
-            PathSegment::SmoothQuadratic { x, y, abs } => {
-                let last = last_point.unwrap_or(zero_coord);
-                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
-                let control_1 = reflect_point(last, last_control_point.unwrap_or(Coord2(0., 0.)));
-                last_control_point = Some(control_1);
-                let end_point = calculate_svg_coord2(x, y, last, abs);
-                let end = Coordinate {
-                    x: end_point.x(),
-                    y: end_point.y(),
-                };
-                last_point = Some(end);
-                // TODO: it is not great to just pick an arbitrary number of points along the curve
-                // update this to use a recursive function instead to create more points until
-                // they are collinear (enough)
-                for x in 1..100 {
-                    let arc_point =
-                        de_casteljau3(x as f64 / 100_f64, start_point, control_1, end_point);
-                    path_segments[segment_count].push(Coordinate {
-                        x: arc_point.x(),
-                        y: arc_point.y(),
-                    });
-                }
-                path_segments[segment_count].push(end);
-            }
-            // TODO: PathSegment::EllipticalArc
-            PathSegment::ClosePath { .. } => {
-                let coord = Coordinate {
-                    x: path_segments[segment_count][0].x,
-                    y: path_segments[segment_count][0].y,
-                };
-                last_point = Some(coord);
-                path_segments[segment_count].push(coord);
-            }
-            _ => last_point = None,
-        }
-    }
-    if path_segments.is_empty() {
-        return Err(SvgError::InvalidSvgError(InvalidSvgError));
-    }
-    Ok(parse_path_segments_to_geom(&path_segments))
+pub fn svg_element_geometry_kind(svg: &str) -> Result<GeometryKind, SvgError> {
+    let (geom, _) = parse_first_element(svg.as_bytes(), &ParseOptions::default())?;
+    Ok(match geom.0.as_slice() {
+        [] => GeometryKind::Empty,
+        [Geometry::Line(_)] => GeometryKind::Line,
+        [Geometry::LineString(_)] => GeometryKind::LineString,
+        [Geometry::Polygon(_)] => GeometryKind::Polygon,
+        [Geometry::MultiLineString(_)] => GeometryKind::MultiLineString,
+        [Geometry::MultiPolygon(_)] => GeometryKind::MultiPolygon,
+        _ => GeometryKind::GeometryCollection,
+    })
 }
 
-/// Parses the `d`-string from an SVG `<path>` element into a single Geometry
+/// A GeometryCollection parsed from an SVG element with `str::parse`
 ///
-/// **Note** that the current parsing of curves is rather simple right now, it just finds
-/// 100 points along the curve.
+/// The orphan rule keeps this crate from implementing `FromStr` for `GeometryCollection`
+/// itself, so parsing goes through this wrapper, which converts into the GeometryCollection.
+/// Parsing delegates to `svg_to_geometry_collection_as`, so the coordinates can be of any float
+/// type, `f64` by default.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use geo_svg_io::geo_svg_reader::svg_d_path_to_geometry;
-/// use geo_types::polygon;
+/// use geo_types::GeometryCollection;
+/// use geo_svg_io::geo_svg_reader::{SvgError, SvgGeometryCollection};
 ///
-/// let poly = polygon!(
-///         exterior: [
-///             (x: 0.0, y: 0.0),
-///             (x: 0.0, y: 60.0),
-///             (x: 60.0, y: 60.0),
-///             (x: 60.0, y: 0.0),
-///             (x: 0.0, y: 0.0),],
-///         interiors:[[
-///             (x: 10.0, y: 10.0),
-///             (x: 40.0, y: 1.0),
-///             (x: 40.0, y: 40.0),
-///             (x: 10.50, y: 40.0),
-///             (x: 10.0, y: 10.0),]
-///             ]
-///         );
+/// fn parse(svg: &str) -> Result<GeometryCollection<f64>, SvgError> {
+///     let parsed: SvgGeometryCollection = svg.parse()?;
+///     Ok(parsed.into())
+/// }
 ///
-/// let svg_string = String::from("M0 0l0 60l60 0L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10");
-/// let parsed_svg = svg_d_path_to_geometry(&svg_string);
-/// assert!(parsed_svg.is_ok());
-/// let pl = parsed_svg.ok().unwrap().into_polygon();
-/// assert!(pl.is_some());
-/// assert_eq!(pl.unwrap(), poly);
+/// let geom = parse(r#"<polygon points="0, 0 60, 0 60, 60 0, 60 0, 0"/>"#);
+/// assert!(geom.is_ok());
+/// assert_eq!(1, geom.ok().unwrap().0.len());
 /// ```
 ///
-pub fn svg_d_path_to_geometry(svg: &str) -> Result<Geometry<f64>, SvgError> {
-    let gc = svg_d_path_to_geometry_collection(svg)?;
-    if gc.0.len() == 1 {
-        return Ok(gc.0[0].clone());
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgGeometryCollection<T: CoordFloat = f64>(pub GeometryCollection<T>);
+
+impl<T: CoordFloat> FromStr for SvgGeometryCollection<T> {
+    type Err = SvgError;
+
+    fn from_str(svg: &str) -> Result<Self, Self::Err> {
+        svg_to_geometry_collection_as(svg).map(SvgGeometryCollection)
     }
-    Err(SvgError::SvgGeomCollectionForGeometry(
-        SvgGeometryCollectionForGeometryError,
-    ))
 }
 
-fn calculate_svg_coord2(x: f64, y: f64, last: Coordinate<f64>, abs: bool) -> Coord2 {
-    Coord2(
-        if abs { x } else { last.x + x },
-        if abs { y } else { last.y + y },
-    )
+impl<T: CoordFloat> From<SvgGeometryCollection<T>> for GeometryCollection<T> {
+    fn from(parsed: SvgGeometryCollection<T>) -> Self {
+        parsed.0
+    }
 }
 
-fn reflect_point(orig: Coordinate<f64>, pr: Coord2) -> Coord2 {
-    let x_step = pr.x() - orig.x;
-    let y_step = pr.y() - orig.y;
-
-    Coord2(orig.x - x_step, orig.y - y_step)
-}
+/// A Geometry parsed from an SVG element with `str::parse`
+///
+/// Like `SvgGeometryCollection`, this wraps the Geometry it converts into. Parsing delegates to
+/// `svg_to_geometry_as`, so it fails for input producing more than one Geometry.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::Geometry;
+/// use geo_svg_io::geo_svg_reader::SvgGeometry;
+///
+/// let parsed = r#"<line x1="0" y1="0" x2="10" y2="10"/>"#.parse::<SvgGeometry>();
+/// assert!(parsed.is_ok());
+///
+/// let geom: Geometry<f64> = parsed.ok().unwrap().into();
+/// assert!(geom.into_line().is_some());
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgGeometry<T: CoordFloat = f64>(pub Geometry<T>);
 
-fn parse_path_segments_to_geom(paths: &Vec<Vec<Coordinate<f64>>>) -> GeometryCollection<f64> {
-    let mut lines = vec![] as Vec<Line<f64>>;
-    let mut line_strings = vec![] as Vec<LineString<f64>>;
-    let mut poly_line_strings = vec![] as Vec<LineString<f64>>;
-    let mut polygons: MultiPolygon<f64> = (vec![] as Vec<Polygon<f64>>).into();
+impl<T: CoordFloat> FromStr for SvgGeometry<T> {
+    type Err = SvgError;
 
-    for path in paths {
-        let length = path.len();
-        if length == 0 {
-            continue;
-        } else if length == 2 {
-            lines.push(Line::new(path[0], path[1]));
-        } else if !path.first().unwrap().eq(path.last().unwrap()) {
-            line_strings.push(path.clone().into());
-        } else {
-            poly_line_strings.push(path.clone().into());
-        }
+    fn from_str(svg: &str) -> Result<Self, Self::Err> {
+        svg_to_geometry_as(svg).map(SvgGeometry)
     }
+}
 
-    if !poly_line_strings.is_empty() {
-        if poly_line_strings.len() == 1 {
-            polygons = Polygon::new(poly_line_strings[0].clone(), vec![]).into();
-        } else {
-            polygons = parse_polygon_rings_to_geom(&poly_line_strings);
-        }
+impl<T: CoordFloat> From<SvgGeometry<T>> for Geometry<T> {
+    fn from(parsed: SvgGeometry<T>) -> Self {
+        parsed.0
     }
+}
 
-    let number_of_geom_types = !lines.is_empty() as i32
-        + !line_strings.is_empty() as i32
-        + !poly_line_strings.is_empty() as i32;
+// With the `serde` feature, a Geometry is (de)serialized as a string of its SVG element, and a
+// GeometryCollection as an <svg> document of its members, so that all of them are read back
+#[cfg(feature = "serde")]
+impl<T: CoordFloat + fmt::Display> serde::Serialize for SvgGeometryCollection<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("<svg>{}</svg>", self.0.to_svg()))
+    }
+}
 
-    let mut geom_collection = vec![] as Vec<Geometry<f64>>;
-    if !lines.is_empty() {
-        let return_lines = map_lines_to_geometry(&lines);
-        if number_of_geom_types == 1 {
-            return GeometryCollection(vec![return_lines]);
-        } else {
-            geom_collection.push(return_lines);
-        }
-    } else if !line_strings.is_empty() {
-        let return_line_strings = map_line_strings_to_geometry(&line_strings);
-        if number_of_geom_types == 1 {
-            return GeometryCollection(vec![return_line_strings]);
-        } else {
-            geom_collection.push(return_line_strings);
-        }
-    } else if !polygons.0.is_empty() {
-        let return_polygons = map_polygons_to_geometry(polygons);
-        if number_of_geom_types == 1 {
-            return GeometryCollection(vec![return_polygons]);
+// A lone element, as a Geometry is serialized, is read as a collection of one
+#[cfg(feature = "serde")]
+impl<'de, T: CoordFloat> serde::Deserialize<'de> for SvgGeometryCollection<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let svg = String::deserialize(deserializer)?;
+        if svg.trim_start().starts_with("<svg") {
+            svg_document_to_geometry_collection_as(&svg)
+                .map(SvgGeometryCollection)
+                .map_err(serde::de::Error::custom)
         } else {
-            geom_collection.push(return_polygons);
+            svg.parse().map_err(serde::de::Error::custom)
         }
     }
-
-    GeometryCollection(geom_collection)
 }
 
-fn parse_polygon_rings_to_geom(rings: &Vec<LineString<f64>>) -> MultiPolygon<f64> {
-    // Early return for empty vector
-    if rings.len() == 0 {
-        return (vec![] as Vec<Polygon<f64>>).into();
+#[cfg(feature = "serde")]
+impl<T: CoordFloat + fmt::Display> serde::Serialize for SvgGeometry<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_svg())
     }
+}
 
-    let mut ring_iter = rings.iter();
-    let mut result_poly = MultiPolygon(vec![Polygon::new(
-        ring_iter.next().unwrap().clone(),
-        vec![],
-    )]);
-
-    result_poly
+#[cfg(feature = "serde")]
+impl<'de, T: CoordFloat> serde::Deserialize<'de> for SvgGeometry<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let svg = String::deserialize(deserializer)?;
+        svg.parse().map_err(serde::de::Error::custom)
+    }
 }
 
-fn map_lines_to_geometry(lines: &Vec<Line<f64>>) -> Geometry<f64> {
-    if lines.len() == 1 {
-        lines[0].into()
-    } else {
-        let multi_line: MultiLineString<f64> = lines
+/// Returns a GeometryCollection of every supported shape element in a full SVG document
+///
+/// Unlike `svg_to_geometry_collection`, the input must be a complete document with a root
+/// `<svg>` element. The shapes are returned in document order, and the elements supported by
+/// `svg_to_geometry_collection` produce the same Geometry types here. Shapes nested in groups
+/// (`<g>`) are included at any depth, with the `transform` of every ancestor group applied after
+/// their own, while shapes inside `<defs>` are only templates that are not rendered themselves,
+/// so they are skipped.
+///
+/// A `<use>` element referencing a shape of the same document by its `id` (`href="#id"`, or the
+/// older `xlink:href`) produces the Geometries of that shape, moved by its `x` and `y` and then
+/// transformed like any other element. The shape can be defined anywhere in the document, e.g.,
+/// in `<defs>`. A reference to anything else, e.g., a group, another `<use>`, or another file,
+/// produces an `InvalidSvgError`.
+///
+/// A valid document without any shapes (e.g., `<svg></svg>`) produces an empty
+/// GeometryCollection, while malformed XML or a document without a root `<svg>` element
+/// produces an `InvalidSvgError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_document_to_geometry_collection;
+///
+/// let svg_string = r#"<svg xmlns="http://www.w3.org/2000/svg">
+///     <rect x="0" y="0" width="60" height="60"/>
+///     <polyline points="0,0 0,60 60,60"/>
+/// </svg>"#;
+///
+/// let parsed_svg = svg_document_to_geometry_collection(svg_string);
+/// assert!(parsed_svg.is_ok());
+///
+/// let geom = parsed_svg.ok().unwrap();
+/// assert_eq!(2, geom.0.len());
+/// assert!(geom.0[0].clone().into_polygon().is_some());
+/// assert!(geom.0[1].clone().into_line_string().is_some());
+/// ```
+///
+pub fn svg_document_to_geometry_collection(svg: &str) -> Result<GeometryCollection<f64>, SvgError> {
+    svg_document_to_geometry_collection_with_options(svg, &ParseOptions::default())
+}
+
+/// Returns a GeometryCollection of every supported shape element in a full SVG document, using
+/// the supplied `ParseOptions`
+///
+/// This reads the same documents as `svg_document_to_geometry_collection`, and the options apply
+/// to every shape as they do in `svg_to_geometry_collection_with_options`.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::{svg_document_to_geometry_collection_with_options, ParseOptions};
+///
+/// let svg_string = "<svg><line x1=\"\u{2212}5\" y1=\"0\" x2=\"5\" y2=\"0\"/></svg>";
+///
+/// let mut options = ParseOptions::default();
+/// options.normalize_unicode = true;
+/// let parsed_svg = svg_document_to_geometry_collection_with_options(svg_string, &options);
+/// assert!(parsed_svg.is_ok());
+/// assert_eq!(1, parsed_svg.ok().unwrap().0.len());
+/// ```
+///
+pub fn svg_document_to_geometry_collection_with_options(
+    svg: &str,
+    options: &ParseOptions,
+) -> Result<GeometryCollection<f64>, SvgError> {
+    svg_geometries_iter_with_options(svg, options)
+        .collect::<Result<Vec<Geometry<f64>>, SvgError>>()
+        .map(GeometryCollection)
+}
+
+/// Returns an iterator over the Geometries of every supported shape element in a full SVG
+/// document
+///
+/// The iterator yields the same Geometries as `svg_document_to_geometry_collection`, in the same
+/// order, but reads the document only as far as needed for the next one, so the shapes of a
+/// large document can be filtered and processed without holding all of them in memory. Use
+/// `GeometryIter::new` to read the document from a file or any other `std::io::Read` source.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_geometries_iter;
+///
+/// let svg_string = r#"<svg xmlns="http://www.w3.org/2000/svg">
+///     <rect x="0" y="0" width="60" height="60"/>
+///     <polyline points="0,0 0,60 60,60"/>
+///     <rect x="100" y="0" width="60" height="60"/>
+/// </svg>"#;
+///
+/// let polygons = svg_geometries_iter(svg_string)
+///     .filter_map(|geom| geom.ok()?.into_polygon())
+///     .count();
+/// assert_eq!(2, polygons);
+/// ```
+///
+pub fn svg_geometries_iter(svg: &str) -> GeometryIter<&[u8]> {
+    svg_geometries_iter_with_options(svg, &ParseOptions::default())
+}
+
+/// Returns an iterator over the Geometries of every supported shape element in a full SVG
+/// document, using the supplied `ParseOptions`, as `svg_document_to_geometry_collection_with_options`
+/// returns them
+pub fn svg_geometries_iter_with_options<'a>(
+    svg: &'a str,
+    options: &ParseOptions,
+) -> GeometryIter<&'a [u8]> {
+    let (referenced, forward) = scan_use_references(svg);
+    let mut iter = GeometryIter::with_options(svg.as_bytes(), options.clone());
+    if !forward.is_empty() {
+        // Another pass indexes the shapes referenced before they are defined
+        let mut index = GeometryIter::with_options(svg.as_bytes(), options.clone());
+        index.index_only = true;
+        index.referenced = Some(forward);
+        index.by_ref().for_each(drop);
+        iter.ids = index.ids;
+    }
+    iter.referenced = Some(referenced);
+    iter
+}
+
+// Returns the ids referenced by the <use> elements of a document, and those of them referenced
+// before the element with the id. Only the XML is read, so errors are left to the full pass.
+fn scan_use_references(svg: &str) -> (HashSet<String>, HashSet<String>) {
+    let mut defined = HashSet::new();
+    let mut referenced = HashSet::new();
+    let mut forward = HashSet::new();
+    for e in EventReader::new(svg.as_bytes()) {
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                for attr in attributes {
+                    if attr.name.local_name == "id" {
+                        defined.insert(attr.value);
+                    } else if name.local_name == "use" && attr.name.local_name == "href" {
+                        if let Some(id) = attr.value.trim().strip_prefix('#') {
+                            if !defined.contains(id) {
+                                forward.insert(id.to_string());
+                            }
+                            referenced.insert(id.to_string());
+                        }
+                    }
+                }
+            }
+            Err(_) => break,
+            _ => {}
+        }
+    }
+    (referenced, forward)
+}
+
+/// An iterator over the Geometries of the shape elements in a full SVG document, which pulls the
+/// XML events from its reader on demand
+///
+/// An element producing several Geometries (e.g., a \<path\> mixing lines and polygons) yields
+/// each of them in turn. Malformed XML or a document without a root `<svg>` element yields an
+/// `InvalidSvgError`, and iteration ends after any error.
+///
+/// The document is read only once, so a `<use>` element can only reference a shape that comes
+/// before it, which is where `<defs>` usually are, and the Geometries of every shape with an id
+/// are kept in case a later `<use>` references it. `svg_geometries_iter` scans the document for
+/// references first, so it resolves them in both directions and keeps only referenced shapes.
+pub struct GeometryIter<R: Read> {
+    events: xml::reader::Events<R>,
+    found_svg: bool,
+    // The transform accumulated from the ancestors of the content of every open element, or None
+    // if that content is not rendered (inside a <defs>). Groups (<g>) can be nested to any depth,
+    // so this follows the start and end of every element.
+    open_elements: Vec<Option<Affine2<f64>>>,
+    // The Geometries of the last shape element that have not been yielded yet
+    pending: std::collections::VecDeque<Geometry<f64>>,
+    // The Geometries of the shapes with an id read so far, with their own transform but not that
+    // of their ancestors, or None if the shape is invalid
+    ids: HashMap<String, Option<Vec<Geometry<f64>>>>,
+    // The ids referenced by <use> elements, if known, so only those shapes are kept in `ids`
+    referenced: Option<HashSet<String>>,
+    // Whether shapes are only indexed into `ids`, without yielding them or resolving <use>
+    index_only: bool,
+    options: ParseOptions,
+    done: bool,
+}
+
+impl<R: Read> GeometryIter<R> {
+    /// Returns an iterator over the Geometries of the SVG document read from `reader`
+    pub fn new(reader: R) -> Self {
+        GeometryIter::with_options(reader, ParseOptions::default())
+    }
+
+    /// Returns an iterator over the Geometries of the SVG document read from `reader`, using the
+    /// supplied `ParseOptions`
+    pub fn with_options(reader: R, options: ParseOptions) -> Self {
+        GeometryIter {
+            events: EventReader::new(reader).into_iter(),
+            found_svg: false,
+            open_elements: vec![],
+            pending: std::collections::VecDeque::new(),
+            ids: HashMap::new(),
+            referenced: None,
+            index_only: false,
+            options,
+            done: false,
+        }
+    }
+
+    // Reads up to the next shape element and returns its Geometries, or None at the end of the
+    // document
+    fn next_element(&mut self) -> Result<Option<Vec<Geometry<f64>>>, SvgError> {
+        for e in self.events.by_ref() {
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    if !self.found_svg {
+                        // The root element must be an <svg>
+                        if name.local_name != "svg" {
+                            return Err(SvgError::InvalidSvgError(InvalidSvgError::new(&format!(
+                                "root element <{}> instead of <svg>",
+                                name.local_name
+                            ))));
+                        }
+                        self.found_svg = true;
+                        if self.options.viewport.is_none() {
+                            self.options.viewport = root_viewport(&attributes);
+                        }
+                        self.open_elements.push(Some(Affine2::identity()));
+                        continue;
+                    }
+
+                    let referenced = &self.referenced;
+                    let id = attributes
+                        .iter()
+                        .find(|attr| attr.name.local_name == "id")
+                        .map(|attr| attr.value.clone())
+                        .filter(|id| match referenced {
+                            Some(ids) => ids.contains(id),
+                            None => true,
+                        });
+                    let inherited = match self.open_elements.last() {
+                        Some(Some(transform)) if name.local_name != "defs" => *transform,
+                        _ => {
+                            self.open_elements.push(None);
+                            // Shapes that are not rendered can still be referenced by a <use>
+                            if let Some(id) = id {
+                                let res = svg_element_to_geometry_collection(
+                                    &name.local_name,
+                                    attributes,
+                                    &self.options,
+                                    &mut ParseReport::default(),
+                                );
+                                match res {
+                                    Ok(Some(res)) => self.ids.insert(id, Some(res.0)),
+                                    Ok(None) => None,
+                                    Err(_) => self.ids.insert(id, None),
+                                };
+                            }
+                            continue;
+                        }
+                    };
+                    // The element applies its own transform before those of its ancestors
+                    let transform = match attributes
+                        .iter()
+                        .find(|attr| attr.name.local_name == "transform")
+                    {
+                        Some(attr) if self.options.normalize_unicode => {
+                            parse_transform(&normalize_unicode(&attr.value))
+                                .map_err(|e| e.on_element(&name.local_name))?
+                                .then(&inherited)
+                        }
+                        Some(attr) => parse_transform(&attr.value)
+                            .map_err(|e| e.on_element(&name.local_name))?
+                            .then(&inherited),
+                        None => inherited,
+                    };
+                    self.open_elements.push(Some(transform));
+                    if self.index_only && (id.is_none() || name.local_name == "use") {
+                        continue;
+                    }
+                    if name.local_name == "use" {
+                        return self
+                            .use_to_geometries(&attributes, &transform)
+                            .map(Some)
+                            .map_err(|e| e.on_element("use"));
+                    }
+                    if let Some(res) = svg_element_to_geometry_collection(
+                        &name.local_name,
+                        attributes,
+                        &self.options,
+                        &mut ParseReport::default(),
+                    )? {
+                        if let Some(id) = id {
+                            self.ids.insert(id, Some(res.0.clone()));
+                        }
+                        if self.index_only {
+                            continue;
+                        }
+                        if inherited == Affine2::identity() {
+                            return Ok(Some(res.0));
+                        }
+                        return Ok(Some(
+                            res.0.iter().map(|g| inherited.apply_geometry(g)).collect(),
+                        ));
+                    }
+                }
+                Ok(XmlEvent::EndElement { .. }) => {
+                    self.open_elements.pop();
+                }
+                Err(e) => {
+                    return Err(SvgError::InvalidSvgError(InvalidSvgError::new(&format!(
+                        "malformed XML ({})",
+                        e.msg()
+                    ))))
+                }
+                _ => {}
+            }
+        }
+
+        if !self.found_svg {
+            return Err(SvgError::InvalidSvgError(InvalidSvgError::new(
+                "no root <svg> element",
+            )));
+        }
+        Ok(None)
+    }
+
+    // The Geometries of the shape referenced by a <use> element, moved by its `x` and `y` before
+    // applying `transform`
+    fn use_to_geometries(
+        &self,
+        attributes: &[OwnedAttribute],
+        transform: &Affine2<f64>,
+    ) -> Result<Vec<Geometry<f64>>, SvgError> {
+        let attrs = attributes
             .iter()
-            .map(|x| LineString(vec![x.start, x.end]))
-            .collect();
-        multi_line.into()
+            .map(|attr| {
+                let value = if self.options.normalize_unicode {
+                    normalize_unicode(&attr.value)
+                } else {
+                    attr.value.clone()
+                };
+                (attr.name.local_name.clone(), value)
+            })
+            .collect::<HashMap<String, String>>();
+        let href = match attrs.get("href") {
+            Some(href) => href.trim(),
+            None => {
+                return Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+                    "href", "missing",
+                )))
+            }
+        };
+        let geoms = match href.strip_prefix('#').map(|id| self.ids.get(id)) {
+            Some(Some(Some(geoms))) => geoms,
+            Some(Some(None)) => {
+                return Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+                    "href",
+                    "referencing an invalid shape",
+                )))
+            }
+            Some(None) => {
+                return Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+                    "href",
+                    "not referencing a shape",
+                )))
+            }
+            None => {
+                return Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+                    "href",
+                    "not referencing the same document",
+                )))
+            }
+        };
+        let x = optional_f64_attr(&attrs, "x", &self.options)?;
+        let y = optional_f64_attr(&attrs, "y", &self.options)?;
+        let transform = Affine2::translate(x, y).then(transform);
+        Ok(geoms.iter().map(|g| transform.apply_geometry(g)).collect())
     }
 }
 
-fn map_line_strings_to_geometry(line_strings: &Vec<LineString<f64>>) -> Geometry<f64> {
-    if line_strings.len() == 1 {
-        line_strings[0].clone().into()
-    } else {
-        let multi_line: MultiLineString<f64> = MultiLineString(line_strings.to_vec());
-        multi_line.into()
+impl<R: Read> Iterator for GeometryIter<R> {
+    type Item = Result<Geometry<f64>, SvgError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(geom) = self.pending.pop_front() {
+                return Some(Ok(geom));
+            }
+            if self.done {
+                return None;
+            }
+            match self.next_element() {
+                Ok(Some(geoms)) => self.pending.extend(geoms),
+                Ok(None) => self.done = true,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
     }
 }
 
-fn map_polygons_to_geometry(polys: MultiPolygon<f64>) -> Geometry<f64> {
-    if polys.0.len() == 1 {
-        polys.0[0].clone().into()
+/// The dimensions declared on the root `<svg>` element of a document
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgDimensions {
+    /// The `width` of the document, stripped of its unit
+    pub width: Option<f64>,
+    /// The `height` of the document, stripped of its unit
+    pub height: Option<f64>,
+    /// The unit shared by `width` and `height`, if any
+    pub unit: Option<SvgUnit>,
+    /// The `viewBox` of the document
+    pub view_box: Option<Rect<f64>>,
+}
+
+impl SvgDimensions {
+    /// Returns the `width` and `height` with their unit, if the document declares both, e.g., to
+    /// write it back out with `geo_svg_writer::svg_document`
+    pub fn size(&self) -> Option<SvgSize<f64>> {
+        Some(SvgSize {
+            width: self.width?,
+            height: self.height?,
+            unit: self.unit,
+        })
+    }
+}
+
+/// Returns the dimensions declared on the root `<svg>` element of a document
+///
+/// Shape coordinates are always read as unitless user units, so the unit of the document
+/// `width` and `height` is recorded here instead. This allows print workflows (where `mm` or
+/// `in` carry meaning) to re-apply the unit when writing the document back out with
+/// `geo_svg_writer::svg_document`.
+///
+/// An `InvalidSvgError` is returned if the input does not start with an `<svg>` element or its
+/// dimensions cannot be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::{svg_document_dimensions, SvgUnit};
+///
+/// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="210mm" height="297mm" viewBox="0 0 210 297"></svg>"#;
+/// let dimensions = svg_document_dimensions(svg);
+/// assert!(dimensions.is_ok());
+///
+/// let dimensions = dimensions.ok().unwrap();
+/// assert_eq!(Some(210.0), dimensions.width);
+/// assert_eq!(Some(297.0), dimensions.height);
+/// assert_eq!(Some(SvgUnit::Mm), dimensions.unit);
+/// ```
+///
+pub fn svg_document_dimensions(svg: &str) -> Result<SvgDimensions, SvgError> {
+    let parser = EventReader::new(svg.as_bytes());
+    for e in parser {
+        if let Ok(XmlEvent::StartElement {
+            name, attributes, ..
+        }) = e
+        {
+            if name.local_name != "svg" {
+                break;
+            }
+
+            let mut dimensions = SvgDimensions::default();
+            let mut width_unit: Option<SvgUnit> = None;
+            let mut height_unit: Option<SvgUnit> = None;
+            for attr in attributes {
+                if attr.name.local_name == "width" {
+                    let (width, unit) = parse_svg_length("width", &attr.value)?;
+                    dimensions.width = Some(width);
+                    width_unit = unit;
+                } else if attr.name.local_name == "height" {
+                    let (height, unit) = parse_svg_length("height", &attr.value)?;
+                    dimensions.height = Some(height);
+                    height_unit = unit;
+                } else if attr.name.local_name == "viewBox" {
+                    let view_box = attr.value.parse::<svgtypes::ViewBox>().map_err(|_| {
+                        SvgError::InvalidSvgError(InvalidSvgError::attribute("viewBox", "invalid"))
+                            .on_element("svg")
+                    })?;
+                    dimensions.view_box = Some(Rect::new(
+                        Coordinate {
+                            x: view_box.x,
+                            y: view_box.y,
+                        },
+                        Coordinate {
+                            x: view_box.x + view_box.w,
+                            y: view_box.y + view_box.h,
+                        },
+                    ));
+                }
+            }
+
+            dimensions.unit = match (dimensions.width, dimensions.height) {
+                (Some(_), Some(_)) if width_unit == height_unit => width_unit,
+                (Some(_), None) => width_unit,
+                (None, Some(_)) => height_unit,
+                _ => None,
+            };
+            return Ok(dimensions);
+        }
+    }
+
+    Err(SvgError::InvalidSvgError(InvalidSvgError::new(
+        "no root <svg> element",
+    )))
+}
+
+// Parses the `width` or `height` of the root <svg>
+fn parse_svg_length(name: &str, value: &str) -> Result<(f64, Option<SvgUnit>), SvgError> {
+    let length = value.parse::<svgtypes::Length>().map_err(|_| {
+        SvgError::InvalidSvgError(InvalidSvgError::attribute(name, "invalid")).on_element("svg")
+    })?;
+    let unit = match length.unit {
+        svgtypes::LengthUnit::None => None,
+        svgtypes::LengthUnit::Px => Some(SvgUnit::Px),
+        svgtypes::LengthUnit::In => Some(SvgUnit::In),
+        svgtypes::LengthUnit::Cm => Some(SvgUnit::Cm),
+        svgtypes::LengthUnit::Mm => Some(SvgUnit::Mm),
+        svgtypes::LengthUnit::Pt => Some(SvgUnit::Pt),
+        svgtypes::LengthUnit::Pc => Some(SvgUnit::Pc),
+        svgtypes::LengthUnit::Em => Some(SvgUnit::Em),
+        svgtypes::LengthUnit::Ex => Some(SvgUnit::Ex),
+        svgtypes::LengthUnit::Percent => Some(SvgUnit::Percent),
+    };
+    Ok((length.num, unit))
+}
+
+fn svg_polygon_to_geometry(point_string: &str) -> Result<Polygon<f64>, SvgError> {
+    // A <polygon> is implicitly closed, and Polygon::new closes its rings by repeating the first
+    // coordinate unless the points already end with it
+    Ok(Polygon::new(
+        LineString(parse_points(point_string)?),
+        vec![],
+    ))
+}
+
+fn svg_polyline_to_geometry(point_string: &str) -> Result<LineString<f64>, SvgError> {
+    Ok(LineString(parse_points(point_string)?))
+}
+
+fn parse_points(point_string: &str) -> Result<Vec<Coordinate<f64>>, SvgError> {
+    if point_string.trim().is_empty() {
+        return Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+            "points", "empty",
+        )));
+    }
+    Ok(coordinate_pairs(point_string)?
+        .into_iter()
+        .map(|(x, y)| Coordinate { x, y })
+        .collect())
+}
+
+// Splits the value of a `points` attribute into (x, y) pairs. Spaces, tabs, newlines and a
+// single comma all separate numbers alike, and a sign or second decimal point starts a new
+// number ("10-5" or ".5.5"). svgtypes' PointsParser silently drops an unpaired last value,
+// so an odd count or a trailing comma is rejected here instead.
+fn coordinate_pairs(point_string: &str) -> Result<Vec<(f64, f64)>, SvgError> {
+    let invalid = |reason| SvgError::InvalidSvgError(InvalidSvgError::attribute("points", reason));
+    let is_space = |b: u8| matches!(b, b' ' | b'\t' | b'\n' | b'\r' | b'\x0C');
+    let bytes = point_string.as_bytes();
+    let skip_spaces = |mut i: usize| {
+        while i < bytes.len() && is_space(bytes[i]) {
+            i += 1;
+        }
+        i
+    };
+    let skip_digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut values = Vec::new();
+    let mut i = skip_spaces(0);
+    while i < bytes.len() {
+        let start = i;
+        if matches!(bytes[i], b'+' | b'-') {
+            i += 1;
+        }
+        let integer_end = skip_digits(i);
+        let mut end = integer_end;
+        if end < bytes.len() && bytes[end] == b'.' {
+            end = skip_digits(end + 1);
+        }
+        if end == i || (end == integer_end + 1 && integer_end == i) {
+            return Err(invalid("invalid"));
+        }
+        if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+            let mut exponent = end + 1;
+            if exponent < bytes.len() && matches!(bytes[exponent], b'+' | b'-') {
+                exponent += 1;
+            }
+            let exponent_end = skip_digits(exponent);
+            if exponent_end > exponent {
+                end = exponent_end;
+            }
+        }
+        let value = point_string[start..end]
+            .parse::<f64>()
+            .map_err(|_| invalid("invalid"))?;
+        values.push(value);
+
+        i = skip_spaces(end);
+        if i < bytes.len() && bytes[i] == b',' {
+            i = skip_spaces(i + 1);
+            if i == bytes.len() {
+                return Err(invalid("unpaired coordinate in"));
+            }
+        }
+    }
+    if values.len() % 2 != 0 {
+        return Err(invalid("unpaired coordinate in"));
+    }
+    Ok(values.chunks(2).map(|xy| (xy[0], xy[1])).collect())
+}
+
+// The largest distance between two coordinates still considered the same point, relative to
+// their magnitude (at least 1) so that float noise in large coordinates is tolerated too
+const COINCIDENT_TOLERANCE: f64 = 1e-9;
+
+fn coords_coincide(a: &Coordinate<f64>, b: &Coordinate<f64>) -> bool {
+    let magnitude = 1_f64.max(a.x.abs()).max(a.y.abs());
+    (a.x - b.x).hypot(a.y - b.y) <= COINCIDENT_TOLERANCE * magnitude
+}
+
+// Whether the line has enough points to enclose an area and ends where it started
+fn is_coincidently_closed(line: &LineString<f64>) -> bool {
+    match (line.0.first(), line.0.last()) {
+        (Some(first), Some(last)) if line.0.len() > 3 => coords_coincide(first, last),
+        _ => false,
+    }
+}
+
+// The width or height of a <rect>, which must not be negative
+fn rect_size_attr(
+    attrs: &HashMap<String, String>,
+    name: &str,
+    options: &ParseOptions,
+) -> Result<f64, SvgError> {
+    match required_f64_attr(attrs, name, options)? {
+        size if size.is_nan() || size < 0. => Err(SvgError::InvalidSvgError(
+            InvalidSvgError::attribute(name, "negative"),
+        )),
+        size => Ok(size),
+    }
+}
+
+fn svg_rect_to_geometry(x: f64, y: f64, width: f64, height: f64) -> Polygon<f64> {
+    // geo_types::Rect is not part of the enum Geometry, so we cast it to Polygon upon return
+    Polygon::from(Rect::new(
+        Coordinate::<f64> { x, y },
+        Coordinate::<f64> {
+            x: x + width,
+            y: y + height,
+        },
+    ))
+}
+
+// The corner radii of a <rect>, following the SVG rules: a missing (or `auto`) radius takes the
+// value of the other one, and each is clamped to half the side. Sharp corners give None.
+fn rect_corner_radii(
+    attrs: &HashMap<String, String>,
+    width: f64,
+    height: f64,
+    options: &ParseOptions,
+) -> Result<Option<(f64, f64)>, SvgError> {
+    let radius = |name: &str| match attrs.get(name).map(|value| value.trim()) {
+        None | Some("auto") => Ok(None),
+        Some(_) => match required_f64_attr(attrs, name, options)? {
+            r if r.is_nan() || r < 0. => Err(SvgError::InvalidSvgError(
+                InvalidSvgError::attribute(name, "negative"),
+            )),
+            r => Ok(Some(r)),
+        },
+    };
+    let (rx, ry) = match (radius("rx")?, radius("ry")?) {
+        (None, None) => return Ok(None),
+        (Some(rx), None) => (rx, rx),
+        (None, Some(ry)) => (ry, ry),
+        (Some(rx), Some(ry)) => (rx, ry),
+    };
+    let (rx, ry) = (rx.min(width / 2.), ry.min(height / 2.));
+    if rx > 0. && ry > 0. {
+        Ok(Some((rx, ry)))
     } else {
-        polys.into()
+        Ok(None)
+    }
+}
+
+fn svg_rounded_rect_to_geometry(
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    (rx, ry): (f64, f64),
+    flattening: &CurveFlattening,
+) -> Polygon<f64> {
+    // The corners in order, each as the center of its quarter ellipse, its start angle and the
+    // exact coordinates where it starts and ends
+    let quarter_turn = std::f64::consts::FRAC_PI_2;
+    let (max_x, max_y) = (x + width, y + height);
+    let corners = [
+        (
+            (max_x - rx, y + ry),
+            -quarter_turn,
+            (max_x - rx, y),
+            (max_x, y + ry),
+        ),
+        (
+            (max_x - rx, max_y - ry),
+            0.,
+            (max_x, max_y - ry),
+            (max_x - rx, max_y),
+        ),
+        (
+            (x + rx, max_y - ry),
+            quarter_turn,
+            (x + rx, max_y),
+            (x, max_y - ry),
+        ),
+        (
+            (x + rx, y + ry),
+            2. * quarter_turn,
+            (x, y + ry),
+            (x + rx, y),
+        ),
+    ];
+    let segments = arc_segment_count((rx, ry), quarter_turn, flattening);
+    let mut coords = vec![] as Vec<Coordinate<f64>>;
+    for ((cx, cy), start_angle, (start_x, start_y), (end_x, end_y)) in corners {
+        let mut arc =
+            arc_to_linestring(cx, cy, rx, ry, 0., start_angle, quarter_turn, segments + 1).0;
+        // Snap the ends of the arc onto the straight sides, without rounding drift
+        let last = arc.len() - 1;
+        arc[0] = Coordinate {
+            x: start_x,
+            y: start_y,
+        };
+        arc[last] = Coordinate { x: end_x, y: end_y };
+        coords.extend(arc);
+    }
+    // Sides of zero length (when a radius is half the side) would repeat a coordinate, and the
+    // last corner already closes the ring without a top side
+    coords.dedup();
+    if coords.first() != coords.last() {
+        coords.push(coords[0]);
+    }
+    Polygon::new(LineString(coords), vec![])
+}
+
+fn svg_circle_to_geometry(
+    cx: f64,
+    cy: f64,
+    r: f64,
+    flattening: &CurveFlattening,
+) -> Result<Polygon<f64>, SvgError> {
+    svg_ellipse_to_geometry(cx, cy, r, r, flattening)
+}
+
+fn svg_ellipse_to_geometry(
+    cx: f64,
+    cy: f64,
+    rx: f64,
+    ry: f64,
+    flattening: &CurveFlattening,
+) -> Result<Polygon<f64>, SvgError> {
+    if rx.is_nan() || rx < 0. || ry.is_nan() || ry < 0. {
+        return Err(SvgError::InvalidSvgError(InvalidSvgError::new(
+            "negative radius",
+        )));
+    }
+
+    // A ring needs at least three distinct points
+    let full_turn = 2. * std::f64::consts::PI;
+    let segments = arc_segment_count((rx, ry), full_turn, flattening).max(3);
+    let mut ring = arc_to_linestring(cx, cy, rx, ry, 0., 0., full_turn, segments + 1);
+    // Close the ring exactly, without rounding drift
+    let last = ring.0.len() - 1;
+    ring.0[last] = ring.0[0];
+    Ok(Polygon::new(ring, vec![]))
+}
+
+fn svg_line_to_geometry(start_x: &f64, start_y: &f64, end_x: &f64, end_y: &f64) -> Line<f64> {
+    Line::new(
+        Coordinate::<f64> {
+            x: *start_x,
+            y: *start_y,
+        },
+        Coordinate::<f64> {
+            x: *end_x,
+            y: *end_y,
+        },
+    )
+}
+
+/// How the curves in a `<path>` are approximated by straight line segments
+///
+/// However fine the flattening, a single curve is split into at most 2<sup>20</sup> segments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurveFlattening {
+    /// Sample every curve at a fixed number of evenly spaced `t` values, producing that many
+    /// segments per curve regardless of its size
+    Segments(usize),
+    /// Adaptively subdivide every curve until each segment is within the given distance (the
+    /// maximum chord error) of the true curve. This is the default, with a tolerance of 0.1.
+    /// The tolerance must be positive and finite, otherwise parsing fails with an
+    /// `SvgError::InvalidOption`.
+    Tolerance(f64),
+    /// Sample every curve at this many points, placing them by how sharply the curve turns, so
+    /// tight bends get most of the points and near-straight stretches few. Elliptical arcs are
+    /// sampled evenly by angle.
+    Curvature(usize),
+}
+
+// The maximum chord error of flattened curves, unless chosen otherwise
+const DEFAULT_CURVE_TOLERANCE: f64 = 0.1;
+
+impl Default for CurveFlattening {
+    fn default() -> Self {
+        CurveFlattening::Tolerance(DEFAULT_CURVE_TOLERANCE)
+    }
+}
+
+// A tolerance that is not a positive, finite distance would subdivide every curve as deeply as
+// allowed, so it is rejected before any curve is flattened
+fn check_curve_flattening(flattening: &CurveFlattening) -> Result<(), SvgError> {
+    match *flattening {
+        CurveFlattening::Tolerance(tolerance) if !(tolerance.is_finite() && tolerance > 0.) => {
+            Err(SvgError::InvalidOption {
+                what: format!(
+                    "the curve tolerance {} is not positive and finite",
+                    tolerance
+                ),
+            })
+        }
+        _ => Ok(()),
     }
 }
 
-/** Tests */
+/// How a nearly closed \<path\> subpath is closed with `ParseOptions::auto_close_rings`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AutoClose {
+    /// Append the start point to a subpath of at least three points, keeping its end
+    #[default]
+    Append,
+    /// Move the end point of a subpath of at least four points onto its start, without adding a
+    /// vertex, e.g., for data whose last point is meant to be the start but is off by rounding
+    Snap,
+}
+
+/// Options controlling how SVG input is parsed into Geometries
+///
+/// Options may be added in later versions, so start from `ParseOptions::default()` and set the
+/// fields you need.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Skip path data that cannot be parsed (e.g., non-standard commands) instead of failing,
+    /// recording a warning for every skipped token
+    pub lenient: bool,
+    /// How curves are flattened into coordinates
+    pub curve_flattening: CurveFlattening,
+    /// Return axis-aligned rectangular polygons as a `Rect` instead of a `Polygon`
+    pub prefer_rect: bool,
+    /// Return a \<polyline\> whose first and last points coincide (within 1e-9) as a closed
+    /// `Polygon` instead of a `LineString`
+    pub close_coincident_polylines: bool,
+    /// Close every \<path\> subpath whose ends are within this distance of each other (but not
+    /// equal) as `auto_close_mode` says, so it is read as a Polygon rather than a LineString
+    pub auto_close_rings: Option<f64>,
+    /// How `auto_close_rings` closes a nearly closed subpath
+    pub auto_close_mode: AutoClose,
+    /// Replace Unicode lookalikes of the characters used in SVG numbers (e.g., the minus sign
+    /// U+2212, dashes, and non-breaking spaces) with their ASCII equivalents before parsing
+    pub normalize_unicode: bool,
+    /// Fail with an `SvgError::LossyOperation` instead of approximating input that the Geometry
+    /// types cannot represent exactly, i.e., curve commands in a \<path\> and lengths with a unit
+    pub lossless: bool,
+    /// How overlapping and nested closed subpaths of a \<path\> are split into Polygons and
+    /// holes, by default `FillRule::NonZero` as in browsers, so a nested ring is only a hole if
+    /// it winds against the ring around it. A `fill-rule` on the \<path\> element, as an
+    /// attribute or in its `style`, takes precedence.
+    pub fill_rule: FillRule,
+    /// Return the rings of shapes with a `fill` of `none` (as an attribute or in their `style`),
+    /// which are drawn as outlines, as a `LineString` (or a `MultiLineString` for several rings)
+    /// instead of a `Polygon`
+    pub unfilled_as_lines: bool,
+    /// The width and height of the viewport, against which percentage lengths of shapes (e.g.,
+    /// `width="50%"`) are resolved. Without it, the viewport of a root `<svg>` element is used,
+    /// which is the size of its `viewBox`, or else its `width` and `height` if both are in user
+    /// units (or `px`), and percentage lengths are an error if there is none.
+    pub viewport: Option<(f64, f64)>,
+    /// Fail with an `InvalidSvgError` for any Polygon with a ring that has fewer than 4
+    /// coordinates or is not closed, e.g., a \<polygon\> of two points, rather than passing it
+    /// on to algorithms that expect valid Polygons
+    pub validate: bool,
+}
+
+/// Parses the `d`-string from an SVG `<path>` element into a GeometryCollection
+///
+/// **Note** that curves are flattened into straight line segments that lie within 0.1 units of
+/// the true curve.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_d_path_to_geometry_collection;
+/// use geo_types::polygon;
+///
+/// let poly = polygon!(
+///         exterior: [
+///             (x: 0.0, y: 0.0),
+///             (x: 0.0, y: 60.0),
+///             (x: 60.0, y: 60.0),
+///             (x: 60.0, y: 0.0),
+///             (x: 0.0, y: 0.0),],
+///         interiors:[[
+///             (x: 10.0, y: 10.0),
+///             (x: 40.0, y: 1.0),
+///             (x: 40.0, y: 40.0),
+///             (x: 10.50, y: 40.0),
+///             (x: 10.0, y: 10.0),]
+///             ]
+///         );
+///
+/// let svg_string = String::from("M0 0l0 60l60 0L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10");
+/// let parsed_svg = svg_d_path_to_geometry_collection(&svg_string);
+/// assert_eq!(parsed_svg.is_ok(), true);
+///
+/// // Unwrap the GeometryCollection result
+/// let geom = parsed_svg.ok().unwrap();
+/// assert_eq!(1, geom.0.len());
+///
+/// // Read the geometry as a Polygon
+/// let pl = geom.0[0].clone().into_polygon();
+/// assert_eq!(true, pl.is_some());
+/// assert_eq!(pl.unwrap(), poly);
+/// ```
+///
+pub fn svg_d_path_to_geometry_collection(svg: &str) -> Result<GeometryCollection<f64>, SvgError> {
+    svg_d_path_to_geometry_collection_with_options(svg, &ParseOptions::default())
+}
+
+/// Parses the `d`-string from an SVG `<path>` element into a GeometryCollection, as configured by
+/// the `ParseOptions`
+///
+/// Most notably, `options.curve_flattening` controls how finely curves are sampled: a small
+/// `CurveFlattening::Tolerance` for high-fidelity GIS work, or a coarse one (or a few
+/// `CurveFlattening::Segments`) for quick previews.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::{
+///     svg_d_path_to_geometry_collection_with_options, CurveFlattening, ParseOptions,
+/// };
+///
+/// let mut options = ParseOptions::default();
+/// options.curve_flattening = CurveFlattening::Segments(4);
+/// let parsed_svg = svg_d_path_to_geometry_collection_with_options("M0 0Q5 10 10 0", &options);
+/// assert!(parsed_svg.is_ok());
+///
+/// let line = parsed_svg.ok().unwrap().0[0].clone().into_line_string().unwrap();
+/// assert_eq!(5, line.0.len());
+/// ```
+///
+pub fn svg_d_path_to_geometry_collection_with_options(
+    svg: &str,
+    options: &ParseOptions,
+) -> Result<GeometryCollection<f64>, SvgError> {
+    let geom = parse_d_path(svg, options, &mut ParseReport::default())?;
+    if options.validate {
+        validate_polygons(&geom)?;
+    }
+    Ok(geom)
+}
+
+/// Parses the `d`-string from an SVG `<path>` element, supplied as bytes, into a
+/// GeometryCollection
+///
+/// The bytes are validated as UTF-8 in place, so data from a memory-mapped file or network
+/// buffer can be parsed without first copying it into a `String`. Bytes that are not valid UTF-8
+/// produce an `InvalidSvgError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_d_path_bytes_to_geometry_collection;
+///
+/// let parsed_svg = svg_d_path_bytes_to_geometry_collection(b"M0 0L0 60L60 60L60 0Z");
+/// assert!(parsed_svg.is_ok());
+///
+/// let geom = parsed_svg.ok().unwrap();
+/// assert!(geom.0[0].clone().into_polygon().is_some());
+/// ```
+///
+pub fn svg_d_path_bytes_to_geometry_collection(
+    d: &[u8],
+) -> Result<GeometryCollection<f64>, SvgError> {
+    match std::str::from_utf8(d) {
+        Ok(svg) => svg_d_path_to_geometry_collection(svg),
+        Err(_) => Err(SvgError::InvalidSvgError(InvalidSvgError::new(
+            "path data is not valid UTF-8",
+        ))),
+    }
+}
+
+/// Parses the `d`-string from an SVG `<path>` element into a GeometryCollection, flattening
+/// curves to a guaranteed maximum chord error
+///
+/// Every Bézier curve is recursively subdivided until each flattened segment lies within
+/// `max_error` of the true curve, so small curves produce few points and large curves as many as
+/// they need. `svg_d_path_to_geometry_collection` does the same with a maximum error of 0.1,
+/// whereas `CurveFlattening::Segments` in the `ParseOptions` samples a chosen number of points
+/// per curve without bounding the error. A `max_error` that is not positive and finite is an
+/// `SvgError::InvalidOption`.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_d_path_to_geometry_collection_with_tolerance;
+///
+/// let coarse = svg_d_path_to_geometry_collection_with_tolerance("M0 0C0 30 30 40 40 40", 1.0);
+/// let fine = svg_d_path_to_geometry_collection_with_tolerance("M0 0C0 30 30 40 40 40", 0.01);
+/// assert!(coarse.is_ok());
+/// assert!(fine.is_ok());
+///
+/// let coarse_line = coarse.ok().unwrap().0[0].clone().into_line_string().unwrap();
+/// let fine_line = fine.ok().unwrap().0[0].clone().into_line_string().unwrap();
+/// assert!(coarse_line.0.len() < fine_line.0.len());
+/// ```
+///
+pub fn svg_d_path_to_geometry_collection_with_tolerance(
+    svg: &str,
+    max_error: f64,
+) -> Result<GeometryCollection<f64>, SvgError> {
+    let options = ParseOptions {
+        curve_flattening: CurveFlattening::Tolerance(max_error),
+        ..Default::default()
+    };
+    svg_d_path_to_geometry_collection_with_options(svg, &options)
+}
+
+/// Parses the `d`-string from an SVG `<path>` element into a GeometryCollection, returning any
+/// warnings collected along the way
+///
+/// When `options.lenient` is set, path data that cannot be parsed (such as the non-standard
+/// `B`/`b` extension commands some generators emit) is skipped up to the next recognized
+/// command, and a warning describing the skipped data is added to the returned list. This
+/// preserves as much of the geometry as possible.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::{svg_d_path_to_geometry_collection_with_warnings, ParseOptions};
+/// use geo_types::polygon;
+///
+/// let poly = polygon![
+///         (x: 0.0, y: 0.0),
+///         (x: 10.0, y: 0.0),
+///         (x: 10.0, y: 10.0),
+///         (x: 0.0, y: 0.0),
+///     ];
+///
+/// let mut options = ParseOptions::default();
+/// options.lenient = true;
+/// let parsed_svg = svg_d_path_to_geometry_collection_with_warnings("M0 0L10 0B45L10 10Z", &options);
+/// assert!(parsed_svg.is_ok());
+///
+/// let (geom, warnings) = parsed_svg.ok().unwrap();
+/// assert_eq!(1, warnings.len());
+/// assert_eq!(geom.0[0].clone().into_polygon().unwrap(), poly);
+/// ```
+///
+pub fn svg_d_path_to_geometry_collection_with_warnings(
+    svg: &str,
+    options: &ParseOptions,
+) -> Result<(GeometryCollection<f64>, Vec<String>), SvgError> {
+    let mut report = ParseReport::default();
+    let geom = parse_d_path(svg, options, &mut report)?;
+    if options.validate {
+        validate_polygons(&geom)?;
+    }
+    Ok((geom, report.warnings))
+}
+
+fn parse_d_path(
+    svg: &str,
+    options: &ParseOptions,
+    report: &mut ParseReport,
+) -> Result<GeometryCollection<f64>, SvgError> {
+    let mut path_segments = parse_d_path_segments(svg, options, report)?;
+    if let Some(tolerance) = options.auto_close_rings {
+        for segment in path_segments.iter_mut() {
+            close_near_closed_ring(segment, tolerance, options.auto_close_mode);
+        }
+    }
+    Ok(parse_path_segments_to_geom(
+        &path_segments,
+        options.fill_rule,
+    ))
+}
+
+// The letter of a path command that can only be approximated by straight line segments
+fn curve_command(segment: &PathSegment) -> Option<char> {
+    let command = match segment {
+        PathSegment::CurveTo { .. } => 'C',
+        PathSegment::SmoothCurveTo { .. } => 'S',
+        PathSegment::Quadratic { .. } => 'Q',
+        PathSegment::SmoothQuadratic { .. } => 'T',
+        PathSegment::EllipticalArc { .. } => 'A',
+        _ => return None,
+    };
+    Some(if segment.is_relative() {
+        command.to_ascii_lowercase()
+    } else {
+        command
+    })
+}
+
+fn parse_d_path_segments(
+    svg: &str,
+    options: &ParseOptions,
+    report: &mut ParseReport,
+) -> Result<Vec<Vec<Coordinate<f64>>>, SvgError> {
+    check_curve_flattening(&options.curve_flattening)?;
+    let normalized: String;
+    let svg = if options.normalize_unicode {
+        normalized = normalize_unicode(svg);
+        normalized.as_str()
+    } else {
+        svg
+    };
+    // We will collect the separate paths (from M to M) into segments for parsing
+    let mut path_segments = vec![] as Vec<Vec<Coordinate<f64>>>;
+    let zero_coord = Coordinate { x: 0_f64, y: 0_f64 }; // Default values to be added to relative coords
+    let mut last_point: Option<Coordinate<f64>> = None; // Store last point for relative coordinates
+
+    // Store the last control points for S and T coordinates, which reflect the last cubic and the
+    // last quadratic control point respectively
+    let mut last_cubic_control_point: Option<Coord2> = None;
+    let mut last_quadratic_control_point: Option<Coord2> = None;
+    // Whether the current subpath was ended by a ClosePath
+    let mut subpath_closed = false;
+    for t in tokenize_d_path(svg, options, &mut report.warnings)? {
+        if options.lossless {
+            if let Some(command) = curve_command(&t) {
+                return Err(SvgError::LossyOperation {
+                    what: format!("flattening the {} curve command", command),
+                });
+            }
+        }
+        // S and T only reflect the control point of an immediately preceding curve of the same
+        // family, otherwise their first control point is the current point
+        let previous_cubic_control_point = last_cubic_control_point.take();
+        let previous_quadratic_control_point = last_quadratic_control_point.take();
+        // A drawing command after a ClosePath starts a new subpath at the initial point of the
+        // closed one, which is where the ClosePath left the current point
+        if subpath_closed {
+            match t {
+                PathSegment::MoveTo { .. } | PathSegment::ClosePath { .. } => (),
+                _ => {
+                    path_segments.push(vec![last_point.unwrap_or(zero_coord)]);
+                    subpath_closed = false;
+                }
+            }
+        }
+        match t {
+            // A relative first MoveTo is taken from the origin, i.e., as absolute, and one after a
+            // ClosePath from the start of the closed subpath, which is then the last point
+            PathSegment::MoveTo { .. } => {
+                let coord = Coordinate {
+                    x: if t.is_relative() {
+                        t.x().unwrap() + last_point.unwrap_or(zero_coord).x
+                    } else {
+                        t.x().unwrap()
+                    },
+                    y: if t.is_relative() {
+                        t.y().unwrap() + last_point.unwrap_or(zero_coord).y
+                    } else {
+                        t.y().unwrap()
+                    },
+                };
+                last_point = Some(coord);
+                path_segments.push(vec![coord]);
+                subpath_closed = false;
+            }
+            PathSegment::LineTo { .. } => {
+                let coord = Coordinate {
+                    x: if t.is_relative() {
+                        t.x().unwrap() + last_point.unwrap_or(zero_coord).x
+                    } else {
+                        t.x().unwrap()
+                    },
+                    y: if t.is_relative() {
+                        t.y().unwrap() + last_point.unwrap_or(zero_coord).y
+                    } else {
+                        t.y().unwrap()
+                    },
+                };
+                last_point = Some(coord);
+                current_segment(&mut path_segments).push(coord);
+            }
+            PathSegment::HorizontalLineTo { .. } => {
+                let coord = Coordinate {
+                    x: if t.is_relative() {
+                        t.x().unwrap() + last_point.unwrap_or(zero_coord).x
+                    } else {
+                        t.x().unwrap()
+                    },
+                    y: last_point.unwrap_or(zero_coord).y,
+                };
+                last_point = Some(coord);
+                current_segment(&mut path_segments).push(coord);
+            }
+            PathSegment::VerticalLineTo { .. } => {
+                let coord = Coordinate {
+                    x: last_point.unwrap_or(zero_coord).x,
+                    y: if t.is_relative() {
+                        t.y().unwrap() + last_point.unwrap_or(zero_coord).y
+                    } else {
+                        t.y().unwrap()
+                    },
+                };
+                last_point = Some(coord);
+                current_segment(&mut path_segments).push(coord);
+            }
+            PathSegment::CurveTo {
+                x,
+                x1,
+                x2,
+                y,
+                y1,
+                y2,
+                abs,
+            } => {
+                let last = last_point.unwrap_or(zero_coord);
+                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
+                let control_1 = calculate_svg_coord2(x1, y1, last, abs);
+                let control_2 = calculate_svg_coord2(x2, y2, last, abs);
+                last_cubic_control_point = Some(control_2);
+                let end_point = calculate_svg_coord2(x, y, last, abs);
+                let end = Coordinate {
+                    x: end_point.x(),
+                    y: end_point.y(),
+                };
+                last_point = Some(end);
+                report.curves_flattened += 1;
+                current_segment(&mut path_segments).extend(flatten_cubic_curve(
+                    start_point,
+                    control_1,
+                    control_2,
+                    end_point,
+                    &options.curve_flattening,
+                ));
+            }
+            PathSegment::SmoothCurveTo { x2, x, y2, y, abs } => {
+                let last = last_point.unwrap_or(zero_coord);
+                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
+                let control_1 =
+                    reflect_point(last, previous_cubic_control_point.unwrap_or(start_point));
+                let control_2 = calculate_svg_coord2(x2, y2, last, abs);
+                last_cubic_control_point = Some(control_2);
+                let end_point = calculate_svg_coord2(x, y, last, abs);
+                let end = Coordinate {
+                    x: end_point.x(),
+                    y: end_point.y(),
+                };
+                last_point = Some(end);
+                report.curves_flattened += 1;
+                current_segment(&mut path_segments).extend(flatten_cubic_curve(
+                    start_point,
+                    control_1,
+                    control_2,
+                    end_point,
+                    &options.curve_flattening,
+                ));
+            }
+            PathSegment::Quadratic { x1, x, y1, y, abs } => {
+                let last = last_point.unwrap_or(zero_coord);
+                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
+                let control_1 = calculate_svg_coord2(x1, y1, last, abs);
+                last_quadratic_control_point = Some(control_1);
+                let end_point = calculate_svg_coord2(x, y, last, abs);
+                let end = Coordinate {
+                    x: end_point.x(),
+                    y: end_point.y(),
+                };
+                last_point = Some(end);
+                report.curves_flattened += 1;
+                current_segment(&mut path_segments).extend(flatten_quadratic_curve(
+                    start_point,
+                    control_1,
+                    end_point,
+                    &options.curve_flattening,
+                ));
+            }
+            PathSegment::SmoothQuadratic { x, y, abs } => {
+                let last = last_point.unwrap_or(zero_coord);
+                let start_point = calculate_svg_coord2(last.x, last.y, last, true);
+                let control_1 = reflect_point(
+                    last,
+                    previous_quadratic_control_point.unwrap_or(start_point),
+                );
+                last_quadratic_control_point = Some(control_1);
+                let end_point = calculate_svg_coord2(x, y, last, abs);
+                let end = Coordinate {
+                    x: end_point.x(),
+                    y: end_point.y(),
+                };
+                last_point = Some(end);
+                report.curves_flattened += 1;
+                current_segment(&mut path_segments).extend(flatten_quadratic_curve(
+                    start_point,
+                    control_1,
+                    end_point,
+                    &options.curve_flattening,
+                ));
+            }
+            PathSegment::EllipticalArc {
+                abs,
+                rx,
+                ry,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                x,
+                y,
+            } => {
+                let last = last_point.unwrap_or(zero_coord);
+                let end = if abs {
+                    Coordinate { x, y }
+                } else {
+                    Coordinate {
+                        x: x + last.x,
+                        y: y + last.y,
+                    }
+                };
+                last_point = Some(end);
+                report.curves_flattened += 1;
+                current_segment(&mut path_segments).extend(flatten_elliptical_arc(
+                    last,
+                    end,
+                    (rx, ry),
+                    x_axis_rotation.to_radians(),
+                    large_arc,
+                    sweep,
+                    &options.curve_flattening,
+                ));
+            }
+            PathSegment::ClosePath { .. } => {
+                let segment = current_segment(&mut path_segments);
+                if let (Some(&coord), false) = (segment.first(), subpath_closed) {
+                    last_point = Some(coord);
+                    // A subpath that already returned to its start is not closed a second time,
+                    // and one that ends a rounding error away from it (e.g., after flattening
+                    // curves drawn with relative commands) is closed exactly on the start
+                    let length = segment.len();
+                    if length == 1 || !coords_coincide(&segment[length - 1], &coord) {
+                        segment.push(coord);
+                    } else {
+                        segment[length - 1] = coord;
+                    }
+                    subpath_closed = true;
+                }
+            }
+        }
+    }
+    if path_segments.is_empty() {
+        return Err(SvgError::InvalidSvgError(InvalidSvgError::attribute(
+            "d", "empty",
+        )));
+    }
+    Ok(path_segments)
+}
+
+// Closes a subpath whose ends nearly coincide, if it has enough points to be left with a ring of
+// some area
+fn close_near_closed_ring(segment: &mut Vec<Coordinate<f64>>, tolerance: f64, mode: AutoClose) {
+    let min_points = match mode {
+        AutoClose::Append => 3,
+        AutoClose::Snap => 4,
+    };
+    if segment.len() < min_points {
+        return;
+    }
+    let first = segment[0];
+    let last = segment.len() - 1;
+    let end = segment[last];
+    if first == end || (first.x - end.x).hypot(first.y - end.y) > tolerance {
+        return;
+    }
+    match mode {
+        AutoClose::Append => segment.push(first),
+        AutoClose::Snap => segment[last] = first,
+    }
+}
+
+// The subpath that drawing commands currently add to. Path data always starts with a MoveTo,
+// but a subpath is opened regardless so that no coordinates can be lost.
+fn current_segment(path_segments: &mut Vec<Vec<Coordinate<f64>>>) -> &mut Vec<Coordinate<f64>> {
+    if path_segments.is_empty() {
+        path_segments.push(vec![]);
+    }
+    path_segments.last_mut().unwrap()
+}
+
+fn tokenize_d_path(
+    svg: &str,
+    options: &ParseOptions,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<PathSegment>, SvgError> {
+    let mut segments = vec![] as Vec<PathSegment>;
+    let mut data = String::from(svg);
+    // svgtypes stops at the first error, so in lenient mode parsing is restarted after the
+    // invalid data. The restarted data is prefixed with a MoveTo (path data must start with one),
+    // which is discarded again.
+    let mut resumed = false;
+    loop {
+        let mut resume_at: Option<usize> = None;
+        for (i, token) in PathParser::from(data.as_str()).enumerate() {
+            if !options.lenient {
+                segments.push(token.map_err(SvgError::PathParseError)?);
+                continue;
+            }
+            match token {
+                Ok(t) => {
+                    if !(resumed && i == 0) {
+                        segments.push(t)
+                    }
+                }
+                Err(e) => {
+                    let error_at = match e {
+                        svgtypes::Error::UnexpectedData(pos)
+                        | svgtypes::Error::InvalidChar(_, pos)
+                        | svgtypes::Error::InvalidString(_, pos)
+                        | svgtypes::Error::InvalidNumber(pos) => {
+                            data.char_indices().nth(pos - 1).map(|(idx, _)| idx)
+                        }
+                        _ => None,
+                    };
+                    match error_at {
+                        Some(start) => {
+                            let end = data[start..]
+                                .char_indices()
+                                .skip(1)
+                                .find(|(_, c)| "MmZzLlHhVvCcSsQqTtAa".contains(*c))
+                                .map_or(data.len(), |(idx, _)| start + idx);
+                            warnings.push(format!(
+                                "skipped invalid path data \"{}\"",
+                                data[start..end].trim()
+                            ));
+                            resume_at = Some(end);
+                        }
+                        None => warnings.push(format!("skipped invalid path data: {}", e)),
+                    }
+                    break;
+                }
+            }
+        }
+        match resume_at {
+            Some(end) if end < data.len() => {
+                data = format!("M0 0{}", &data[end..]);
+                resumed = true;
+            }
+            _ => break,
+        }
+    }
+    Ok(segments)
+}
+
+/// A closed subpath's coordinates and its winding direction (`1`, `-1`, or `0` if degenerate)
+pub type WoundSubpath = (Vec<Coordinate<f64>>, i32);
+
+/// Returns every closed subpath of the `d`-string from an SVG `<path>` element together with its
+/// winding direction
+///
+/// The winding direction is the sign of the subpath's signed (shoelace) area: `1` for rings that
+/// are counter-clockwise in a y-up coordinate system (which appear clockwise on screen, since SVG
+/// is y-down), `-1` for the opposite direction and `0` for degenerate rings without area. Under
+/// the `nonzero` fill rule, a point is filled when the windings of the rings containing it do not
+/// sum to zero, so this shows how each subpath contributes to the filled region. Open subpaths do
+/// not enclose any area and are left out.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_d_path_subpaths_with_winding;
+///
+/// let parsed_svg = svg_d_path_subpaths_with_winding("M0 0L10 0L10 10L0 10ZM2 2L2 8L8 8L8 2Z");
+/// assert!(parsed_svg.is_ok());
+///
+/// let windings = parsed_svg
+///     .ok()
+///     .unwrap()
+///     .iter()
+///     .map(|(_, winding)| *winding)
+///     .collect::<Vec<i32>>();
+/// assert_eq!(vec![1, -1], windings);
+/// ```
+///
+pub fn svg_d_path_subpaths_with_winding(svg: &str) -> Result<Vec<WoundSubpath>, SvgError> {
+    let path_segments =
+        parse_d_path_segments(svg, &ParseOptions::default(), &mut ParseReport::default())?;
+    Ok(path_segments
+        .into_iter()
+        .filter(|segment| segment.len() > 2 && segment.first() == segment.last())
+        .map(|segment| {
+            let winding = ring_winding(&segment);
+            (segment, winding)
+        })
+        .collect())
+}
+
+/// Parses the `d`-string from an SVG `<path>` element into a separate Geometry for every piece
+///
+/// Unlike `svg_d_path_to_geometry_collection`, the pieces are never combined into a
+/// `MultiLineString` or `MultiPolygon`: every subpath with two points becomes a `Line` and every
+/// other open subpath a `LineString`, in the order they appear in the path. They are followed by
+/// the `Polygon`s built from the closed subpaths.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_d_path_to_geometry_parts;
+///
+/// let parsed_svg = svg_d_path_to_geometry_parts("M0 0L10 0M0 10L10 10M0 20L10 20");
+/// assert!(parsed_svg.is_ok());
+///
+/// let parts = parsed_svg.ok().unwrap();
+/// assert_eq!(3, parts.len());
+/// assert!(parts.iter().all(|part| part.clone().into_line().is_some()));
+/// ```
+///
+pub fn svg_d_path_to_geometry_parts(svg: &str) -> Result<Vec<Geometry<f64>>, SvgError> {
+    let path_segments =
+        parse_d_path_segments(svg, &ParseOptions::default(), &mut ParseReport::default())?;
+    let mut parts = vec![] as Vec<Geometry<f64>>;
+    let mut poly_line_strings = vec![] as Vec<LineString<f64>>;
+    for path in path_segments {
+        if path.len() < 2 {
+            continue;
+        } else if path.len() == 2 {
+            parts.push(Line::new(path[0], path[1]).into());
+        } else if path.first() != path.last() {
+            parts.push(LineString(path).into());
+        } else {
+            poly_line_strings.push(LineString(path));
+        }
+    }
+
+    if poly_line_strings.len() == 1 {
+        parts.push(Polygon::new(poly_line_strings.remove(0), vec![]).into());
+    } else {
+        parts.extend(
+            parse_polygon_rings_to_geom(&poly_line_strings, FillRule::EvenOdd)
+                .0
+                .into_iter()
+                .map(Geometry::Polygon),
+        );
+    }
+    Ok(parts)
+}
+
+/// Parses the `d`-string from an SVG `<path>` element into a single Geometry
+///
+/// **Note** that curves are flattened into straight line segments that lie within 0.1 units of
+/// the true curve.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_svg_io::geo_svg_reader::svg_d_path_to_geometry;
+/// use geo_types::polygon;
+///
+/// let poly = polygon!(
+///         exterior: [
+///             (x: 0.0, y: 0.0),
+///             (x: 0.0, y: 60.0),
+///             (x: 60.0, y: 60.0),
+///             (x: 60.0, y: 0.0),
+///             (x: 0.0, y: 0.0),],
+///         interiors:[[
+///             (x: 10.0, y: 10.0),
+///             (x: 40.0, y: 1.0),
+///             (x: 40.0, y: 40.0),
+///             (x: 10.50, y: 40.0),
+///             (x: 10.0, y: 10.0),]
+///             ]
+///         );
+///
+/// let svg_string = String::from("M0 0l0 60l60 0L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10");
+/// let parsed_svg = svg_d_path_to_geometry(&svg_string);
+/// assert!(parsed_svg.is_ok());
+/// let pl = parsed_svg.ok().unwrap().into_polygon();
+/// assert!(pl.is_some());
+/// assert_eq!(pl.unwrap(), poly);
+/// ```
+///
+pub fn svg_d_path_to_geometry(svg: &str) -> Result<Geometry<f64>, SvgError> {
+    let gc = svg_d_path_to_geometry_collection(svg)?;
+    if gc.0.len() == 1 {
+        return Ok(gc.0[0].clone());
+    }
+    Err(SvgError::SvgGeomCollectionForGeometry(
+        SvgGeometryCollectionForGeometryError,
+    ))
+}
+
+fn flatten_cubic_curve(
+    start: Coord2,
+    control_1: Coord2,
+    control_2: Coord2,
+    end: Coord2,
+    flattening: &CurveFlattening,
+) -> Vec<Coordinate<f64>> {
+    match *flattening {
+        CurveFlattening::Segments(segments) => {
+            let segments = capped_segments(segments);
+            let mut coords = (1..segments)
+                .map(|x| {
+                    let arc_point =
+                        de_casteljau4(x as f64 / segments as f64, start, control_1, control_2, end);
+                    Coordinate {
+                        x: arc_point.x(),
+                        y: arc_point.y(),
+                    }
+                })
+                .collect::<Vec<Coordinate<f64>>>();
+            coords.push(Coordinate {
+                x: end.x(),
+                y: end.y(),
+            });
+            coords
+        }
+        CurveFlattening::Tolerance(tolerance) => {
+            flatten_cubic(start, control_1, control_2, end, tolerance)
+        }
+        CurveFlattening::Curvature(segments) => flatten_by_curvature(
+            |t| de_casteljau4(t, start, control_1, control_2, end),
+            segments,
+        ),
+    }
+}
+
+fn flatten_quadratic_curve(
+    start: Coord2,
+    control: Coord2,
+    end: Coord2,
+    flattening: &CurveFlattening,
+) -> Vec<Coordinate<f64>> {
+    match *flattening {
+        CurveFlattening::Segments(segments) => {
+            let segments = capped_segments(segments);
+            let mut coords = (1..segments)
+                .map(|x| {
+                    let arc_point = de_casteljau3(x as f64 / segments as f64, start, control, end);
+                    Coordinate {
+                        x: arc_point.x(),
+                        y: arc_point.y(),
+                    }
+                })
+                .collect::<Vec<Coordinate<f64>>>();
+            coords.push(Coordinate {
+                x: end.x(),
+                y: end.y(),
+            });
+            coords
+        }
+        CurveFlattening::Tolerance(tolerance) => flatten_quadratic(start, control, end, tolerance),
+        CurveFlattening::Curvature(segments) => {
+            flatten_by_curvature(|t| de_casteljau3(t, start, control, end), segments)
+        }
+    }
+}
+
+// The most straight segments a single curve is flattened into, however small the tolerance or
+// large the requested number of segments, so that the count of coordinates stays allocatable
+const MAX_CURVE_SEGMENTS: usize = 1 << 20;
+
+fn capped_segments(segments: usize) -> usize {
+    segments.clamp(1, MAX_CURVE_SEGMENTS)
+}
+
+// The number of evenly spaced `t` values at which the turning of a curve is measured
+const CURVATURE_SAMPLES: usize = 256;
+
+// The share of the points spread evenly along `t` on top of those placed by curvature, so
+// straight stretches between bends still get some points
+const UNIFORM_SHARE: f64 = 0.25;
+
+/// Flattens a curve into `segments` coordinates following its start, spacing them in `t` so that
+/// the curve turns by about the same angle between any two consecutive coordinates
+fn flatten_by_curvature(point_at: impl Fn(f64) -> Coord2, segments: usize) -> Vec<Coordinate<f64>> {
+    let segments = capped_segments(segments);
+    let points = (0..=CURVATURE_SAMPLES)
+        .map(|i| point_at(i as f64 / CURVATURE_SAMPLES as f64))
+        .collect::<Vec<Coord2>>();
+    // The angle the curve turns by at every inner sample
+    let mut turns = vec![0.; CURVATURE_SAMPLES + 1];
+    for i in 1..CURVATURE_SAMPLES {
+        let (before, at, after) = (points[i - 1], points[i], points[i + 1]);
+        let (x1, y1) = (at.x() - before.x(), at.y() - before.y());
+        let (x2, y2) = (after.x() - at.x(), after.y() - at.y());
+        turns[i] = (x1 * y2 - y1 * x2).atan2(x1 * x2 + y1 * y2).abs();
+    }
+    let total_turn = turns.iter().sum::<f64>();
+    let uniform_weight = if total_turn > 0. {
+        total_turn * UNIFORM_SHARE / CURVATURE_SAMPLES as f64
+    } else {
+        1.
+    };
+    let weights = turns
+        .windows(2)
+        .map(|pair| (pair[0] + pair[1]) / 2. + uniform_weight)
+        .collect::<Vec<f64>>();
+    let total_weight = weights.iter().sum::<f64>();
+
+    // Invert the cumulative weight to find the `t` of every evenly spaced share of the weight
+    let mut coords = vec![] as Vec<Coordinate<f64>>;
+    let mut interval = 0;
+    let mut cumulative = 0.;
+    for k in 1..segments {
+        let target = k as f64 / segments as f64 * total_weight;
+        while interval < CURVATURE_SAMPLES - 1 && cumulative + weights[interval] < target {
+            cumulative += weights[interval];
+            interval += 1;
+        }
+        let within = ((target - cumulative) / weights[interval]).clamp(0., 1.);
+        let point = point_at((interval as f64 + within) / CURVATURE_SAMPLES as f64);
+        coords.push(Coordinate {
+            x: point.x(),
+            y: point.y(),
+        });
+    }
+    let end = point_at(1.);
+    coords.push(Coordinate {
+        x: end.x(),
+        y: end.y(),
+    });
+    coords
+}
+
+// Guards against endless subdivision of curves with non-finite coordinates, and keeps subdivided
+// curves within MAX_CURVE_SEGMENTS, as each level of subdivision doubles the segments
+const MAX_SUBDIVISION_DEPTH: usize = MAX_CURVE_SEGMENTS.trailing_zeros() as usize;
+
+/// Flattens a cubic Bézier into the coordinates following `start`, subdividing until the control
+/// points (and thus, by the convex hull property, the whole curve) are within `tolerance` of
+/// each chord
+fn flatten_cubic(
+    start: Coord2,
+    control_1: Coord2,
+    control_2: Coord2,
+    end: Coord2,
+    tolerance: f64,
+) -> Vec<Coordinate<f64>> {
+    let mut coords = vec![] as Vec<Coordinate<f64>>;
+    subdivide_cubic(start, control_1, control_2, end, tolerance, 0, &mut coords);
+    coords
+}
+
+fn subdivide_cubic(
+    start: Coord2,
+    control_1: Coord2,
+    control_2: Coord2,
+    end: Coord2,
+    tolerance: f64,
+    depth: usize,
+    coords: &mut Vec<Coordinate<f64>>,
+) {
+    let flat = distance_to_chord(control_1, start, end) <= tolerance
+        && distance_to_chord(control_2, start, end) <= tolerance;
+    if flat || depth >= MAX_SUBDIVISION_DEPTH {
+        coords.push(Coordinate {
+            x: end.x(),
+            y: end.y(),
+        });
+        return;
+    }
+
+    // Split the curve in half with de Casteljau's algorithm
+    let p01 = midpoint(start, control_1);
+    let p12 = midpoint(control_1, control_2);
+    let p23 = midpoint(control_2, end);
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+    let mid = midpoint(p012, p123);
+    subdivide_cubic(start, p01, p012, mid, tolerance, depth + 1, coords);
+    subdivide_cubic(mid, p123, p23, end, tolerance, depth + 1, coords);
+}
+
+/// Flattens a quadratic Bézier into the coordinates following `start`, subdividing until the
+/// control point is within `tolerance` of each chord
+fn flatten_quadratic(
+    start: Coord2,
+    control: Coord2,
+    end: Coord2,
+    tolerance: f64,
+) -> Vec<Coordinate<f64>> {
+    let mut coords = vec![] as Vec<Coordinate<f64>>;
+    subdivide_quadratic(start, control, end, tolerance, 0, &mut coords);
+    coords
+}
+
+fn subdivide_quadratic(
+    start: Coord2,
+    control: Coord2,
+    end: Coord2,
+    tolerance: f64,
+    depth: usize,
+    coords: &mut Vec<Coordinate<f64>>,
+) {
+    if distance_to_chord(control, start, end) <= tolerance || depth >= MAX_SUBDIVISION_DEPTH {
+        coords.push(Coordinate {
+            x: end.x(),
+            y: end.y(),
+        });
+        return;
+    }
+
+    let p01 = midpoint(start, control);
+    let p12 = midpoint(control, end);
+    let mid = midpoint(p01, p12);
+    subdivide_quadratic(start, p01, mid, tolerance, depth + 1, coords);
+    subdivide_quadratic(mid, p12, end, tolerance, depth + 1, coords);
+}
+
+fn midpoint(a: Coord2, b: Coord2) -> Coord2 {
+    Coord2((a.x() + b.x()) / 2., (a.y() + b.y()) / 2.)
+}
+
+/// The distance from `point` to the line segment running from `start` to `end`
+fn distance_to_chord(point: Coord2, start: Coord2, end: Coord2) -> f64 {
+    let dx = end.x() - start.x();
+    let dy = end.y() - start.y();
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == 0. {
+        return (point.x() - start.x()).hypot(point.y() - start.y());
+    }
+    let t = (((point.x() - start.x()) * dx + (point.y() - start.y()) * dy) / length_squared)
+        .clamp(0., 1.);
+    (point.x() - (start.x() + t * dx)).hypot(point.y() - (start.y() + t * dy))
+}
+
+/// Returns the points along an elliptical arc as a LineString
+///
+/// The ellipse is centered on `cx`, `cy` with the radii `rx` and `ry`, and its x-axis is rotated
+/// by `rotation`. The arc begins at the parametric `start_angle` and runs through `sweep_angle`
+/// (positive sweeps run from the positive x-axis towards the positive y-axis). All angles are in
+/// radians. The arc is sampled at `samples` evenly spaced angles, including both ends, with a
+/// minimum of 2.
+///
+/// This is the building block used to flatten the `A`/`a` commands of a `<path>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::f64::consts::PI;
+/// use geo_svg_io::geo_svg_reader::arc_to_linestring;
+///
+/// // A quarter of a circle with a radius of 10
+/// let arc = arc_to_linestring(0.0, 0.0, 10.0, 10.0, 0.0, 0.0, PI / 2.0, 3);
+/// assert_eq!(3, arc.0.len());
+/// assert!((arc.0[1].x - 50_f64.sqrt()).abs() < 1e-9);
+/// assert!((arc.0[1].y - 50_f64.sqrt()).abs() < 1e-9);
+/// assert!((arc.0[2].x - 0.0).abs() < 1e-9);
+/// assert!((arc.0[2].y - 10.0).abs() < 1e-9);
+/// ```
+///
+#[allow(clippy::too_many_arguments)]
+pub fn arc_to_linestring(
+    cx: f64,
+    cy: f64,
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    start_angle: f64,
+    sweep_angle: f64,
+    samples: usize,
+) -> LineString<f64> {
+    let samples = samples.max(2);
+    let (sin_rotation, cos_rotation) = rotation.sin_cos();
+    (0..samples)
+        .map(|i| {
+            let angle = start_angle + sweep_angle * i as f64 / (samples - 1) as f64;
+            let (sin, cos) = angle.sin_cos();
+            Coordinate {
+                x: cx + rx * cos * cos_rotation - ry * sin * sin_rotation,
+                y: cy + rx * cos * sin_rotation + ry * sin * cos_rotation,
+            }
+        })
+        .collect()
+}
+
+// The number of straight segments an elliptical arc sweeping the angle (in radians) is flattened
+// into
+fn arc_segment_count(radii: (f64, f64), sweep_angle: f64, flattening: &CurveFlattening) -> usize {
+    match *flattening {
+        CurveFlattening::Segments(segments) | CurveFlattening::Curvature(segments) => {
+            capped_segments(segments)
+        }
+        CurveFlattening::Tolerance(tolerance) => {
+            // The chord of an angle step deviates from a circle of the larger radius by
+            // r * (1 - cos(step / 2)), which bounds the deviation from the ellipse as well
+            let radius = radii.0.max(radii.1);
+            if tolerance >= radius {
+                1
+            } else {
+                // A float beyond the range of usize (or NaN) saturates when cast
+                let step = 2. * (1. - tolerance / radius).acos();
+                capped_segments((sweep_angle.abs() / step).ceil() as usize)
+            }
+        }
+    }
+}
+
+/// Flattens the arc of a `<path>` into the coordinates following `start`, converting the SVG
+/// endpoint parameterization to a center parameterization as described in the SVG
+/// implementation notes (https://www.w3.org/TR/SVG11/implnote.html#ArcImplementationNotes)
+fn flatten_elliptical_arc(
+    start: Coordinate<f64>,
+    end: Coordinate<f64>,
+    radii: (f64, f64),
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    flattening: &CurveFlattening,
+) -> Vec<Coordinate<f64>> {
+    // An arc to the current point is omitted, and one without radii is a straight line
+    if start == end {
+        return vec![];
+    }
+    let (mut rx, mut ry) = (radii.0.abs(), radii.1.abs());
+    if rx == 0. || ry == 0. {
+        return vec![end];
+    }
+
+    // The start point in a coordinate system centered between both points, aligned with the
+    // ellipse axes
+    let (sin_rotation, cos_rotation) = rotation.sin_cos();
+    let half_dx = (start.x - end.x) / 2.;
+    let half_dy = (start.y - end.y) / 2.;
+    let x1 = cos_rotation * half_dx + sin_rotation * half_dy;
+    let y1 = -sin_rotation * half_dx + cos_rotation * half_dy;
+
+    // Radii too small to reach the end are scaled up until they just do
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1. {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let sign = if large_arc == sweep { -1. } else { 1. };
+    let coefficient = sign * (numerator / denominator).max(0.).sqrt();
+    let center_x1 = coefficient * rx * y1 / ry;
+    let center_y1 = -coefficient * ry * x1 / rx;
+    let cx = cos_rotation * center_x1 - sin_rotation * center_y1 + (start.x + end.x) / 2.;
+    let cy = sin_rotation * center_x1 + cos_rotation * center_y1 + (start.y + end.y) / 2.;
+
+    let vector_angle =
+        |ux: f64, uy: f64, vx: f64, vy: f64| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    let start_x = (x1 - center_x1) / rx;
+    let start_y = (y1 - center_y1) / ry;
+    let start_angle = vector_angle(1., 0., start_x, start_y);
+    let mut sweep_angle = vector_angle(
+        start_x,
+        start_y,
+        (-x1 - center_x1) / rx,
+        (-y1 - center_y1) / ry,
+    );
+    if !sweep && sweep_angle > 0. {
+        sweep_angle -= 2. * std::f64::consts::PI;
+    } else if sweep && sweep_angle < 0. {
+        sweep_angle += 2. * std::f64::consts::PI;
+    }
+
+    let segments = arc_segment_count((rx, ry), sweep_angle, flattening);
+
+    let mut coords = arc_to_linestring(
+        cx,
+        cy,
+        rx,
+        ry,
+        rotation,
+        start_angle,
+        sweep_angle,
+        segments + 1,
+    )
+    .0;
+    coords.remove(0);
+    // End exactly on the end point, without rounding drift
+    if let Some(last) = coords.last_mut() {
+        *last = end;
+    }
+    coords
+}
+
+fn calculate_svg_coord2(x: f64, y: f64, last: Coordinate<f64>, abs: bool) -> Coord2 {
+    Coord2(
+        if abs { x } else { last.x + x },
+        if abs { y } else { last.y + y },
+    )
+}
+
+fn reflect_point(orig: Coordinate<f64>, pr: Coord2) -> Coord2 {
+    let x_step = pr.x() - orig.x;
+    let y_step = pr.y() - orig.y;
+
+    Coord2(orig.x - x_step, orig.y - y_step)
+}
+
+fn parse_path_segments_to_geom(
+    paths: &Vec<Vec<Coordinate<f64>>>,
+    fill_rule: FillRule,
+) -> GeometryCollection<f64> {
+    let mut lines = vec![] as Vec<Line<f64>>;
+    let mut line_strings = vec![] as Vec<LineString<f64>>;
+    let mut poly_line_strings = vec![] as Vec<LineString<f64>>;
+    let mut polygons: MultiPolygon<f64> = (vec![] as Vec<Polygon<f64>>).into();
+
+    for path in paths {
+        let length = path.len();
+        // A subpath that never leaves its start, e.g., `M5 5Z` or `M5 5L5 5`, has no extent. This
+        // is tested exactly, as the relative tolerance of coinciding ends grows with the magnitude
+        // of the coordinates and would drop short subpaths far from the origin.
+        if path.iter().all(|coord| *coord == path[0]) {
+            continue;
+        } else if length == 2 {
+            lines.push(Line::new(path[0], path[1]));
+        } else if !coords_coincide(&path[0], &path[length - 1]) {
+            line_strings.push(path.clone().into());
+        } else {
+            // Relative commands and curve flattening can leave the end a rounding error away
+            // from the start, so the ring is closed exactly
+            let mut ring = path.clone();
+            ring[length - 1] = ring[0];
+            poly_line_strings.push(ring.into());
+        }
+    }
+
+    if !poly_line_strings.is_empty() {
+        if poly_line_strings.len() == 1 {
+            polygons = Polygon::new(poly_line_strings[0].clone(), vec![]).into();
+        } else {
+            polygons = parse_polygon_rings_to_geom(&poly_line_strings, fill_rule);
+        }
+    }
+
+    // A path mixing lines, open and closed subpaths produces one Geometry of each type
+    let mut geom_collection = vec![] as Vec<Geometry<f64>>;
+    if !lines.is_empty() {
+        geom_collection.push(map_lines_to_geometry(&lines));
+    }
+    if !line_strings.is_empty() {
+        geom_collection.push(map_line_strings_to_geometry(&line_strings));
+    }
+    if !polygons.0.is_empty() {
+        geom_collection.push(map_polygons_to_geometry(polygons));
+    }
+
+    GeometryCollection(geom_collection)
+}
+
+fn parse_polygon_rings_to_geom(
+    rings: &Vec<LineString<f64>>,
+    fill_rule: FillRule,
+) -> MultiPolygon<f64> {
+    // Early return for empty vector
+    if rings.len() == 0 {
+        return (vec![] as Vec<Polygon<f64>>).into();
+    }
+
+    // Visit the rings from the largest to the smallest, so every ring that contains another one
+    // has been classified before it
+    let mut order = (0..rings.len()).collect::<Vec<usize>>();
+    order.sort_by(|a, b| {
+        ring_area(&rings[*b])
+            .partial_cmp(&ring_area(&rings[*a]))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Whether the area enclosed by rings with this sum of winding directions is filled
+    let windings = rings
+        .iter()
+        .map(|ring| ring_winding(&ring.0))
+        .collect::<Vec<i32>>();
+    let is_filled = |winding: i32, rings: usize| match fill_rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => rings % 2 == 1,
+    };
+
+    // The exterior every ring belongs to. A ring with a filled inside and an unfilled outside is
+    // an exterior, and one with an unfilled inside and a filled outside a hole of the smallest
+    // exterior containing it. A ring with the same fill on both sides does not bound anything.
+    let mut exteriors = vec![None as Option<usize>; rings.len()];
+    for (position, &ring) in order.iter().enumerate() {
+        let containers = order[..position]
+            .iter()
+            .copied()
+            .filter(|&other| ring_contains_ring(&rings[other], &rings[ring]))
+            .collect::<Vec<usize>>();
+        let winding = containers.iter().map(|&other| windings[other]).sum::<i32>();
+        let outside = is_filled(winding, containers.len());
+        let inside = is_filled(winding + windings[ring], containers.len() + 1);
+        exteriors[ring] = match (outside, inside) {
+            (false, true) => Some(ring),
+            (true, false) => containers
+                .iter()
+                .rev()
+                .find(|&&other| exteriors[other] == Some(other))
+                .copied(),
+            _ => None,
+        };
+    }
+
+    // Polygons and their holes keep the order of the rings in the path
+    (0..rings.len())
+        .filter(|&ring| exteriors[ring] == Some(ring))
+        .map(|exterior| {
+            let interiors = (0..rings.len())
+                .filter(|&ring| ring != exterior && exteriors[ring] == Some(exterior))
+                .map(|ring| rings[ring].clone())
+                .collect();
+            Polygon::new(rings[exterior].clone(), interiors)
+        })
+        .collect::<Vec<Polygon<f64>>>()
+        .into()
+}
+
+// The winding direction of a closed ring: the sign of its signed (shoelace) area
+fn ring_winding(ring: &[Coordinate<f64>]) -> i32 {
+    let area = ring
+        .windows(2)
+        .map(|pair| pair[0].x * pair[1].y - pair[1].x * pair[0].y)
+        .sum::<f64>();
+    if area > 0. {
+        1
+    } else if area < 0. {
+        -1
+    } else {
+        0
+    }
+}
+
+// The unsigned (shoelace) area of a closed ring
+fn ring_area(ring: &LineString<f64>) -> f64 {
+    ring.0
+        .windows(2)
+        .map(|pair| pair[0].x * pair[1].y - pair[1].x * pair[0].y)
+        .sum::<f64>()
+        .abs()
+        / 2.
+}
+
+// Whether `inner` lies inside `outer`. Rings of a path do not cross, so this tests the first
+// vertex of `inner` that is not on the boundary of `outer`.
+fn ring_contains_ring(outer: &LineString<f64>, inner: &LineString<f64>) -> bool {
+    match inner.0.iter().find(|c| !outer.0.contains(c)) {
+        Some(c) => ring_contains_point(outer, *c),
+        None => false,
+    }
+}
+
+// Ray casting: a point is inside the ring if a ray from it crosses the ring an odd number of times
+fn ring_contains_point(ring: &LineString<f64>, point: Coordinate<f64>) -> bool {
+    ring.lines()
+        .filter(|line| {
+            (line.start.y > point.y) != (line.end.y > point.y)
+                && point.x
+                    < line.start.x
+                        + (point.y - line.start.y) / (line.end.y - line.start.y)
+                            * (line.end.x - line.start.x)
+        })
+        .count()
+        % 2
+        == 1
+}
+
+fn map_lines_to_geometry(lines: &Vec<Line<f64>>) -> Geometry<f64> {
+    if lines.len() == 1 {
+        lines[0].into()
+    } else {
+        let multi_line: MultiLineString<f64> = lines
+            .iter()
+            .map(|x| LineString(vec![x.start, x.end]))
+            .collect();
+        multi_line.into()
+    }
+}
+
+fn map_line_strings_to_geometry(line_strings: &Vec<LineString<f64>>) -> Geometry<f64> {
+    if line_strings.len() == 1 {
+        line_strings[0].clone().into()
+    } else {
+        let multi_line: MultiLineString<f64> = MultiLineString(line_strings.to_vec());
+        multi_line.into()
+    }
+}
+
+fn map_polygons_to_geometry(polys: MultiPolygon<f64>) -> Geometry<f64> {
+    if polys.0.len() == 1 {
+        polys.0[0].clone().into()
+    } else {
+        polys.into()
+    }
+}
+
+/** Coordinate types */
+
+/// Returns a GeometryCollection with coordinates of type `T` parsed from the submitted SVG element
+///
+/// This parses the same elements as `svg_to_geometry_collection`, e.g., to return `f32`
+/// coordinates that take half the memory. Numbers are parsed and curves are flattened in `f64`
+/// before converting, so the result only differs by the precision of `T`. A number that does not
+/// fit into `T` returns an `SvgError::CoordinateOverflow`.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::{ Geometry, polygon };
+/// use geo_svg_io::geo_svg_reader::svg_to_geometry_collection_as;
+///
+/// let parsed_svg = svg_to_geometry_collection_as::<f32>(r#"<polygon points="0,0 60,0 60,60"/>"#);
+/// assert!(parsed_svg.is_ok());
+///
+/// let poly = polygon![(x: 0.0_f32, y: 0.0), (x: 60.0, y: 0.0), (x: 60.0, y: 60.0)];
+/// assert_eq!(Geometry::Polygon(poly), parsed_svg.ok().unwrap().0[0]);
+/// ```
+///
+pub fn svg_to_geometry_collection_as<T: CoordFloat>(
+    svg: &str,
+) -> Result<GeometryCollection<T>, SvgError> {
+    cast_geometry_collection(svg_to_geometry_collection(svg)?)
+}
+
+/// Returns a GeometryCollection with coordinates of type `T` parsed from the submitted SVG
+/// element, as `svg_to_geometry_collection_with_options` does for `f64`
+///
+pub fn svg_to_geometry_collection_with_options_as<T: CoordFloat>(
+    svg: &str,
+    options: &ParseOptions,
+) -> Result<GeometryCollection<T>, SvgError> {
+    cast_geometry_collection(svg_to_geometry_collection_with_options(svg, options)?)
+}
+
+/// Returns a Geometry with coordinates of type `T` parsed from the submitted SVG element, as
+/// `svg_to_geometry` does for `f64`
+///
+pub fn svg_to_geometry_as<T: CoordFloat>(svg: &str) -> Result<Geometry<T>, SvgError> {
+    cast_geometry(svg_to_geometry(svg)?)
+}
+
+/// Returns a GeometryCollection with coordinates of type `T` parsed from the submitted SVG
+/// document, as `svg_document_to_geometry_collection` does for `f64`
+///
+/// Each shape is converted as soon as it is read, so the `f64` coordinates of only one shape are
+/// held at a time.
+///
+pub fn svg_document_to_geometry_collection_as<T: CoordFloat>(
+    svg: &str,
+) -> Result<GeometryCollection<T>, SvgError> {
+    svg_document_to_geometry_collection_with_options_as(svg, &ParseOptions::default())
+}
+
+/// Returns a GeometryCollection with coordinates of type `T` parsed from the submitted SVG
+/// document, as `svg_document_to_geometry_collection_with_options` does for `f64`
+///
+pub fn svg_document_to_geometry_collection_with_options_as<T: CoordFloat>(
+    svg: &str,
+    options: &ParseOptions,
+) -> Result<GeometryCollection<T>, SvgError> {
+    svg_geometries_iter_with_options(svg, options)
+        .map(|geom| cast_geometry(geom?))
+        .collect::<Result<Vec<Geometry<T>>, SvgError>>()
+        .map(GeometryCollection)
+}
+
+/// Returns a GeometryCollection with coordinates of type `T` parsed from the submitted SVG path
+/// data, as `svg_d_path_to_geometry_collection` does for `f64`
+///
+pub fn svg_d_path_to_geometry_collection_as<T: CoordFloat>(
+    svg: &str,
+) -> Result<GeometryCollection<T>, SvgError> {
+    cast_geometry_collection(svg_d_path_to_geometry_collection(svg)?)
+}
+
+/// Returns a GeometryCollection with coordinates of type `T` parsed from the submitted SVG path
+/// data, as `svg_d_path_to_geometry_collection_with_options` does for `f64`
+///
+pub fn svg_d_path_to_geometry_collection_with_options_as<T: CoordFloat>(
+    svg: &str,
+    options: &ParseOptions,
+) -> Result<GeometryCollection<T>, SvgError> {
+    cast_geometry_collection(svg_d_path_to_geometry_collection_with_options(
+        svg, options,
+    )?)
+}
+
+/// Returns a Geometry with coordinates of type `T` parsed from the submitted SVG path data, as
+/// `svg_d_path_to_geometry` does for `f64`
+///
+pub fn svg_d_path_to_geometry_as<T: CoordFloat>(svg: &str) -> Result<Geometry<T>, SvgError> {
+    cast_geometry(svg_d_path_to_geometry(svg)?)
+}
+
+// The members are consumed one by one, so each is dropped once it is converted
+fn cast_geometry_collection<T: CoordFloat>(
+    gc: GeometryCollection<f64>,
+) -> Result<GeometryCollection<T>, SvgError> {
+    Ok(GeometryCollection(
+        gc.0.into_iter()
+            .map(cast_geometry)
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+fn cast_geometry<T: CoordFloat>(geom: Geometry<f64>) -> Result<Geometry<T>, SvgError> {
+    let line_string = |ls: &LineString<f64>| -> Result<LineString<T>, SvgError> {
+        Ok(LineString(
+            ls.0.iter()
+                .map(|c| cast_coord(*c))
+                .collect::<Result<_, _>>()?,
+        ))
+    };
+    let polygon = |poly: &Polygon<f64>| -> Result<Polygon<T>, SvgError> {
+        Ok(Polygon::new(
+            line_string(poly.exterior())?,
+            poly.interiors()
+                .iter()
+                .map(line_string)
+                .collect::<Result<_, _>>()?,
+        ))
+    };
+    Ok(match geom {
+        Geometry::Point(p) => Point(cast_coord(p.0)?).into(),
+        Geometry::Line(l) => Line::new(cast_coord(l.start)?, cast_coord(l.end)?).into(),
+        Geometry::LineString(ls) => line_string(&ls)?.into(),
+        Geometry::Polygon(poly) => polygon(&poly)?.into(),
+        Geometry::MultiPoint(mp) => MultiPoint(
+            mp.0.iter()
+                .map(|p| Ok(Point(cast_coord(p.0)?)))
+                .collect::<Result<_, SvgError>>()?,
+        )
+        .into(),
+        Geometry::MultiLineString(mls) => {
+            MultiLineString(mls.0.iter().map(line_string).collect::<Result<_, _>>()?).into()
+        }
+        Geometry::MultiPolygon(mp) => {
+            MultiPolygon(mp.0.iter().map(polygon).collect::<Result<_, _>>()?).into()
+        }
+        Geometry::GeometryCollection(gc) => {
+            Geometry::GeometryCollection(cast_geometry_collection(gc)?)
+        }
+        Geometry::Rect(r) => Rect::new(cast_coord(r.min())?, cast_coord(r.max())?).into(),
+        Geometry::Triangle(t) => {
+            Triangle(cast_coord(t.0)?, cast_coord(t.1)?, cast_coord(t.2)?).into()
+        }
+    })
+}
+
+fn cast_coord<T: CoordFloat>(coord: Coordinate<f64>) -> Result<Coordinate<T>, SvgError> {
+    Ok(Coordinate {
+        x: cast_number(coord.x)?,
+        y: cast_number(coord.y)?,
+    })
+}
+
+// Converting to a smaller float saturates to infinity instead of failing
+fn cast_number<T: CoordFloat>(value: f64) -> Result<T, SvgError> {
+    match T::from(value) {
+        Some(number) if number.is_finite() || !value.is_finite() => Ok(number),
+        _ => Err(SvgError::CoordinateOverflow { value }),
+    }
+}
+
+/** Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo_svg_writer::{svg_document, ToSvg};
+    use geo_types::{line_string, polygon};
+
+    #[test]
+    fn can_convert_svg_path() {
+        let poly = polygon!(
+        exterior: [
+            (x: 0.0, y: 0.0),
+            (x: 0.0, y: 60.0),
+            (x: 60.0, y: 60.0),
+            (x: 60.0, y: 0.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[[
+            (x: 10.0, y: 10.0),
+            (x: 40.0, y: 1.0),
+            (x: 40.0, y: 40.0),
+            (x: 10.50, y: 40.0),
+            (x: 10.0, y: 10.0),]
+            ]
+        );
+        let svg_string = String::from("M0 0l0 60l60 0L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10");
+        let parsed_svg = svg_d_path_to_geometry_collection(&svg_string);
+        assert_eq!(parsed_svg.is_ok(), true);
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let pl = geom.0[0].clone().into_polygon();
+        assert_eq!(true, pl.is_some());
+        assert_eq!(pl.unwrap(), poly);
+    }
+
+    #[test]
+    fn can_convert_svg_path_test() {
+        let poly: Polygon<f64> = polygon!(
+        exterior: [
+            (x: 0.0_f64, y: 0.0),
+            (x: 0.0, y: 60.0),
+            (x: 60.0, y: 60.0),
+            (x: 60.0, y: 0.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[[
+            (x: 10.0, y: 10.0),
+            (x: 40.0, y: 1.0),
+            (x: 40.0, y: 40.0),
+            (x: 10.50, y: 40.0),
+            (x: 10.0, y: 10.0),]
+            ]
+        )
+        .into();
+        let svg_string =
+            String::from(r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert_eq!(parsed_svg.is_ok(), true);
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let pl = geom.0[0].clone().into_polygon();
+        assert_eq!(true, pl.is_some());
+        assert_eq!(poly, pl.unwrap());
+    }
+
+    #[test]
+    fn can_convert_svg_h_v_path_test() {
+        let poly: Polygon<f64> = polygon!(
+        exterior: [
+            (x: 0.0_f64, y: 0.0),
+            (x: 0.0, y: 60.0),
+            (x: 60.0, y: 60.0),
+            (x: 60.0, y: 0.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[[
+            (x: 10.0, y: 10.0),
+            (x: 40.0, y: 1.0),
+            (x: 40.0, y: 40.0),
+            (x: 10.50, y: 40.0),
+            (x: 10.0, y: 10.0),]
+            ]
+        )
+        .into();
+        let svg_string =
+            String::from(r#"<path d="M0 0v60h60v-60h-60M10 10L40 1L40 40L10.5 40L10 10"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert_eq!(parsed_svg.is_ok(), true);
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let pl = geom.0[0].clone().into_polygon();
+        assert_eq!(true, pl.is_some());
+        assert_eq!(poly, pl.unwrap());
+    }
+
+    #[test]
+    fn can_convert_svg_c_s_path_test() {
+        let solution = String::from(
+            r#"<path d="M0 0L0.08636474609375 2.75421142578125L0.33935546875 5.39306640625L0.74981689453125 7.91839599609375L1.30859375 10.33203125L2.00653076171875 12.63580322265625L2.83447265625 14.83154296875L4.84375 18.90625L7.26318359375 22.57080078125L10.01953125 25.83984375L13.03955078125 28.72802734375L16.25 31.25L19.57763671875 33.42041015625L22.94921875 35.25390625L26.29150390625 36.76513671875L29.53125 37.96875L32.59521484375 38.87939453125L35.41015625 39.51171875L37.90283203125 39.88037109375L40 40L41.7626953125 40.224609375L43.3203125 40.859375L44.7021484375 41.845703125L45.9375 43.125L47.0556640625 44.638671875L48.0859375 46.328125L50 50L51.9140625 53.671875L52.9443359375 55.361328125L54.0625 56.875L55.2978515625 58.154296875L56.6796875 59.140625L58.2373046875 59.775390625L60 60L60 0L0 0M10 10L20 10L20 20L10 20L10 10"/>"#,
+        );
+        let svg_string = String::from(
+            r#"<path d="M0 0C0 30 30 40 40 40S50 60 60 60L60 0ZM10 10L20 10L20 20L10 20L10 10" />"#,
+        );
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert_eq!(true, parsed_svg.is_ok());
+        let svg = parsed_svg.ok().unwrap().to_svg();
+        assert_eq!(solution, svg);
+    }
+
+    #[test]
+    fn can_convert_svg_closed_curve_path_to_polygon() {
+        let parsed_svg = svg_d_path_to_geometry_collection("M0 0C10 0 10 10 0 10Z");
+        assert!(parsed_svg.is_ok());
+        let poly = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(poly.is_some());
+        let ring = poly.unwrap().exterior().0.clone();
+        assert_eq!(Coordinate { x: 0., y: 10. }, ring[ring.len() - 2]);
+        assert_eq!(ring[0], ring[ring.len() - 1]);
+
+        // Summing relative offsets ends the second curve a rounding error away from the start,
+        // which is closed exactly instead of keeping both points
+        let parsed_svg = svg_d_path_to_geometry_collection(
+            "m0.1 0.2c0.7 0 0.7 0.7 0.7 0.7c-0.7 0 -0.7 -0.7 -0.7 -0.7z",
+        );
+        assert!(parsed_svg.is_ok());
+        let poly = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(poly.is_some());
+        let ring = poly.unwrap().exterior().0.clone();
+        assert_eq!(Coordinate { x: 0.1, y: 0.2 }, ring[0]);
+        assert_eq!(ring[0], ring[ring.len() - 1]);
+        assert!(!coords_coincide(&ring[0], &ring[ring.len() - 2]));
+    }
+
+    #[test]
+    fn can_convert_svg_q_t_path_test() {
+        let solution = String::from(
+            r#"<path d="M0 0L7.1875 9.375L13.75 17.5L19.6875 24.375L22.421875 27.34375L25 30L27.421875 32.34375L29.6875 34.375L31.796875 36.09375L33.75 37.5L35.546875 38.59375L37.1875 39.375L38.671875 39.84375L40 40L41.25 40.078125L42.5 40.3125L43.75 40.703125L45 41.25L46.25 41.953125L47.5 42.8125L48.75 43.828125L50 45L51.25 46.328125L52.5 47.8125L55 51.25L57.5 55.3125L60 60L60 0L0 0M10 10L20 10L20 20L10 20L10 10"/>"#,
+        );
+        let svg_string = String::from(
+            r#"<path d="M0 0Q30 40 40 40T60 60L60 0ZM10 10L20 10L20 20L10 20L10 10" />"#,
+        );
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert_eq!(true, parsed_svg.is_ok());
+        let svg = parsed_svg.ok().unwrap().to_svg();
+        assert_eq!(solution, svg);
+    }
+
+    #[test]
+    fn can_convert_svg_polygon_test() {
+        let poly: Polygon<f64> = polygon!(
+        exterior: [
+            (x: 0.0_f64, y: 0.0),
+            (x: 0.0, y: 60.0),
+            (x: 60.0, y: 60.0),
+            (x: 60.0, y: 0.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[]
+        )
+        .into();
+        let svg_string = String::from(r#"<polygon points="0, 0 60, 0 60, 60 0, 60 0, 0"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert_eq!(parsed_svg.is_ok(), true);
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let pl = geom.0[0].clone().into_polygon();
+        assert_eq!(true, pl.is_some());
+        assert_eq!(poly, pl.unwrap());
+    }
+
+    #[test]
+    fn can_convert_svg_polygon_without_closing_point() {
+        let expected = line_string![
+            (x: 0.0_f64, y: 0.0),
+            (x: 60.0, y: 0.0),
+            (x: 60.0, y: 60.0),
+            (x: 0.0, y: 60.0),
+            (x: 0.0, y: 0.0),
+        ];
+        for points in ["0,0 60,0 60,60 0,60", "0,0 60,0 60,60 0,60 0,0"] {
+            let parsed_svg = svg_to_geometry(&format!(r#"<polygon points="{}"/>"#, points));
+            assert!(parsed_svg.is_ok());
+            let pl = parsed_svg.ok().unwrap().into_polygon();
+            assert!(pl.is_some());
+            assert_eq!(&expected, pl.unwrap().exterior());
+        }
+    }
+
+    #[test]
+    fn can_convert_svg_polyline_test() {
+        let line: LineString<f64> = line_string![
+            (x: 0.0_f64, y: 0.0),
+            (x: 0.0, y: 60.0),
+            (x: 60.0, y: 60.0),
+            (x: 60.0, y: 0.0),]
+        .into();
+        let svg_string = String::from(r#"<polyline points="0, 0 0, 60 60, 60 60, 0"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert_eq!(parsed_svg.is_ok(), true);
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let pl = geom.0[0].clone().into_line_string();
+        assert_eq!(true, pl.is_some());
+        assert_eq!(line, pl.unwrap());
+    }
+
+    #[test]
+    fn can_convert_svg_rect_test() {
+        let poly: Polygon<f64> = polygon!(
+        exterior: [
+            (x: 0.0_f64, y: 0.0),
+            (x: 0.0, y: 60.0),
+            (x: 60.0, y: 60.0),
+            (x: 60.0, y: 0.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[]
+        )
+        .into();
+        let svg_string = String::from(r#"<rect x="0" y="0" width="60" height="60"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert_eq!(parsed_svg.is_ok(), true);
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let pl = geom.0[0].clone().into_polygon();
+        assert_eq!(true, pl.is_some());
+        assert_eq!(poly, pl.unwrap());
+    }
+
+    #[test]
+    fn can_convert_svg_rect_without_corner() {
+        let parsed_svg = svg_to_geometry_collection(r#"<rect width="5" height="5"/>"#);
+        assert!(parsed_svg.is_ok());
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 5. });
+        assert_eq!(
+            vec![Geometry::Polygon(rect.into())],
+            parsed_svg.ok().unwrap().0
+        );
+
+        let parsed_svg = svg_to_geometry_collection(r#"<rect y="2" width="5" height="5"/>"#);
+        assert!(parsed_svg.is_ok());
+        let rect = Rect::new(Coordinate { x: 0., y: 2. }, Coordinate { x: 5., y: 7. });
+        assert_eq!(
+            vec![Geometry::Polygon(rect.into())],
+            parsed_svg.ok().unwrap().0
+        );
+
+        // Such a rect no longer fails the whole document
+        let parsed_svg = svg_document_to_geometry_collection(
+            r#"<svg><rect width="5" height="5"/><line x2="1"/></svg>"#,
+        );
+        assert!(parsed_svg.is_ok());
+        assert_eq!(2, parsed_svg.ok().unwrap().0.len());
+    }
+
+    #[test]
+    fn cannot_convert_svg_rect_with_negative_size() {
+        for (svg, attribute) in [
+            (r#"<rect x="0" y="0" width="-5" height="10"/>"#, "width"),
+            (r#"<rect x="0" y="0" width="10" height="-5"/>"#, "height"),
+            (
+                r#"<rect x="0" y="0" width="10" height="-5" rx="1"/>"#,
+                "height",
+            ),
+            (r#"<rect x="1e300" y="0" width="-1" height="10"/>"#, "width"),
+        ] {
+            match svg_to_geometry_collection(svg) {
+                Err(SvgError::InvalidSvgError(error)) => {
+                    assert_eq!(Some(String::from(attribute)), error.attribute)
+                }
+                _ => panic!("expected an InvalidSvgError for {}", svg),
+            }
+        }
+    }
+
+    #[test]
+    fn can_convert_svg_rect_without_area_to_nothing() {
+        for svg in [
+            r#"<rect x="0" y="0" width="0" height="10"/>"#,
+            r#"<rect x="0" y="0" width="10" height="0" rx="2"/>"#,
+        ] {
+            let parsed_svg = svg_to_geometry_collection(svg);
+            assert!(parsed_svg.is_ok());
+            assert!(parsed_svg.ok().unwrap().0.is_empty());
+        }
+
+        let parsed_svg = svg_document_to_geometry_collection(
+            r#"<svg><rect x="0" y="0" width="0" height="10"/><line x2="1"/></svg>"#,
+        );
+        assert!(parsed_svg.is_ok());
+        assert_eq!(1, parsed_svg.ok().unwrap().0.len());
+    }
+
+    #[test]
+    fn can_convert_svg_line_with_missing_endpoints() {
+        let parsed_svg = svg_to_geometry(r#"<line x2="10" y2="10"/>"#);
+        assert!(parsed_svg.is_ok());
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 10., y: 10. });
+        assert_eq!(Some(line), parsed_svg.ok().unwrap().into_line());
+
+        let parsed_svg = svg_to_geometry(r#"<line x1="5"/>"#);
+        assert!(parsed_svg.is_ok());
+        let line = Line::new(Coordinate { x: 5., y: 0. }, Coordinate { x: 0., y: 0. });
+        assert_eq!(Some(line), parsed_svg.ok().unwrap().into_line());
+
+        assert!(matches!(
+            svg_to_geometry(r#"<line x2="ten" y2="10"/>"#),
+            Err(SvgError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn can_convert_svg_attributes_with_number_forms() {
+        let options = ParseOptions {
+            prefer_rect: true,
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<rect x="1e2" y=".5" width=" 60 " height="1E1"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let rect = Rect::new(
+            Coordinate { x: 100., y: 0.5 },
+            Coordinate { x: 160., y: 10.5 },
+        );
+        assert_eq!(Geometry::Rect(rect), parsed_svg.ok().unwrap().0[0]);
+
+        let parsed_svg = svg_to_geometry(r#"<line x1="-.5" y1=" 2.5e-1" x2="+1e+2 " y2="5."/>"#);
+        assert!(parsed_svg.is_ok());
+        let line = Line::new(
+            Coordinate { x: -0.5, y: 0.25 },
+            Coordinate { x: 100., y: 5. },
+        );
+        assert_eq!(Geometry::Line(line), parsed_svg.ok().unwrap());
+
+        // Padding alone is an empty value
+        assert!(matches!(
+            svg_to_geometry_collection(r#"<rect x="0" y="0" width=" " height="10"/>"#),
+            Err(SvgError::InvalidSvgError(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn can_serialize_svg_geometry_with_serde() {
+        let poly = Geometry::Polygon(polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 0.0),
+        ]);
+        let json = serde_json::to_string(&SvgGeometry(poly.clone()));
+        assert!(json.is_ok());
+        let json = json.ok().unwrap();
+        assert_eq!(r#""<path d=\"M0 0L10 0L10 10L0 0\"/>""#, json);
+
+        let parsed: Result<SvgGeometry, _> = serde_json::from_str(&json);
+        assert!(parsed.is_ok());
+        assert_eq!(poly, parsed.ok().unwrap().0);
+
+        let parsed: Result<SvgGeometryCollection, _> = serde_json::from_str(&json);
+        assert!(parsed.is_ok());
+        assert_eq!(vec![poly], parsed.ok().unwrap().0 .0);
+
+        assert!(serde_json::from_str::<SvgGeometry>(r#""<polygon points=""#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn can_serialize_svg_geometry_collection_with_serde() {
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(polygon![
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 10.0),
+                (x: 0.0, y: 0.0),
+            ]),
+            Geometry::LineString(
+                line_string![(x: 20.0, y: 0.0), (x: 30.0, y: 10.0), (x: 40.0, y: 0.0)],
+            ),
+            Geometry::Line(Line::new((50.0, 0.0), (60.0, 10.0))),
+        ]);
+        let json = serde_json::to_string(&SvgGeometryCollection(gc.clone()));
+        assert!(json.is_ok());
+        let json = json.ok().unwrap();
+        assert!(json.starts_with(r#""<svg>"#));
+
+        let parsed: Result<SvgGeometryCollection, _> = serde_json::from_str(&json);
+        assert!(parsed.is_ok());
+        assert_eq!(gc, parsed.ok().unwrap().0);
+
+        let parsed: Result<SvgGeometryCollection<f32>, _> = serde_json::from_str(&json);
+        assert!(parsed.is_ok());
+        assert_eq!(3, parsed.ok().unwrap().0 .0.len());
+    }
+
+    #[test]
+    fn can_convert_svg_from_reader() {
+        let svg = r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#;
+        let parsed_svg = svg_to_geometry_collection_from_reader(std::io::Cursor::new(svg));
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            svg_to_geometry_collection_with_options(svg, &ParseOptions::default())
+                .ok()
+                .unwrap(),
+            parsed_svg.ok().unwrap()
+        );
+
+        // Reading stops at the first supported element
+        let svg = "<g><line x1=\"0\" y1=\"0\" x2=\"10\" y2=\"10\"/><circle r=";
+        let parsed_svg =
+            svg_to_geometry_collection_from_reader(std::io::BufReader::new(svg.as_bytes()));
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0].clone().into_line().is_some());
+
+        assert!(svg_to_geometry_collection_from_reader("<g></g>".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn can_get_svg_element_geometry_kind() {
+        for (svg, kind) in [
+            (r#"<path d="M0 0L10 10"/>"#, GeometryKind::Line),
+            (r#"<path d="M0 0L10 10L20 0"/>"#, GeometryKind::LineString),
+            (r#"<path d="M0 0Q5 10 10 0"/>"#, GeometryKind::LineString),
+            (r#"<path d="M0 0L10 0L10 10Z"/>"#, GeometryKind::Polygon),
+            (r#"<path d="M0 0A5 5 0 0 1 10 0Z"/>"#, GeometryKind::Polygon),
+            (
+                r#"<path d="M0 0L20 0L20 20L0 20ZM5 5L5 15L15 15L15 5Z"/>"#,
+                GeometryKind::Polygon,
+            ),
+            (
+                r#"<path d="M0 0L10 0L10 10ZM20 0L30 0L30 10Z"/>"#,
+                GeometryKind::MultiPolygon,
+            ),
+            (
+                r#"<path d="M0 0L10 0L10 10M20 0L30 0L30 10"/>"#,
+                GeometryKind::MultiLineString,
+            ),
+            (
+                r#"<path d="M0 0L10 0M20 0L30 0L30 10Z"/>"#,
+                GeometryKind::GeometryCollection,
+            ),
+            (r#"<path d="M5 5Z"/>"#, GeometryKind::Empty),
+            (
+                r#"<polygon points="0,0 10,0 10,10"/>"#,
+                GeometryKind::Polygon,
+            ),
+            (
+                r#"<polyline points="0,0 10,0 10,10"/>"#,
+                GeometryKind::LineString,
+            ),
+            (
+                r#"<rect x="0" y="0" width="10" height="5"/>"#,
+                GeometryKind::Polygon,
+            ),
+            (
+                r#"<rect x="0" y="0" width="10" height="5" rx="2"/>"#,
+                GeometryKind::Polygon,
+            ),
+            (
+                r#"<rect x="0" y="0" width="0" height="5"/>"#,
+                GeometryKind::Empty,
+            ),
+            (r#"<circle cx="5" cy="5" r="5"/>"#, GeometryKind::Polygon),
+            (
+                r#"<ellipse cx="5" cy="5" rx="5" ry="2"/>"#,
+                GeometryKind::Polygon,
+            ),
+            (
+                r#"<line x1="0" y1="0" x2="10" y2="10"/>"#,
+                GeometryKind::Line,
+            ),
+            (r#"<g><text/><line x2="10"/></g>"#, GeometryKind::Line),
+        ] {
+            let parsed_kind = svg_element_geometry_kind(svg);
+            assert!(parsed_kind.is_ok(), "{}", svg);
+            assert_eq!(kind, parsed_kind.ok().unwrap(), "{}", svg);
+        }
+
+        assert!(svg_element_geometry_kind(r#"<text x="0" y="0"/>"#).is_err());
+        assert!(svg_element_geometry_kind(r#"<path d="M0 0X"/>"#).is_err());
+        assert!(svg_element_geometry_kind(r#"<rect x="0" y="0" width="-1" height="5"/>"#).is_err());
+
+        // The small square is only within the ring once its curves bulge out to it
+        for svg in [
+            r#"<path d="M0 0C0 -100 100 -100 100 0C100 100 0 100 0 0ZM5 -30L8 -30L8 -27L5 -27Z"/>"#,
+            r#"<path d="M0 0C0 -100 100 -100 100 0C100 100 0 100 0 0ZM5 -30L5 -27L8 -27L8 -30Z"/>"#,
+        ] {
+            let parsed_kind = svg_element_geometry_kind(svg);
+            assert!(parsed_kind.is_ok());
+            assert_eq!(GeometryKind::Polygon, parsed_kind.ok().unwrap());
+            let parsed_svg = svg_to_geometry_collection(svg);
+            assert!(parsed_svg.is_ok());
+            assert!(parsed_svg.ok().unwrap().0[0]
+                .clone()
+                .into_polygon()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn can_parse_svg_from_str() {
+        let svg = r#"<polygon points="0, 0 60, 0 60, 60 0, 60 0, 0"/>"#;
+        let parsed_svg = svg.parse::<SvgGeometryCollection>();
+        assert!(parsed_svg.is_ok());
+        let geom: GeometryCollection<f64> = parsed_svg.ok().unwrap().into();
+        assert_eq!(svg_to_geometry_collection(svg).ok().unwrap(), geom);
+
+        let parsed_svg = svg.parse::<SvgGeometry>();
+        assert!(parsed_svg.is_ok());
+        let geom: Geometry<f64> = parsed_svg.ok().unwrap().into();
+        assert_eq!(svg_to_geometry(svg).ok().unwrap(), geom);
+
+        assert!("<polygon points=".parse::<SvgGeometryCollection>().is_err());
+        assert!("<polygon points=".parse::<SvgGeometry>().is_err());
+
+        let parsed_svg = svg.parse::<SvgGeometry<f32>>();
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0.into_polygon().is_some());
+    }
+
+    #[test]
+    fn can_convert_svg_path_to_single_geom() {
+        let poly: Polygon<f64> = polygon!(
+            exterior: [
+                (x: 0.0_f64, y: 0.0),
+                (x: 0.0, y: 60.0),
+                (x: 60.0, y: 60.0),
+                (x: 60.0, y: 0.0),
+                (x: 0.0, y: 0.0),],
+            interiors:[[
+                (x: 10.0, y: 10.0),
+                (x: 40.0, y: 1.0),
+                (x: 40.0, y: 40.0),
+                (x: 10.50, y: 40.0),
+                (x: 10.0, y: 10.0),]
+                ]
+            );
+        let svg_string =
+            String::from(r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#);
+
+        let parsed_svg = svg_to_geometry(&svg_string);
+        assert!(parsed_svg.is_ok());
+        let parsed_poly = parsed_svg.ok().unwrap().into_polygon();
+        assert!(parsed_poly.is_some());
+        assert_eq!(poly, parsed_poly.unwrap());
+    }
+
+    #[test]
+    fn can_convert_svg_polygon_to_single_geom() {
+        let poly: Polygon<f64> = polygon!(
+            exterior: [
+                (x: 0.0_f64, y: 0.0),
+                (x: 0.0, y: 60.0),
+                (x: 60.0, y: 60.0),
+                (x: 60.0, y: 0.0),
+                (x: 0.0, y: 0.0),],
+            interiors:[]
+            );
+        let svg_string = String::from(r#"<polygon points="0, 0 60, 0 60, 60 0, 60 0, 0"/>"#);
+
+        let parsed_svg = svg_to_geometry(&svg_string);
+        assert!(parsed_svg.is_ok());
+        let parsed_poly = parsed_svg.ok().unwrap().into_polygon();
+        assert!(parsed_poly.is_some());
+        assert_eq!(poly, parsed_poly.unwrap());
+    }
+
+    #[test]
+    fn can_skip_unknown_path_command_when_lenient() {
+        let line: LineString<f64> = line_string![
+            (x: 0.0_f64, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 10.0),];
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let parsed_svg = svg_d_path_to_geometry_collection_with_warnings(
+            "M0 0L10 0B1 2 3L10 10l-10 0",
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let (geom, warnings) = parsed_svg.ok().unwrap();
+        assert_eq!(
+            vec![String::from("skipped invalid path data \"B1 2 3\"")],
+            warnings
+        );
+        assert_eq!(1, geom.0.len());
+        let ls = geom.0[0].clone().into_line_string();
+        assert!(ls.is_some());
+        assert_eq!(line, ls.unwrap());
+    }
+
+    #[test]
+    fn curve_flattening_with_tolerance_meets_error_bound() {
+        let max_error = 0.05;
+        let parsed_svg =
+            svg_d_path_to_geometry_collection_with_tolerance("M0 0C0 30 30 40 40 40", max_error);
+        assert!(parsed_svg.is_ok());
+        let ls = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_line_string()
+            .unwrap();
+        assert_eq!(Coordinate { x: 0., y: 0. }, ls.0[0]);
+        assert_eq!(Coordinate { x: 40., y: 40. }, ls.0[ls.0.len() - 1]);
+
+        // every point on the true curve must be within max_error of the flattened line
+        for x in 0..=1000 {
+            let curve_point = de_casteljau4(
+                x as f64 / 1000.,
+                Coord2(0., 0.),
+                Coord2(0., 30.),
+                Coord2(30., 40.),
+                Coord2(40., 40.),
+            );
+            let distance = ls
+                .lines()
+                .map(|line| {
+                    distance_to_chord(
+                        curve_point,
+                        Coord2(line.start.x, line.start.y),
+                        Coord2(line.end.x, line.end.y),
+                    )
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance <= max_error + 1e-9);
+        }
+    }
+
+    #[test]
+    fn cannot_flatten_curves_with_invalid_tolerance() {
+        for tolerance in [0., -1., f64::NAN, f64::INFINITY] {
+            match svg_d_path_to_geometry_collection_with_tolerance(
+                "M0 0C0 30 30 40 40 40",
+                tolerance,
+            ) {
+                Err(SvgError::InvalidOption { what }) => assert_eq!(
+                    format!(
+                        "the curve tolerance {} is not positive and finite",
+                        tolerance
+                    ),
+                    what
+                ),
+                _ => panic!("expected an InvalidOption for the tolerance {}", tolerance),
+            }
+            let options = ParseOptions {
+                curve_flattening: CurveFlattening::Tolerance(tolerance),
+                ..Default::default()
+            };
+            for svg in [
+                r#"<path d="M0 0Q10 10 20 0"/>"#,
+                r#"<circle r="5"/>"#,
+                r#"<rect width="10" height="10" rx="2"/>"#,
+            ] {
+                assert!(matches!(
+                    svg_to_geometry_collection_with_options(svg, &options),
+                    Err(SvgError::InvalidOption { .. })
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn can_flatten_curves_at_extreme_resolutions() {
+        for flattening in [
+            CurveFlattening::Segments(usize::MAX),
+            CurveFlattening::Curvature(usize::MAX),
+            CurveFlattening::Tolerance(f64::MIN_POSITIVE),
+        ] {
+            let options = ParseOptions {
+                curve_flattening: flattening,
+                ..Default::default()
+            };
+            for svg in [
+                r#"<circle r="5"/>"#,
+                r#"<path d="M0 0A5 5 0 0 1 10 0"/>"#,
+                r#"<path d="M0 0C0 1e6 1e6 1e6 1e6 0"/>"#,
+                r#"<path d="M0 0Q5e5 1e6 1e6 0"/>"#,
+            ] {
+                let parsed_svg = svg_to_geometry_collection_with_options(svg, &options);
+                assert!(parsed_svg.is_ok());
+                let vertices = count_vertices(&parsed_svg.ok().unwrap().0[0]);
+                assert!(vertices > 1000 && vertices <= MAX_CURVE_SEGMENTS + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn curve_flattening_by_curvature_concentrates_points_at_bend() {
+        // A curve with a single sharp bend at (50, 37.5)
+        let svg_string = r#"<path d="M0 0C50 50 50 50 100 0"/>"#;
+        let points_near_bend = |flattening: CurveFlattening| {
+            let options = ParseOptions {
+                curve_flattening: flattening,
+                ..Default::default()
+            };
+            let parsed_svg = svg_to_geometry_collection_with_options(svg_string, &options);
+            assert!(parsed_svg.is_ok());
+            let ls = parsed_svg.ok().unwrap().0[0]
+                .clone()
+                .into_line_string()
+                .unwrap();
+            assert_eq!(21, ls.0.len());
+            assert_eq!(Coordinate { x: 0., y: 0. }, ls.0[0]);
+            assert_eq!(Coordinate { x: 100., y: 0. }, ls.0[20]);
+            ls.0.iter()
+                .filter(|c| (c.x - 50.).hypot(c.y - 37.5) < 10.)
+                .count()
+        };
+
+        let uniform = points_near_bend(CurveFlattening::Segments(20));
+        let by_curvature = points_near_bend(CurveFlattening::Curvature(20));
+        assert!(by_curvature > uniform);
+        assert!(by_curvature >= 10);
+    }
+
+    #[test]
+    fn default_curve_flattening_is_adaptive() {
+        let point_count = |svg: &str| {
+            let parsed_svg = svg_d_path_to_geometry_collection(svg);
+            assert!(parsed_svg.is_ok());
+            parsed_svg.ok().unwrap().0[0]
+                .clone()
+                .into_line_string()
+                .unwrap()
+                .0
+                .len()
+        };
+
+        // Small curves need few points, and large ones more than a fixed 100
+        assert!(point_count("M0 0C0 3 3 4 4 4") < 10);
+        assert!(point_count("M0 0Q1 2 2 0") < 10);
+        assert!(point_count("M0 0C0 30000 30000 40000 40000 40000") > 100);
+        assert!(point_count("M0 0Q10000 20000 20000 0T40000 0") > 100);
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_options() {
+        let svg_string = "M0 0C0 300 300 400 400 400";
+        let point_count = |curve_flattening: CurveFlattening| {
+            let options = ParseOptions {
+                curve_flattening,
+                ..Default::default()
+            };
+            let parsed_svg = svg_d_path_to_geometry_collection_with_options(svg_string, &options);
+            assert!(parsed_svg.is_ok());
+            parsed_svg.ok().unwrap().0[0]
+                .clone()
+                .into_line_string()
+                .unwrap()
+                .0
+                .len()
+        };
+
+        // The defaults match the plain function
+        let parsed_svg =
+            svg_d_path_to_geometry_collection_with_options(svg_string, &ParseOptions::default());
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            svg_d_path_to_geometry_collection(svg_string).ok().unwrap(),
+            parsed_svg.ok().unwrap()
+        );
+
+        assert!(
+            point_count(CurveFlattening::Tolerance(0.001))
+                > point_count(CurveFlattening::Tolerance(10.))
+        );
+        assert_eq!(9, point_count(CurveFlattening::Segments(8)));
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_hole() {
+        let parsed_svg =
+            svg_d_path_to_geometry_collection("M0 0L10 0L10 10L0 10ZM3 3L3 7L7 7L7 3Z");
+        assert!(parsed_svg.is_ok());
+
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let expected = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 3., y: 3.), (x: 3., y: 7.), (x: 7., y: 7.), (x: 7., y: 3.)]],
+        );
+        assert_eq!(Some(expected), geom.0[0].clone().into_polygon());
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_disjoint_holed_polygons() {
+        // The second hole comes first, and the second square is inside neither the first square
+        // nor its hole. The second hole winds like its square, so it is only a hole with even-odd.
+        let options = ParseOptions {
+            fill_rule: FillRule::EvenOdd,
+            ..Default::default()
+        };
+        let parsed_svg = svg_d_path_to_geometry_collection_with_options(
+            "M0 0L10 0L10 10L0 10ZM23 3L27 3L27 7L23 7ZM20 0L30 0L30 10L20 10ZM2 2L2 8L8 8L8 2Z",
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let expected = MultiPolygon(vec![
+            polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.)]],
+            ),
+            polygon!(
+                exterior: [(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 20., y: 10.)],
+                interiors: [[(x: 23., y: 3.), (x: 27., y: 3.), (x: 27., y: 7.), (x: 23., y: 7.)]],
+            ),
+        ]);
+        assert_eq!(Some(expected), geom.0[0].clone().into_multi_polygon());
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_disjoint_holed_polygons_by_non_zero() {
+        // With non-zero, a hole of the second square must wind against it to be a hole, while
+        // one winding the same way is filled
+        let options = ParseOptions {
+            fill_rule: FillRule::NonZero,
+            ..Default::default()
+        };
+        let parsed_svg = svg_d_path_to_geometry_collection_with_options(
+            "M0 0L10 0L10 10L0 10ZM23 3L23 7L27 7L27 3ZM20 0L30 0L30 10L20 10ZM2 2L2 8L8 8L8 2Z",
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let expected = MultiPolygon(vec![
+            polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.)]],
+            ),
+            polygon!(
+                exterior: [(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 20., y: 10.)],
+                interiors: [[(x: 23., y: 3.), (x: 23., y: 7.), (x: 27., y: 7.), (x: 27., y: 3.)]],
+            ),
+        ]);
+        assert_eq!(
+            Some(expected),
+            parsed_svg.ok().unwrap().0[0].clone().into_multi_polygon()
+        );
+
+        let parsed_svg = svg_d_path_to_geometry_collection_with_options(
+            "M0 0L10 0L10 10L0 10ZM23 3L27 3L27 7L23 7ZM20 0L30 0L30 10L20 10ZM2 2L2 8L8 8L8 2Z",
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let polygons = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_multi_polygon()
+            .unwrap();
+        assert_eq!(
+            vec![1, 0],
+            polygons
+                .0
+                .iter()
+                .map(|poly| poly.interiors().len())
+                .collect::<Vec<usize>>()
+        );
+    }
+
+    #[test]
+    fn can_convert_svg_path_data_syntax_variants() {
+        let expected = svg_d_path_to_geometry("M0 0 L10 0 L10 10 Z");
+        assert!(expected.is_ok());
+        let expected = expected.ok().unwrap();
+        assert!(expected.clone().into_polygon().is_some());
+        for d in [
+            // Separators
+            "M0,0L10,0L10,10Z",
+            "M0,0 L10,0 L10,10 Z",
+            "M 0 , 0 L 10 , 0 L 10 , 10 Z",
+            "M0 0,10 0,10 10Z",
+            "\tM0 0\nL10 0\r\nL10 10 Z ",
+            // No spaces around commands
+            "M0 0L10 0L10 10Z",
+            "M+0-0L+10-0L10+10Z",
+            "M0 0L10 0 10 10Z",
+            "M.0 0L1e1 0L10 1E1Z",
+            // Close commands of either case, with or without a closing coordinate
+            "M0 0L10 0L10 10z",
+            "M0 0L10 0L10 10L0 0Z",
+            "M0 0L10 0L10 10L0 0z",
+            "M0 0 10 0 10 10 0 0",
+            // Relative commands
+            "m0 0l10 0l0 10z",
+            "m0,0 10,0 0,10z",
+            "M0 0h10v10z",
+            "M0 0H10V10Z",
+        ] {
+            let parsed_svg = svg_d_path_to_geometry(d);
+            assert!(parsed_svg.is_ok(), "{}", d);
+            assert_eq!(expected, parsed_svg.ok().unwrap(), "{}", d);
+        }
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_relative_move_to() {
+        let triangle = polygon![
+            (x: 10., y: 10.),
+            (x: 20., y: 10.),
+            (x: 20., y: 20.),
+            (x: 10., y: 10.),
+        ];
+        let parsed_svg = svg_d_path_to_geometry("m10 10l10 0l0 10z");
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(triangle.clone()),
+            parsed_svg.ok().unwrap().into_polygon()
+        );
+
+        // A move after a close is relative to the start of the closed subpath
+        let parsed_svg = svg_d_path_to_geometry("m10 10l10 0l0 10zm20 0l10 0l0 10z");
+        assert!(parsed_svg.is_ok());
+        let moved = polygon![
+            (x: 30., y: 10.),
+            (x: 40., y: 10.),
+            (x: 40., y: 20.),
+            (x: 30., y: 10.),
+        ];
+        assert_eq!(
+            Some(MultiPolygon(vec![triangle, moved])),
+            parsed_svg.ok().unwrap().into_multi_polygon()
+        );
+    }
+
+    #[test]
+    fn can_convert_svg_path_mixing_geometry_types() {
+        let line = Geometry::Line(Line::new(
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 10., y: 0. },
+        ));
+        let triangle = Geometry::Polygon(polygon![
+            (x: 20., y: 20.),
+            (x: 30., y: 20.),
+            (x: 30., y: 30.),
+            (x: 20., y: 20.),
+        ]);
+        let parsed_svg = svg_d_path_to_geometry_collection("M0 0L10 0M20 20L30 20L30 30L20 20");
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            vec![line.clone(), triangle.clone()],
+            parsed_svg.ok().unwrap().0
+        );
+
+        // Every type is kept, in the order lines, open subpaths and then closed ones
+        let parsed_svg =
+            svg_d_path_to_geometry_collection("M20 20L30 20L30 30ZM0 5L5 10L10 5M0 0L10 0");
+        assert!(parsed_svg.is_ok());
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(3, geom.0.len());
+        assert_eq!(line, geom.0[0]);
+        assert!(geom.0[1].clone().into_line_string().is_some());
+        assert_eq!(triangle, geom.0[2]);
+    }
+
+    #[test]
+    fn can_skip_degenerate_svg_subpaths() {
+        for svg in [
+            "M5 5Z",
+            "M5 5L5 5",
+            "M5 5L5 5Z",
+            "M5 5",
+            "M5 5l0 0l0 0z",
+            "M5 5ZM6 6L6 6",
+        ] {
+            let parsed_svg = svg_d_path_to_geometry_collection(svg);
+            assert!(parsed_svg.is_ok(), "{}", svg);
+            assert!(parsed_svg.ok().unwrap().0.is_empty(), "{}", svg);
+        }
+
+        // Only the degenerate subpaths of a path are skipped
+        let parsed_svg = svg_d_path_to_geometry_collection("M5 5ZM0 0L10 0M20 20L20 20");
+        assert!(parsed_svg.is_ok());
+        let line = Geometry::Line(Line::new(
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 10., y: 0. },
+        ));
+        assert_eq!(vec![line], parsed_svg.ok().unwrap().0);
+
+        // Short subpaths far from the origin are kept
+        let parsed_svg = svg_d_path_to_geometry_collection("M1e12 0L1000000000500 0");
+        assert!(parsed_svg.is_ok());
+        let line = Geometry::Line(Line::new(
+            Coordinate { x: 1e12, y: 0. },
+            Coordinate {
+                x: 1e12 + 500.,
+                y: 0.,
+            },
+        ));
+        assert_eq!(vec![line], parsed_svg.ok().unwrap().0);
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_open_subpaths_and_ring() {
+        let square = Geometry::Polygon(polygon![
+            (x: 40., y: 40.),
+            (x: 50., y: 40.),
+            (x: 50., y: 50.),
+            (x: 40., y: 50.),
+            (x: 40., y: 40.),
+        ]);
+        let parsed_svg =
+            svg_d_path_to_geometry_collection("M0 0L10 0M20 0L30 0M40 40L50 40L50 50L40 50L40 40");
+        assert!(parsed_svg.is_ok());
+        let expected = Geometry::MultiLineString(MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 20., y: 0.), (x: 30., y: 0.)],
+        ]));
+        assert_eq!(vec![expected, square.clone()], parsed_svg.ok().unwrap().0);
+
+        // Open subpaths of more than two points are collected the same way
+        let parsed_svg = svg_d_path_to_geometry_collection(
+            "M0 0L10 0L10 5M20 0L30 0L30 5M40 40L50 40L50 50L40 50Z",
+        );
+        assert!(parsed_svg.is_ok());
+        let expected = Geometry::MultiLineString(MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.)],
+            line_string![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 5.)],
+        ]));
+        assert_eq!(vec![expected, square], parsed_svg.ok().unwrap().0);
+    }
+
+    #[test]
+    fn can_convert_svg_shapes_with_px_and_percentages() {
+        let options = ParseOptions {
+            viewport: Some((200., 100.)),
+            ..Default::default()
+        };
+        let cases = [
+            (
+                r#"<rect x="10px" y=" 5px " width="20px" height="10"/>"#,
+                r#"<rect x="10" y="5" width="20" height="10"/>"#,
+            ),
+            (
+                r#"<rect x="10%" y="10%" width="50%" height="50%" rx="5%"/>"#,
+                r#"<rect x="20" y="10" width="100" height="50" rx="10"/>"#,
+            ),
+            (
+                r#"<line x1="0%" y1="0" x2="100%" y2="100%"/>"#,
+                r#"<line x1="0" y1="0" x2="200" y2="100"/>"#,
+            ),
+            (
+                r#"<ellipse cx="50%" cy="50%" rx="25%" ry="25%"/>"#,
+                r#"<ellipse cx="100" cy="50" rx="50" ry="25"/>"#,
+            ),
+        ];
+        for (svg, expected) in cases {
+            let parsed_svg = svg_to_geometry_collection_with_options(svg, &options);
+            assert!(parsed_svg.is_ok());
+            let expected = svg_to_geometry_collection(expected);
+            assert!(expected.is_ok());
+            assert_eq!(expected.ok().unwrap(), parsed_svg.ok().unwrap());
+        }
+
+        // A radius is a percentage of the normalized diagonal, sqrt((w² + h²) / 2)
+        let options = ParseOptions {
+            viewport: Some((30., 40.)),
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(r#"<circle r="20%"/>"#, &options);
+        assert!(parsed_svg.is_ok());
+        let expected =
+            svg_to_geometry_collection(&format!(r#"<circle r="{}"/>"#, 1250_f64.sqrt() / 5.));
+        assert_eq!(expected.ok().unwrap(), parsed_svg.ok().unwrap());
+
+        // Percentages cannot be resolved without a viewport
+        match svg_to_geometry_collection(r#"<rect x="0" y="0" width="50%" height="10"/>"#) {
+            Err(SvgError::InvalidSvgError(error)) => assert_eq!(
+                r#"percentage without a viewport in attribute "width" on <rect>"#,
+                error.to_string()
+            ),
+            _ => panic!("expected an InvalidSvgError for a percentage without a viewport"),
+        }
+
+        // The root <svg> sets the viewport, from its viewBox before its width and height
+        let expected = svg_to_geometry_collection(r#"<rect x="0" y="0" width="50" height="20"/>"#);
+        for svg in [
+            r#"<svg width="100" height="40px"><rect width="50%" height="50%"/></svg>"#,
+            r#"<svg width="10cm" height="4cm" viewBox="0 0 100 40"><rect width="50%" height="50%"/></svg>"#,
+        ] {
+            let parsed_svg = svg_to_geometry_collection(svg);
+            assert!(parsed_svg.is_ok());
+            assert_eq!(expected.as_ref().ok(), parsed_svg.as_ref().ok());
+            let parsed_svg = svg_document_to_geometry_collection(svg);
+            assert!(parsed_svg.is_ok());
+            assert_eq!(expected.as_ref().ok(), parsed_svg.as_ref().ok());
+        }
+        // A viewport in the options takes precedence
+        let parsed_svg = svg_document_to_geometry_collection_with_options(
+            r#"<svg width="100" height="40"><rect width="50%" height="50%"/></svg>"#,
+            &ParseOptions {
+                viewport: Some((200., 100.)),
+                ..Default::default()
+            },
+        );
+        assert!(parsed_svg.is_ok());
+        let expected = svg_to_geometry_collection(r#"<rect width="100" height="50"/>"#);
+        assert_eq!(expected.ok().unwrap(), parsed_svg.ok().unwrap());
+        // Other units cannot be converted to user units
+        assert!(svg_document_to_geometry_collection(
+            r#"<svg width="10cm" height="4cm"><rect width="50%" height="10"/></svg>"#
+        )
+        .is_err());
+        assert!(matches!(
+            svg_to_geometry_collection(r#"<circle r="5pt"/>"#),
+            Err(SvgError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn can_validate_svg_polygon_rings() {
+        let options = ParseOptions {
+            validate: true,
+            ..Default::default()
+        };
+        // Two points make a closed ring of 3 coordinates
+        let svg = r#"<polygon points="0,0 10,0"/>"#;
+        assert!(svg_to_geometry_collection(svg).is_ok());
+        match svg_to_geometry_collection_with_options(svg, &options) {
+            Err(SvgError::InvalidSvgError(error)) => assert_eq!(
+                "Polygon exterior ring with 3 coordinates (at least 4 are needed) on <polygon>",
+                error.to_string()
+            ),
+            _ => panic!("expected an InvalidSvgError for a 3-coordinate ring"),
+        }
+
+        // geo_types closes the rings of every Polygon it builds, so an unclosed ring is checked
+        // on its own
+        let unclosed =
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        match validate_ring(&unclosed, "interior ring 0") {
+            Err(SvgError::InvalidSvgError(error)) => assert_eq!(
+                "Polygon interior ring 0 that is not closed",
+                error.to_string()
+            ),
+            _ => panic!("expected an InvalidSvgError for an unclosed ring"),
+        }
+        let mut closed = unclosed.clone();
+        closed.close();
+        assert!(validate_ring(&closed, "exterior ring").is_ok());
+
+        // Valid Polygons, including those from paths, rects, and circles, pass
+        for svg in [
+            r#"<polygon points="0,0 10,0 10,10"/>"#,
+            r#"<path d="M0 0L10 0L10 10L0 10ZM2 2L4 2L4 4Z"/>"#,
+            r#"<rect x="0" y="0" width="10" height="10"/>"#,
+            r#"<circle r="5"/>"#,
+        ] {
+            assert!(svg_to_geometry_collection_with_options(svg, &options).is_ok());
+        }
+        assert!(
+            svg_d_path_to_geometry_collection_with_options("M0 0L10 0L10 10Z", &options).is_ok()
+        );
+
+        // Every entry point taking ParseOptions validates
+        assert!(svg_d_path_to_geometry_collection_with_options("M0 0L10 0Z", &options).is_err());
+        assert!(svg_d_path_to_geometry_collection_with_warnings("M0 0L10 0Z", &options).is_err());
+        assert!(
+            svg_d_path_to_geometry_collection_with_warnings("M0 0L10 0L10 10Z", &options).is_ok()
+        );
+    }
+
+    #[test]
+    fn can_convert_unfilled_svg_shapes_to_lines() {
+        let options = ParseOptions {
+            unfilled_as_lines: true,
+            ..Default::default()
+        };
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        for svg in [
+            r#"<path d="M0 0L10 0L10 10L0 10Z" fill="none" stroke="black"/>"#,
+            r#"<path d="M0 0L10 0L10 10L0 10Z" style="fill:none;stroke:black"/>"#,
+        ] {
+            let parsed_svg = svg_to_geometry_collection_with_options(svg, &options);
+            assert!(parsed_svg.is_ok());
+            assert_eq!(
+                vec![Geometry::LineString(square.clone())],
+                parsed_svg.ok().unwrap().0
+            );
+        }
+
+        // Holes and separate polygons are kept as rings of a MultiLineString
+        let hole = line_string![
+            (x: 2., y: 2.),
+            (x: 2., y: 8.),
+            (x: 8., y: 8.),
+            (x: 8., y: 2.),
+            (x: 2., y: 2.),
+        ];
+        let svg =
+            r#"<path fill=" none " d="M0 0L10 0L10 10L0 10ZM2 2L2 8L8 8L8 2ZM20 0L25 5L20 5Z"/>"#;
+        let parsed_svg = svg_to_geometry_collection_with_options(svg, &options);
+        assert!(parsed_svg.is_ok());
+        let triangle =
+            line_string![(x: 20., y: 0.), (x: 25., y: 5.), (x: 20., y: 5.), (x: 20., y: 0.)];
+        assert_eq!(
+            vec![Geometry::MultiLineString(MultiLineString(vec![
+                square.clone(),
+                hole,
+                triangle
+            ]))],
+            parsed_svg.ok().unwrap().0
+        );
+
+        // Other shapes are drawn as outlines the same way
+        let svg = r#"<rect x="0" y="0" width="10" height="10" fill="none"/>"#;
+        let parsed_svg = svg_to_geometry_collection_with_options(svg, &options);
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_line_string()
+            .is_some());
+
+        // Filled shapes, and every shape without the option, are still Polygons
+        for (svg, options) in [
+            (r#"<path d="M0 0L10 0L10 10L0 10Z"/>"#, &options),
+            (r#"<path d="M0 0L10 0L10 10L0 10Z" fill="red"/>"#, &options),
+            (
+                r#"<path d="M0 0L10 0L10 10L0 10Z" fill="none"/>"#,
+                &ParseOptions::default(),
+            ),
+        ] {
+            let parsed_svg = svg_to_geometry_collection_with_options(svg, options);
+            assert!(parsed_svg.is_ok(), "{}", svg);
+            assert!(
+                parsed_svg.ok().unwrap().0[0]
+                    .clone()
+                    .into_polygon()
+                    .is_some(),
+                "{}",
+                svg
+            );
+        }
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_fill_rule() {
+        let outer = polygon![(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 20.), (x: 0., y: 20.)];
+        let hole = line_string![
+            (x: 4., y: 4.),
+            (x: 4., y: 16.),
+            (x: 16., y: 16.),
+            (x: 16., y: 4.),
+            (x: 4., y: 4.),
+        ];
+        let opposing = "M0 0L20 0L20 20L0 20ZM4 4L4 16L16 16L16 4Z";
+        let matching = "M0 0L20 0L20 20L0 20ZM4 4L16 4L16 16L4 16Z";
+        let parse = |d: &str, fill_rule: FillRule| {
+            let options = ParseOptions {
+                fill_rule,
+                ..Default::default()
+            };
+            let parsed_svg = svg_d_path_to_geometry_collection_with_options(d, &options);
+            assert!(parsed_svg.is_ok());
+            parsed_svg.ok().unwrap().0[0].clone()
+        };
+        let holed = Geometry::Polygon(Polygon::new(outer.exterior().clone(), vec![hole]));
+
+        // A ring winding the other way is a hole with either rule
+        assert_eq!(holed, parse(opposing, FillRule::NonZero));
+        assert_eq!(holed, parse(opposing, FillRule::EvenOdd));
+        // One winding the same way only is with even-odd, and is filled with non-zero
+        assert_eq!(
+            Geometry::Polygon(outer.clone()),
+            parse(matching, FillRule::NonZero)
+        );
+        assert_eq!(
+            Some(1),
+            parse(matching, FillRule::EvenOdd)
+                .into_polygon()
+                .map(|poly| poly.interiors().len())
+        );
+
+        // Non-zero is the default, as in browsers, and the fill-rule attribute of the element
+        // takes precedence over the option
+        assert_eq!(FillRule::NonZero, ParseOptions::default().fill_rule);
+        let parsed_svg = svg_d_path_to_geometry_collection(matching);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Geometry::Polygon(outer.clone()),
+            parsed_svg.ok().unwrap().0[0]
+        );
+        let parsed_svg =
+            svg_to_geometry(&format!(r#"<path d="{}" fill-rule="evenodd"/>"#, matching));
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(1),
+            parsed_svg
+                .ok()
+                .unwrap()
+                .into_polygon()
+                .map(|poly| poly.interiors().len())
+        );
+        let options = ParseOptions {
+            fill_rule: FillRule::EvenOdd,
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            &format!(r#"<path d="{}" fill-rule="nonzero"/>"#, matching),
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Geometry::Polygon(outer.clone()),
+            parsed_svg.ok().unwrap().0[0]
+        );
+
+        // An island winding like the outer ring inside a hole winding the other way is filled
+        // again with non-zero, so it is a Polygon of its own
+        let island = "M0 0L20 0L20 20L0 20ZM4 4L4 16L16 16L16 4ZM8 8L12 8L12 12L8 12Z";
+        assert_eq!(
+            Some(2),
+            parse(island, FillRule::NonZero)
+                .into_multi_polygon()
+                .map(|polys| polys.0.len())
+        );
+        // Inside a ring winding like the outer one, everything stays filled with non-zero
+        let island_within_filled =
+            "M0 0L20 0L20 20L0 20ZM4 4L16 4L16 16L4 16ZM8 8L12 8L12 12L8 12Z";
+        assert_eq!(
+            Geometry::Polygon(outer),
+            parse(island_within_filled, FillRule::NonZero)
+        );
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_island_in_hole() {
+        let parsed_svg = svg_d_path_to_geometry_collection(
+            "M0 0L20 0L20 20L0 20ZM4 4L4 16L16 16L16 4ZM8 8L12 8L12 12L8 12Z",
+        );
+        assert!(parsed_svg.is_ok());
+
+        let polygons = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_multi_polygon()
+            .unwrap();
+        assert_eq!(2, polygons.0.len());
+        assert_eq!(1, polygons.0[0].interiors().len());
+        assert_eq!(0, polygons.0[1].interiors().len());
+        assert_eq!(Coordinate { x: 8., y: 8. }, polygons.0[1].exterior().0[0]);
+    }
+
+    #[test]
+    fn can_report_svg_parse() {
+        let svg_string = String::from(
+            r#"<svg><title>Shapes</title><path d="M0 0L10 0Q20 5 10 10L0 10ZM20 20L30 30"/></svg>"#,
+        );
+        let parsed_svg = svg_to_geometry_collection_reported(&svg_string);
+        assert!(parsed_svg.is_ok());
+        let (geom, report) = parsed_svg.ok().unwrap();
+        assert_eq!(geom.0.len(), report.shapes);
+        assert_eq!(1, report.curves_flattened);
+        assert_eq!(
+            geom.0.iter().map(count_vertices).sum::<usize>(),
+            report.vertices
+        );
+        assert_eq!(
+            vec![String::from("skipped unsupported element <title>")],
+            report.warnings
+        );
+    }
+
+    #[test]
+    fn can_convert_rectangular_path_to_rect() {
+        let svg_string = String::from(r#"<path d="M10 10L10 40L60 40L60 10Z"/>"#);
+        let options = ParseOptions {
+            prefer_rect: true,
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(&svg_string, &options);
+        assert!(parsed_svg.is_ok());
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        let rect: Geometry<f64> =
+            Rect::new(Coordinate { x: 10., y: 10. }, Coordinate { x: 60., y: 40. }).into();
+        assert_eq!(rect, geom.0[0]);
+
+        // Without the option the rectangle stays a Polygon
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .is_some());
+    }
+
+    #[test]
+    fn prefer_rect_keeps_non_rectangular_polygon() {
+        let options = ParseOptions {
+            prefer_rect: true,
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<polygon points="0,0 60,0 70,30 0,30 0,0"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .is_some());
+    }
+
+    #[test]
+    fn can_convert_attribute_maps() {
+        let elements = vec![
+            ("path", vec![("d", "M0 0L0 60L60 60L60 0Z")]),
+            ("polygon", vec![("points", "0,0 0,60 60,60 60,0 0,0")]),
+            ("polyline", vec![("points", "0,0 0,60 60,60")]),
+            (
+                "rect",
+                vec![("x", "0"), ("y", "0"), ("width", "60"), ("height", "60")],
+            ),
+            (
+                "line",
+                vec![("x1", "0"), ("y1", "0"), ("x2", "60"), ("y2", "60")],
+            ),
+        ];
+        for (name, attributes) in elements {
+            let attrs = attributes
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<String, String>>();
+            let svg_string = format!(
+                "<{} {}/>",
+                name,
+                attributes
+                    .iter()
+                    .map(|(k, v)| format!("{}=\"{}\"", k, v))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            );
+
+            let from_attrs = element_attrs_to_geometry(name, &attrs);
+            let from_xml = svg_to_geometry_collection(&svg_string);
+            assert!(from_attrs.is_ok());
+            assert!(from_xml.is_ok());
+            assert_eq!(from_xml.ok().unwrap(), from_attrs.ok().unwrap());
+        }
+    }
+
+    #[test]
+    fn can_convert_line_attribute_map() {
+        let mut attrs = HashMap::new();
+        attrs.insert(String::from("x1"), String::from("1"));
+        attrs.insert(String::from("y1"), String::from("2"));
+        attrs.insert(String::from("x2"), String::from("3.5"));
+        attrs.insert(String::from("y2"), String::from("4"));
+        let parsed_svg = element_attrs_to_geometry("line", &attrs);
+        assert!(parsed_svg.is_ok());
+        let line = parsed_svg.ok().unwrap().0[0].clone().into_line();
+        assert_eq!(
+            Some(Line::new(
+                Coordinate { x: 1., y: 2. },
+                Coordinate { x: 3.5, y: 4. }
+            )),
+            line
+        );
+    }
+
+    #[test]
+    fn cannot_convert_incomplete_or_unsupported_attribute_map() {
+        let mut attrs = HashMap::new();
+        attrs.insert(String::from("x"), String::from("0"));
+        assert!(element_attrs_to_geometry("rect", &attrs).is_err());
+        assert!(element_attrs_to_geometry("text", &attrs).is_err());
+    }
+
+    #[test]
+    fn can_convert_svg_path_bytes() {
+        let svg_string = "M0 0C0 30 30 40 40 40L40 0ZM50 50l10 0";
+        let from_bytes = svg_d_path_bytes_to_geometry_collection(svg_string.as_bytes());
+        let from_str = svg_d_path_to_geometry_collection(svg_string);
+        assert!(from_bytes.is_ok());
+        assert!(from_str.is_ok());
+        assert_eq!(from_str.ok().unwrap(), from_bytes.ok().unwrap());
+
+        assert!(svg_d_path_bytes_to_geometry_collection(&[b'M', 0xff, b'0']).is_err());
+    }
+
+    #[test]
+    fn can_get_subpath_windings() {
+        // An outer ring, an opposite-wound hole, an island in the hole wound like the outer ring,
+        // and an open subpath
+        let svg_string = "M0 0L30 0L30 30L0 30Z M5 5L5 25L25 25L25 5Z M10 10L20 10L20 20L10 20Z \
+            M40 0L50 10";
+        let parsed_svg = svg_d_path_subpaths_with_winding(svg_string);
+        assert!(parsed_svg.is_ok());
+        let subpaths = parsed_svg.ok().unwrap();
+        assert_eq!(3, subpaths.len());
+        assert_eq!(
+            vec![1, -1, 1],
+            subpaths.iter().map(|(_, w)| *w).collect::<Vec<i32>>()
+        );
+        assert_eq!(Coordinate { x: 5., y: 5. }, subpaths[1].0[0]);
+        assert_eq!(5, subpaths[1].0.len());
+    }
+
+    #[test]
+    fn can_parse_unicode_minus_when_normalizing() {
+        let options = ParseOptions {
+            normalize_unicode: true,
+            ..Default::default()
+        };
+        let parsed_svg = svg_d_path_to_geometry_collection_with_warnings(
+            "M0\u{a0}0L\u{2212}10 0L\u{2212}10\u{2009}\u{2212}10.5",
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let (geom, warnings) = parsed_svg.ok().unwrap();
+        assert!(warnings.is_empty());
+        let line: LineString<f64> = line_string![
+            (x: 0.0_f64, y: 0.0),
+            (x: -10.0, y: 0.0),
+            (x: -10.0, y: -10.5),];
+        assert_eq!(Some(line), geom.0[0].clone().into_line_string());
+
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            "<line x1=\"\u{2212}1\" y1=\"0\" x2=\"\u{2013}2\" y2=\"3\"/>",
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(Line::new(
+                Coordinate { x: -1., y: 0. },
+                Coordinate { x: -2., y: 3. }
+            )),
+            parsed_svg.ok().unwrap().0[0].clone().into_line()
+        );
+
+        // Documents are normalized too, including the transforms of groups and uses
+        let svg_string = "<svg>
+            <defs><line id=\"l\" x1=\"\u{2212}1\" y1=\"0\" x2=\"1\" y2=\"0\"/></defs>
+            <g transform=\"translate(\u{2212}5\u{a0}0)\"><use href=\"#l\" y=\"\u{2212}2\"/></g>
+        </svg>";
+        assert!(svg_document_to_geometry_collection(svg_string).is_err());
+        let parsed_svg = svg_document_to_geometry_collection_with_options(svg_string, &options);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            vec![Geometry::Line(Line::new(
+                Coordinate { x: -6., y: -2. },
+                Coordinate { x: -4., y: -2. }
+            ))],
+            parsed_svg.ok().unwrap().0
+        );
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_transform() {
+        let svg_string = String::from(
+            r#"<path transform="translate(10 20) scale(2)" d="M0 0L0 5L5 5L5 0Z" fill-rule="evenodd"/>"#,
+        );
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert!(parsed_svg.is_ok());
+        let untransformed = svg_d_path_to_geometry_collection("M0 0L0 5L5 5L5 0Z");
+        assert!(untransformed.is_ok());
+
+        // Every coordinate is scaled, then translated
+        let pl = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        let original = untransformed.ok().unwrap().0[0].clone().into_polygon();
+        assert!(pl.is_some());
+        assert!(original.is_some());
+        let expected = original
+            .unwrap()
+            .exterior()
+            .0
+            .iter()
+            .map(|c| Coordinate {
+                x: c.x * 2. + 10.,
+                y: c.y * 2. + 20.,
+            })
+            .collect::<LineString<f64>>();
+        assert_eq!(&expected, pl.unwrap().exterior());
+
+        // The fill-rule in the style overrides the attribute
+        let nested = "M0 0L10 0L10 10L0 10ZM2 2L8 2L8 8L2 8Z";
+        let holes = |svg: &str| {
+            svg_to_geometry(svg)
+                .ok()
+                .and_then(|geom| geom.into_polygon())
+                .map(|poly| poly.interiors().len())
+        };
+        assert_eq!(
+            Some(0),
+            holes(&format!(
+                r#"<path d="{}" style="stroke: black; fill-rule: nonzero"/>"#,
+                nested
+            ))
+        );
+        assert_eq!(
+            Some(1),
+            holes(&format!(
+                r#"<path d="{}" fill-rule="nonzero" style="fill-rule:evenodd"/>"#,
+                nested
+            ))
+        );
+    }
+
+    #[test]
+    fn can_close_coincident_polyline() {
+        let options = ParseOptions {
+            close_coincident_polylines: true,
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<polyline points="0,0 10,0 10,10 0.0000000001,0"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let pl = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(pl.is_some());
+        let poly = pl.unwrap();
+        assert_eq!(4, poly.exterior().0.len());
+        assert_eq!(poly.exterior().0[0], poly.exterior().0[3]);
+
+        // The spec behavior is kept without the option
+        let parsed_svg = svg_to_geometry_collection(r#"<polyline points="0,0 10,0 10,10 0,0"/>"#);
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_line_string()
+            .is_some());
+    }
+
+    #[test]
+    fn cannot_close_non_coincident_polyline() {
+        let options = ParseOptions {
+            close_coincident_polylines: true,
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<polyline points="0,0 10,0 10,10 0,0.1"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let ls = parsed_svg.ok().unwrap().0[0].clone().into_line_string();
+        assert!(ls.is_some());
+        assert_eq!(4, ls.unwrap().0.len());
+    }
+
+    #[test]
+    fn can_sample_arc_to_linestring() {
+        use std::f64::consts::PI;
+
+        // Half of an ellipse rotated by 90 degrees, so its x-axis points down the y-axis
+        let arc = arc_to_linestring(10., 20., 4., 2., PI / 2., 0., PI, 5);
+        let expected = [
+            Coordinate { x: 10., y: 24. },
+            Coordinate {
+                x: 10. - 2_f64.sqrt(),
+                y: 20. + 8_f64.sqrt(),
+            },
+            Coordinate { x: 8., y: 20. },
+            Coordinate {
+                x: 10. - 2_f64.sqrt(),
+                y: 20. - 8_f64.sqrt(),
+            },
+            Coordinate { x: 10., y: 16. },
+        ];
+        assert_eq!(expected.len(), arc.0.len());
+        for (c, e) in arc.0.iter().zip(expected.iter()) {
+            assert!((c.x - e.x).abs() < 1e-9);
+            assert!((c.y - e.y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn can_convert_svg_arc_path() {
+        let parsed_svg = svg_d_path_to_geometry_collection("M10 0A10 10 0 0 1 0 10");
+        assert!(parsed_svg.is_ok());
+        let ls = parsed_svg.ok().unwrap().0[0].clone().into_line_string();
+        assert!(ls.is_some());
+        let ls = ls.unwrap();
+        assert_eq!(Coordinate { x: 10., y: 0. }, ls.0[0]);
+        assert_eq!(Coordinate { x: 0., y: 10. }, ls.0[ls.0.len() - 1]);
+        // The small, positive-angle arc is centered on the origin
+        for c in ls.0.iter() {
+            assert!((c.x.hypot(c.y) - 10.).abs() < 1e-9);
+            assert!(c.x >= -1e-9 && c.y >= -1e-9);
+        }
+
+        // The large arc goes the long way round, centered on (10, 10)
+        let parsed_svg = svg_d_path_to_geometry_collection("M10 0a10 10 0 1 1 -10 10");
+        assert!(parsed_svg.is_ok());
+        let ls = parsed_svg.ok().unwrap().0[0].clone().into_line_string();
+        assert!(ls.is_some());
+        for c in ls.unwrap().0.iter() {
+            assert!(((c.x - 10.).hypot(c.y - 10.) - 10.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn can_convert_svg_arc_path_with_compact_flags() {
+        // The flags are single characters, so they need no separators, e.g., `0110` is the
+        // large-arc flag 0, the sweep flag 1 and then the x coordinate 10
+        for (compact, spaced) in [
+            ("M0 0A5 5 0 0110 10", "M0 0A5 5 0 0 1 10 10"),
+            ("M0 0a5 5 0 10-10 10", "M0 0a5 5 0 1 0 -10 10"),
+            ("M0 0A5 5 30 11.5.5", "M0 0A5 5 30 1 1 .5 .5"),
+            ("M0 0A5,5,0,0,1,10,10", "M0 0A5 5 0 0 1 10 10"),
+            (
+                "M0 0A5 5 0 0110 10 5 5 0 0020 20",
+                "M0 0A5 5 0 0 1 10 10A5 5 0 0 0 20 20",
+            ),
+        ] {
+            let parsed_compact = svg_d_path_to_geometry_collection(compact);
+            let parsed_spaced = svg_d_path_to_geometry_collection(spaced);
+            assert!(parsed_compact.is_ok(), "{}", compact);
+            assert!(parsed_spaced.is_ok(), "{}", spaced);
+            assert_eq!(
+                parsed_spaced.ok().unwrap(),
+                parsed_compact.ok().unwrap(),
+                "{}",
+                compact
+            );
+        }
+    }
+
+    #[test]
+    fn can_auto_close_near_closed_path() {
+        let svg_string = String::from(r#"<path d="M0 0L10 0L10 10L0.01 0.01"/>"#);
+
+        // By default the unclosed ring is a LineString
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_line_string()
+            .is_some());
+
+        let options = ParseOptions {
+            auto_close_rings: Some(0.1),
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(&svg_string, &options);
+        assert!(parsed_svg.is_ok());
+        let pl = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(pl.is_some());
+        let expected: LineString<f64> = line_string![
+            (x: 0.0_f64, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.01, y: 0.01),
+            (x: 0.0, y: 0.0),];
+        assert_eq!(&expected, pl.unwrap().exterior());
+
+        // A tool that drops the closing point leaves the last point slightly off the first
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<path d="M0 0L10 0L10 10L0 10L0.0001 0"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let pl = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(pl.is_some());
+        let exterior = pl.unwrap().exterior().clone();
+        assert_eq!(6, exterior.0.len());
+        assert_eq!(exterior.0[0], exterior.0[5]);
+
+        // Genuinely open paths and polylines are left open
+        let parsed_svg =
+            svg_to_geometry_collection_with_options(r#"<path d="M0 0L10 0L10 10L1 1"/>"#, &options);
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_line_string()
+            .is_some());
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<polyline points="0,0 10,0 10,10 0.01,0.01"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_line_string()
+            .is_some());
+    }
+
+    #[test]
+    fn can_snap_near_closed_path_closed() {
+        let options = ParseOptions {
+            auto_close_rings: Some(0.001),
+            auto_close_mode: AutoClose::Snap,
+            ..Default::default()
+        };
+        // The last point is 0.0001 off the first, and is moved onto it rather than followed by it
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<path d="M0 0L10 0L10 10L0 10L0.0001 0"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let pl = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(pl.is_some());
+        let expected: LineString<f64> = line_string![
+            (x: 0.0_f64, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 10.0),
+            (x: 0.0, y: 0.0),];
+        assert_eq!(&expected, pl.unwrap().exterior());
+
+        // Ends further apart than the tolerance, or too few points for a ring, are left open
+        for svg in [
+            r#"<path d="M0 0L10 0L10 10L0 10L0.01 0"/>"#,
+            r#"<path d="M0 0L10 0L0.0001 0"/>"#,
+        ] {
+            let parsed_svg = svg_to_geometry_collection_with_options(svg, &options);
+            assert!(parsed_svg.is_ok());
+            assert!(parsed_svg.ok().unwrap().0[0]
+                .clone()
+                .into_line_string()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn can_convert_mixed_smooth_and_regular_curves() {
+        // Every smooth curve is equal to its regular curve with the reflected control point
+        let smooth = svg_d_path_to_geometry_collection(
+            "M0 0C0 10 10 10 10 0S20 -10 20 0Q25 10 30 0T40 0T50 0",
+        );
+        let regular = svg_d_path_to_geometry_collection(
+            "M0 0C0 10 10 10 10 0C10 -10 20 -10 20 0Q25 10 30 0Q35 -10 40 0Q45 10 50 0",
+        );
+        assert!(smooth.is_ok());
+        assert!(regular.is_ok());
+        assert_eq!(regular.ok().unwrap(), smooth.ok().unwrap());
+
+        let smooth =
+            svg_d_path_to_geometry_collection("M0 0Q5 10 10 0T20 0C20 10 30 10 30 0S40 -10 40 0");
+        let regular = svg_d_path_to_geometry_collection(
+            "M0 0Q5 10 10 0Q15 -10 20 0C20 10 30 10 30 0C30 -10 40 -10 40 0",
+        );
+        assert!(smooth.is_ok());
+        assert!(regular.is_ok());
+        assert_eq!(regular.ok().unwrap(), smooth.ok().unwrap());
+    }
+
+    #[test]
+    fn cannot_convert_lossy_input_when_lossless() {
+        let options = ParseOptions {
+            lossless: true,
+            ..Default::default()
+        };
+
+        // Every curve command can only be approximated
+        for (d, command) in [
+            ("M0 0C0 10 10 10 10 0", 'C'),
+            ("M0 0C0 10 10 10 10 0S20 -10 20 0", 'C'),
+            ("M0 0L10 0s10 -10 10 0", 's'),
+            ("M0 0Q5 10 10 0", 'Q'),
+            ("M0 0L10 0T20 0", 'T'),
+            ("M0 0a5 5 0 0 1 10 0", 'a'),
+        ] {
+            let element = format!(r#"<path d="{}"/>"#, d);
+            match svg_to_geometry_collection_with_options(&element, &options) {
+                Err(SvgError::LossyOperation { what }) => {
+                    assert_eq!(format!("flattening the {} curve command", command), what)
+                }
+                _ => panic!("expected a LossyOperation for {}", d),
+            }
+        }
+
+        // Lengths with a unit lose it when converted to user units
+        match svg_to_geometry_collection_with_options(
+            r#"<rect x="0" y="0" width="10mm" height="10"/>"#,
+            &options,
+        ) {
+            Err(SvgError::LossyOperation { what }) => {
+                assert_eq!("converting the width length 10mm to user units", what)
+            }
+            _ => panic!("expected a LossyOperation for a length with a unit"),
+        }
+        assert!(matches!(
+            svg_to_geometry_collection(r#"<rect x="0" y="0" width="10mm" height="10"/>"#),
+            Err(SvgError::ParseError(_))
+        ));
+
+        // Straight lines and unitless lengths are read exactly
+        assert!(svg_to_geometry_collection_with_options(
+            r#"<path d="M0 0H10V10L0 10Z"/>"#,
+            &options
+        )
+        .is_ok());
+        assert!(svg_to_geometry_collection_with_options(
+            r#"<line x1="0" y1="0" x2="10px" y2="10"/>"#,
+            &options
+        )
+        .is_err());
+        assert!(svg_to_geometry_collection_with_options(
+            r#"<rect x="0" y="0" width="10" height="10"/>"#,
+            &options
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn can_convert_svg_path_to_separate_parts() {
+        let parsed_svg =
+            svg_d_path_to_geometry_parts("M0 0L10 0M0 10L10 10L10 20M20 0L30 0L30 10L20 10Z");
+        assert!(parsed_svg.is_ok());
+
+        let parts = parsed_svg.ok().unwrap();
+        assert_eq!(3, parts.len());
+        assert_eq!(
+            Some(Line::new(
+                Coordinate { x: 0., y: 0. },
+                Coordinate { x: 10., y: 0. }
+            )),
+            parts[0].clone().into_line()
+        );
+        assert_eq!(
+            Some(line_string![(x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 20.)]),
+            parts[1].clone().into_line_string()
+        );
+        assert_eq!(
+            Some(polygon![
+                (x: 20., y: 0.),
+                (x: 30., y: 0.),
+                (x: 30., y: 10.),
+                (x: 20., y: 10.),
+            ]),
+            parts[2].clone().into_polygon()
+        );
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_character_references() {
+        // The XML reader decodes character references in attribute values before path parsing
+        let parsed_svg =
+            svg_to_geometry_collection(r#"<path d="M0 0&#x4C;10 0&#76;10 10&#x5A;"/>"#);
+        assert!(parsed_svg.is_ok());
+
+        let expected = svg_to_geometry_collection(r#"<path d="M0 0L10 0L10 10Z"/>"#);
+        assert!(expected.is_ok());
+        assert_eq!(expected.ok().unwrap(), parsed_svg.ok().unwrap());
+    }
+
+    #[test]
+    fn can_convert_svg_circle() {
+        let parsed_svg = svg_to_geometry_collection(r#"<circle cx="10" cy="20" r="5"/>"#);
+        assert!(parsed_svg.is_ok());
+
+        let pl = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(pl.is_some());
+        let ring = pl.unwrap().exterior().clone();
+        assert_eq!(ring.0.first(), ring.0.last());
+        assert!(ring.0.len() > 8);
+        for c in ring.0.iter() {
+            assert!(((c.x - 10.).hypot(c.y - 20.) - 5.).abs() < 1e-9);
+        }
+        // Every chord is within the 0.1 tolerance of the circle
+        let area = ring_area(&ring);
+        assert!(area < std::f64::consts::PI * 5. * 5.);
+        assert!(area > std::f64::consts::PI * 4.9 * 4.9);
+
+        // The center defaults to the origin
+        let parsed_svg = svg_to_geometry_collection(r#"<circle r="1"/>"#);
+        assert!(parsed_svg.is_ok());
+        let ring = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .unwrap()
+            .exterior()
+            .clone();
+        assert_eq!(Coordinate { x: 1., y: 0. }, ring.0[0]);
+
+        // The resolution follows the curve flattening
+        let options = ParseOptions {
+            curve_flattening: CurveFlattening::Segments(6),
+            ..Default::default()
+        };
+        let parsed_svg =
+            svg_to_geometry_collection_with_options(r#"<circle cx="0" cy="0" r="5"/>"#, &options);
+        assert!(parsed_svg.is_ok());
+        let pl = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .unwrap();
+        assert_eq!(7, pl.exterior().0.len());
+    }
+
+    #[test]
+    fn cannot_convert_invalid_svg_circle() {
+        assert!(svg_to_geometry_collection(r#"<circle cx="10" cy="20"/>"#).is_err());
+        assert!(svg_to_geometry_collection(r#"<circle cx="10" cy="20" r="-1"/>"#).is_err());
+    }
+
+    #[test]
+    fn can_convert_svg_ellipse() {
+        let parsed_svg = svg_to_geometry_collection(r#"<ellipse cx="10" cy="20" rx="8" ry="4"/>"#);
+        assert!(parsed_svg.is_ok());
+
+        let pl = parsed_svg.ok().unwrap().0[0].clone().into_polygon();
+        assert!(pl.is_some());
+        let ring = pl.unwrap().exterior().clone();
+        assert_eq!(ring.0.first(), ring.0.last());
+        for c in ring.0.iter() {
+            let (x, y) = ((c.x - 10.) / 8., (c.y - 20.) / 4.);
+            assert!((x.hypot(y) - 1.).abs() < 1e-9);
+        }
+        assert_eq!(Coordinate { x: 18., y: 20. }, ring.0[0]);
+        let area = ring_area(&ring);
+        assert!(area < std::f64::consts::PI * 8. * 4.);
+        assert!(area > std::f64::consts::PI * 7.9 * 3.9);
+
+        // The center defaults to the origin, and the resolution follows the curve flattening
+        let options = ParseOptions {
+            curve_flattening: CurveFlattening::Segments(8),
+            ..Default::default()
+        };
+        let parsed_svg =
+            svg_to_geometry_collection_with_options(r#"<ellipse rx="2" ry="1"/>"#, &options);
+        assert!(parsed_svg.is_ok());
+        let ring = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .unwrap()
+            .exterior()
+            .clone();
+        assert_eq!(9, ring.0.len());
+        assert_eq!(Coordinate { x: 2., y: 0. }, ring.0[0]);
+
+        assert!(svg_to_geometry_collection(r#"<ellipse cx="1" cy="1" rx="2"/>"#).is_err());
+        assert!(svg_to_geometry_collection(r#"<ellipse cx="1" cy="1" ry="2"/>"#).is_err());
+        assert!(svg_to_geometry_collection(r#"<ellipse rx="-2" ry="2"/>"#).is_err());
+    }
+
+    #[test]
+    fn can_convert_svg_rounded_rect() {
+        let parsed_svg =
+            svg_to_geometry_collection(r#"<rect x="0" y="0" width="20" height="10" rx="2"/>"#);
+        assert!(parsed_svg.is_ok());
+        let ring = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .unwrap()
+            .exterior()
+            .clone();
+        assert_eq!(ring.0.first(), ring.0.last());
+        assert!(ring.0.len() > 9);
+        // The straight sides end where the corners start
+        for corner in [
+            (18., 0.),
+            (20., 2.),
+            (20., 8.),
+            (18., 10.),
+            (2., 10.),
+            (0., 8.),
+            (0., 2.),
+            (2., 0.),
+        ] {
+            assert!(ring.0.contains(&Coordinate {
+                x: corner.0,
+                y: corner.1
+            }));
+        }
+        for c in ring.0.iter() {
+            assert!(c.x >= 0. && c.x <= 20. && c.y >= 0. && c.y <= 10.);
+        }
+        // Every corner cuts off its square minus a quarter circle
+        let area = ring_area(&ring);
+        assert!(area > 200. - 4. * 4. && area < 200. - (4. - std::f64::consts::PI) * 4.);
+
+        // rx defaults to ry, and both are clamped to half the side, making an ellipse
+        let options = ParseOptions {
+            curve_flattening: CurveFlattening::Segments(2),
+            ..Default::default()
+        };
+        let parsed_svg = svg_to_geometry_collection_with_options(
+            r#"<rect x="0" y="0" width="20" height="10" ry="50"/>"#,
+            &options,
+        );
+        assert!(parsed_svg.is_ok());
+        let ring = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .unwrap()
+            .exterior()
+            .clone();
+        let diagonal = 0.5_f64.sqrt();
+        let expected = line_string![
+            (x: 10., y: 0.),
+            (x: 10. + 10. * diagonal, y: 5. - 5. * diagonal),
+            (x: 20., y: 5.),
+            (x: 10. + 10. * diagonal, y: 5. + 5. * diagonal),
+            (x: 10., y: 10.),
+            (x: 10. - 10. * diagonal, y: 5. + 5. * diagonal),
+            (x: 0., y: 5.),
+            (x: 10. - 10. * diagonal, y: 5. - 5. * diagonal),
+            (x: 10., y: 0.),
+        ];
+        assert_eq!(expected.0.len(), ring.0.len());
+        for (expected, actual) in expected.0.iter().zip(ring.0.iter()) {
+            assert!((expected.x - actual.x).abs() < 1e-9 && (expected.y - actual.y).abs() < 1e-9);
+        }
+
+        // Without radii the rect keeps its four corners
+        let parsed_svg =
+            svg_to_geometry_collection(r#"<rect x="0" y="0" width="20" height="10" rx="0"/>"#);
+        assert!(parsed_svg.is_ok());
+        let pl = parsed_svg.ok().unwrap().0[0]
+            .clone()
+            .into_polygon()
+            .unwrap();
+        assert_eq!(5, pl.exterior().0.len());
+
+        assert!(svg_to_geometry_collection(
+            r#"<rect x="0" y="0" width="20" height="10" rx="-1"/>"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn can_convert_svg_document_skipping_defs() {
+        let svg_string = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <line x1="0" y1="0" x2="10" y2="10"/>
+            <defs>
+                <rect x="0" y="0" width="5" height="5"/>
+                <g><circle r="2"/></g>
+            </defs>
+            <rect x="0" y="0" width="60" height="60"/>
+            <polyline points="0,0 0,60 60,60"/>
+        </svg>"#;
+        let parsed_svg = svg_document_to_geometry_collection(svg_string);
+        assert!(parsed_svg.is_ok());
+
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(3, geom.0.len());
+        assert!(geom.0[0].clone().into_line().is_some());
+        assert!(geom.0[1].clone().into_polygon().is_some());
+        assert!(geom.0[2].clone().into_line_string().is_some());
+    }
+
+    #[test]
+    fn can_convert_svg_document_with_use_references() {
+        let square = |x: f64, y: f64| {
+            Geometry::Polygon(polygon![
+                (x: x, y: y),
+                (x: x + 5., y: y),
+                (x: x + 5., y: y + 5.),
+                (x: x, y: y + 5.),
+                (x: x, y: y),
+            ])
+        };
+        let svg_string = r##"<svg xmlns="http://www.w3.org/2000/svg">
+            <defs><rect id="r" x="0" y="0" width="5" height="5"/></defs>
+            <use href="#r" x="10"/>
+        </svg>"##;
+        let parsed_svg = svg_document_to_geometry_collection(svg_string);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(vec![square(10., 0.)], parsed_svg.ok().unwrap().0);
+
+        // Rendered shapes can be referenced too, before or after them, with the transforms of
+        // the <use> and its groups applied after its position
+        let svg_string = r##"<svg xmlns="http://www.w3.org/2000/svg"
+                xmlns:xlink="http://www.w3.org/1999/xlink">
+            <g transform="translate(100 0)"><use xlink:href="#r" y="10" transform="scale(2)"/></g>
+            <rect id="r" x="0" y="0" width="5" height="5"/>
+            <use href=" #r " x="-5"/>
+        </svg>"##;
+        let parsed_svg = svg_document_to_geometry_collection(svg_string);
+        assert!(parsed_svg.is_ok());
+        let scaled = Geometry::Polygon(polygon![
+            (x: 100., y: 20.),
+            (x: 110., y: 20.),
+            (x: 110., y: 30.),
+            (x: 100., y: 30.),
+            (x: 100., y: 20.),
+        ]);
+        assert_eq!(
+            vec![scaled, square(0., 0.), square(-5., 0.)],
+            parsed_svg.ok().unwrap().0
+        );
+
+        // Reading a document once only resolves references to earlier shapes
+        let svg_string = r##"<svg xmlns="http://www.w3.org/2000/svg">
+            <use href="#r"/>
+            <rect id="r" x="0" y="0" width="5" height="5"/>
+        </svg>"##;
+        assert!(svg_geometries_iter(svg_string).all(|geom| geom.is_ok()));
+        let mut iter = GeometryIter::new(svg_string.as_bytes());
+        assert!(iter.next().unwrap().is_err());
+
+        // Only the shapes that are referenced are kept for resolving references
+        let svg_string = r##"<svg xmlns="http://www.w3.org/2000/svg">
+            <rect id="a" x="0" y="0" width="5" height="5"/>
+            <use href="#c"/>
+            <defs><rect id="b" width="5" height="5"/><rect id="c" width="5" height="5"/></defs>
+        </svg>"##;
+        let mut iter = svg_geometries_iter(svg_string);
+        assert_eq!(2, iter.by_ref().filter(|geom| geom.is_ok()).count());
+        assert_eq!(vec!["c"], iter.ids.keys().collect::<Vec<_>>());
+
+        // References that cannot be resolved are errors rather than skipped
+        for (svg_use, reason) in [
+            (r##"<use href="#missing"/>"##, "not referencing a shape"),
+            (r##"<use href="#group"/>"##, "not referencing a shape"),
+            (r##"<use href="#bad"/>"##, "referencing an invalid shape"),
+            (
+                r##"<use href="shapes.svg#r"/>"##,
+                "not referencing the same document",
+            ),
+            (r#"<use x="10"/>"#, "missing"),
+        ] {
+            let svg_string = format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg">
+                <defs><g id="group"/><rect id="bad" width="5"/></defs>
+                {}
+            </svg>"#,
+                svg_use
+            );
+            match svg_document_to_geometry_collection(&svg_string) {
+                Err(SvgError::InvalidSvgError(e)) => {
+                    assert_eq!(Some(String::from("use")), e.element, "{}", svg_use);
+                    assert_eq!(Some(String::from("href")), e.attribute, "{}", svg_use);
+                    assert_eq!(reason, e.reason, "{}", svg_use);
+                }
+                res => panic!("{}: {:?}", svg_use, res),
+            }
+        }
+    }
+
+    #[test]
+    fn can_convert_svg_document_with_groups() {
+        let svg_string = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <g id="outer">
+                <rect x="0" y="0" width="10" height="10"/>
+                <g><rect x="20" y="0" width="10" height="10"/></g>
+            </g>
+            <line x1="0" y1="0" x2="10" y2="10"/>
+        </svg>"#;
+        let parsed_svg = svg_document_to_geometry_collection(svg_string);
+        assert!(parsed_svg.is_ok());
+
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(3, geom.0.len());
+        assert!(geom.0[0]
+            .clone()
+            .into_polygon()
+            .unwrap()
+            .exterior()
+            .0
+            .contains(&Coordinate { x: 0., y: 0. }));
+        assert!(geom.0[1]
+            .clone()
+            .into_polygon()
+            .unwrap()
+            .exterior()
+            .0
+            .contains(&Coordinate { x: 30., y: 10. }));
+        assert!(geom.0[2].clone().into_line().is_some());
+    }
+
+    #[test]
+    fn can_iterate_svg_document_geometries() {
+        let svg_string = r#"<svg>
+            <g transform="translate(10 0)"><line x2="1"/></g>
+            <path d="M0 0L10 0M20 20L30 20L30 30Z"/>
+            <defs><rect x="0" y="0" width="1" height="1"/></defs>
+            <polyline points="0,0 1,1"/>
+        </svg>"#;
+        let geoms = svg_geometries_iter(svg_string).collect::<Vec<_>>();
+        assert_eq!(4, geoms.len());
+        assert!(geoms.iter().all(|geom| geom.is_ok()));
+        assert_eq!(
+            svg_document_to_geometry_collection(svg_string)
+                .ok()
+                .unwrap()
+                .0,
+            geoms
+                .into_iter()
+                .map(|geom| geom.ok().unwrap())
+                .collect::<Vec<_>>()
+        );
+
+        // Shapes before an error are yielded, and nothing after it
+        let mut iter = GeometryIter::new(std::io::Cursor::new(
+            r#"<svg><line x2="1"/><circle r="-1"/><line x2="2"/></svg>"#,
+        ));
+        assert!(matches!(iter.next(), Some(Ok(_))));
+        assert!(matches!(
+            iter.next(),
+            Some(Err(SvgError::InvalidSvgError(_)))
+        ));
+        assert!(iter.next().is_none());
+
+        let mut iter = svg_geometries_iter("<g><line x2=\"1\"/></g>");
+        assert!(matches!(
+            iter.next(),
+            Some(Err(SvgError::InvalidSvgError(_)))
+        ));
+        assert!(iter.next().is_none());
+        assert_eq!(0, svg_geometries_iter("<svg></svg>").count());
+    }
+
+    #[test]
+    fn can_convert_svg_document_with_nested_transforms() {
+        let svg_string = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <g transform="translate(10,10)">
+                <g transform="scale(2)">
+                    <rect x="1" y="1" width="2" height="3" transform="translate(1 0)"/>
+                </g>
+                <line x1="0" y1="0" x2="1" y2="1"/>
+            </g>
+            <line x1="0" y1="0" x2="1" y2="1"/>
+        </svg>"#;
+        let parsed_svg = svg_document_to_geometry_collection(svg_string);
+        assert!(parsed_svg.is_ok());
+
+        let geom = parsed_svg.ok().unwrap();
+        assert_eq!(3, geom.0.len());
+        // The rect is moved by its own transform, then scaled, then translated
+        let mut exterior = geom.0[0]
+            .clone()
+            .into_polygon()
+            .unwrap()
+            .exterior()
+            .0
+            .clone();
+        exterior.pop();
+        exterior.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap()
+                .then(a.y.partial_cmp(&b.y).unwrap())
+        });
+        assert_eq!(
+            vec![
+                Coordinate { x: 14., y: 12. },
+                Coordinate { x: 14., y: 18. },
+                Coordinate { x: 18., y: 12. },
+                Coordinate { x: 18., y: 18. },
+            ],
+            exterior
+        );
+        assert_eq!(
+            Some(Line::new(
+                Coordinate { x: 10., y: 10. },
+                Coordinate { x: 11., y: 11. }
+            )),
+            geom.0[1].clone().into_line()
+        );
+        assert_eq!(
+            Some(Line::new(
+                Coordinate { x: 0., y: 0. },
+                Coordinate { x: 1., y: 1. }
+            )),
+            geom.0[2].clone().into_line()
+        );
+    }
+
+    #[test]
+    fn can_use_svg_error_as_std_error() {
+        use std::error::Error;
+
+        let error: Box<dyn Error> = Box::new(
+            svg_to_geometry_collection(r#"<rect x="0" y="0" width="ten" height="10"/>"#)
+                .err()
+                .unwrap(),
+        );
+        assert_eq!(
+            "A number in the SVG is invalid: invalid float literal",
+            error.to_string()
+        );
+        assert!(error.source().is_some());
+
+        let error = svg_to_geometry_collection(r#"<text>Label</text>"#)
+            .err()
+            .unwrap();
+        assert_eq!(
+            "The SVG could not be parsed to a valid Geometry type: unsupported element <text>",
+            error.to_string()
+        );
+        assert!(error.source().is_none());
+
+        let options = ParseOptions {
+            lossless: true,
+            ..Default::default()
+        };
+        let error = svg_to_geometry_collection_with_options(r#"<circle r="1"/>"#, &options)
+            .err()
+            .unwrap();
+        assert_eq!(
+            "The SVG could not be parsed without flattening a <circle>",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn cannot_convert_svg_elements_without_shape() {
+        for (svg, attribute, reason) in [
+            (r#"<path d=""/>"#, "d", "empty"),
+            (r#"<path d="   "/>"#, "d", "empty"),
+            (r#"<path/>"#, "d", "missing"),
+            (r#"<polygon points=""/>"#, "points", "empty"),
+            (r#"<polygon points="  "/>"#, "points", "empty"),
+            (r#"<polygon/>"#, "points", "missing"),
+            (r#"<polyline points=""/>"#, "points", "empty"),
+            (r#"<polyline points=" "/>"#, "points", "empty"),
+            (r#"<polyline/>"#, "points", "missing"),
+            (r#"<rect x="" y="0" width="1" height="1"/>"#, "x", "empty"),
+            (r#"<circle/>"#, "r", "missing"),
+            (r#"<circle r=" "/>"#, "r", "empty"),
+            (r#"<ellipse ry="1"/>"#, "rx", "missing"),
+            (r#"<ellipse rx="1"/>"#, "ry", "missing"),
+        ] {
+            match svg_to_geometry_collection(svg) {
+                Err(SvgError::InvalidSvgError(e)) => {
+                    assert_eq!(Some(String::from(attribute)), e.attribute, "{}", svg);
+                    assert_eq!(reason, e.reason, "{}", svg);
+                }
+                res => panic!("{}: {:?}", svg, res),
+            }
+        }
+
+        // A <line> without coordinates starts and ends at the origin, as its attributes default
+        // to 0, so like a path that never leaves its start it has no extent and produces nothing
+        for svg in ["<line/>", r#"<line x1="5" y1="5" x2="5" y2="5"/>"#] {
+            let parsed_svg = svg_to_geometry_collection(svg);
+            assert!(parsed_svg.is_ok());
+            assert!(parsed_svg.ok().unwrap().0.is_empty());
+        }
+
+        // In lenient mode, elements without their shape attribute are skipped with a warning
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let svg = r#"<g><path/><polygon/><polyline points="0,0 1,1"/></g>"#;
+        let parsed_svg = parse_first_element(svg.as_bytes(), &options);
+        assert!(parsed_svg.is_ok());
+        let (geom, report) = parsed_svg.ok().unwrap();
+        assert_eq!(1, geom.0.len());
+        assert_eq!(
+            vec![
+                "skipped <path> without a d attribute",
+                "skipped <polygon> without a points attribute"
+            ],
+            report.warnings
+        );
+    }
+
+    #[test]
+    fn can_describe_where_svg_is_invalid() {
+        let cases = [
+            (
+                r#"<rect x="0" y="0" height="10"/>"#,
+                r#"missing attribute "width" on <rect>"#,
+            ),
+            (r#"<circle r="-1"/>"#, "negative radius on <circle>"),
+            (
+                r#"<polygon points="0,0 10"/>"#,
+                r#"unpaired coordinate in attribute "points" on <polygon>"#,
+            ),
+            (
+                r#"<line x1="0" y1="0" x2="1" y2="1" transform="bogus"/>"#,
+                r#"invalid attribute "transform" on <line>"#,
+            ),
+        ];
+        for (svg, message) in cases {
+            match svg_to_geometry_collection(svg) {
+                Err(SvgError::InvalidSvgError(error)) => assert_eq!(message, error.to_string()),
+                _ => panic!("expected an InvalidSvgError for {}", svg),
+            }
+        }
+
+        let error = svg_to_geometry_collection(r#"<rect x="0" y="0" width="-5" height="10"/>"#)
+            .err()
+            .unwrap();
+        assert_eq!(
+            r#"The SVG input is invalid: negative attribute "width" on <rect>"#,
+            error.to_string()
+        );
+        match error {
+            SvgError::InvalidSvgError(error) => {
+                assert_eq!(Some(String::from("rect")), error.element);
+                assert_eq!(Some(String::from("width")), error.attribute);
+                assert_eq!("negative", error.reason);
+            }
+            _ => panic!("expected an InvalidSvgError"),
+        }
+
+        match svg_document_to_geometry_collection("<g></g>") {
+            Err(SvgError::InvalidSvgError(error)) => {
+                assert_eq!("root element <g> instead of <svg>", error.to_string())
+            }
+            _ => panic!("expected an InvalidSvgError for a document without an <svg>"),
+        }
+        match svg_to_geometry_collection("<svg><g></g></svg>") {
+            Err(SvgError::SvgInvalidType(error)) => assert_eq!(None, error.element),
+            _ => panic!("expected an SvgUnsupportedGeometryTypeError"),
+        }
+    }
+
+    #[test]
+    fn cannot_convert_malformed_svg_path() {
+        for svg_string in ["M0 0 L bogus", "M0 0L10", "X10 10", "M0 0L10 0B45"] {
+            match svg_d_path_to_geometry_collection(svg_string) {
+                Err(SvgError::PathParseError(_)) => {}
+                _ => panic!("expected a PathParseError for {}", svg_string),
+            }
+        }
+        assert!(svg_to_geometry_collection(r#"<path d="M0 0 L bogus"/>"#).is_err());
+    }
+
+    #[test]
+    fn can_convert_svg_path_with_implicit_line_to() {
+        let explicit = svg_d_path_to_geometry("M0 0L10 0L10 10Z");
+        assert!(explicit.is_ok());
+        let explicit = explicit.ok().unwrap();
+        assert_eq!(
+            Geometry::Polygon(polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0)]),
+            explicit
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::geo_svg_writer::ToSvg;
-    use geo_types::{line_string, polygon};
+        // Coordinates following a MoveTo are implicit LineTos, relative ones for an `m`
+        let implicit = svg_d_path_to_geometry("M0 0 10 0 10 10Z");
+        assert!(implicit.is_ok());
+        assert_eq!(explicit, implicit.ok().unwrap());
+        let implicit_relative = svg_d_path_to_geometry("m0 0 10 0 0 10z");
+        assert!(implicit_relative.is_ok());
+        assert_eq!(explicit, implicit_relative.ok().unwrap());
+    }
 
     #[test]
-    fn can_convert_svg_path() {
-        let poly = polygon!(
-        exterior: [
-            (x: 0.0, y: 0.0),
-            (x: 0.0, y: 60.0),
-            (x: 60.0, y: 60.0),
-            (x: 60.0, y: 0.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[[
-            (x: 10.0, y: 10.0),
-            (x: 40.0, y: 1.0),
-            (x: 40.0, y: 40.0),
-            (x: 10.50, y: 40.0),
-            (x: 10.0, y: 10.0),]
-            ]
+    fn can_convert_svg_path_with_relative_commands_after_close() {
+        let coords = |points: &[(f64, f64)]| {
+            points
+                .iter()
+                .map(|&(x, y)| Coordinate { x, y })
+                .collect::<Vec<Coordinate<f64>>>()
+        };
+
+        // The `m` is relative to the initial point of the closed subpath, not to its last point
+        let subpaths = svg_d_path_subpaths_with_winding("M10 10L20 10L20 20Zm5 5l10 0l0 10Z");
+        assert!(subpaths.is_ok());
+        let subpaths = subpaths.ok().unwrap();
+        assert_eq!(2, subpaths.len());
+        assert_eq!(
+            coords(&[(10., 10.), (20., 10.), (20., 20.), (10., 10.)]),
+            subpaths[0].0
+        );
+        assert_eq!(
+            coords(&[(15., 15.), (25., 15.), (25., 25.), (15., 15.)]),
+            subpaths[1].0
+        );
+
+        // Drawing straight after a ClosePath starts a new subpath at the same initial point, and
+        // a repeated ClosePath adds nothing
+        let subpaths = svg_d_path_subpaths_with_winding("M10 10L20 10L20 20Zl-10 10l10 0zZ");
+        assert!(subpaths.is_ok());
+        let subpaths = subpaths.ok().unwrap();
+        assert_eq!(2, subpaths.len());
+        assert_eq!(
+            coords(&[(10., 10.), (0., 20.), (10., 20.), (10., 10.)]),
+            subpaths[1].0
         );
-        let svg_string = String::from("M0 0l0 60l60 0L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10");
-        let parsed_svg = svg_d_path_to_geometry_collection(&svg_string);
-        assert_eq!(parsed_svg.is_ok(), true);
-        let geom = parsed_svg.ok().unwrap();
-        assert_eq!(1, geom.0.len());
-        let pl = geom.0[0].clone().into_polygon();
-        assert_eq!(true, pl.is_some());
-        assert_eq!(pl.unwrap(), poly);
     }
 
     #[test]
-    fn can_convert_svg_path_test() {
-        let poly: Polygon<f64> = polygon!(
-        exterior: [
-            (x: 0.0_f64, y: 0.0),
-            (x: 0.0, y: 60.0),
-            (x: 60.0, y: 60.0),
-            (x: 60.0, y: 0.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[[
-            (x: 10.0, y: 10.0),
-            (x: 40.0, y: 1.0),
-            (x: 40.0, y: 40.0),
-            (x: 10.50, y: 40.0),
-            (x: 10.0, y: 10.0),]
-            ]
-        )
-        .into();
-        let svg_string =
-            String::from(r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#);
-        let parsed_svg = svg_to_geometry_collection(&svg_string);
-        assert_eq!(parsed_svg.is_ok(), true);
-        let geom = parsed_svg.ok().unwrap();
-        assert_eq!(1, geom.0.len());
-        let pl = geom.0[0].clone().into_polygon();
-        assert_eq!(true, pl.is_some());
-        assert_eq!(poly, pl.unwrap());
+    fn can_convert_svg_path_with_smooth_curves_after_other_commands() {
+        let assert_same_geometry = |smooth: &str, explicit: &str| {
+            let smooth_geom = svg_d_path_to_geometry(smooth);
+            assert!(smooth_geom.is_ok());
+            let explicit_geom = svg_d_path_to_geometry(explicit);
+            assert!(explicit_geom.is_ok());
+            assert_eq!(explicit_geom.ok().unwrap(), smooth_geom.ok().unwrap());
+        };
+
+        // The control point of a curve before another command is not reflected
+        assert_same_geometry(
+            "M0 0C0 10 10 10 10 0L20 0S30 10 40 0",
+            "M0 0C0 10 10 10 10 0L20 0C20 0 30 10 40 0",
+        );
+        assert_same_geometry("M0 0Q5 10 10 0L20 0T40 0", "M0 0Q5 10 10 0L20 0Q20 0 40 0");
+        // Nor is that of a curve of the other family
+        assert_same_geometry(
+            "M0 0Q5 10 10 0S20 10 30 0",
+            "M0 0Q5 10 10 0C10 0 20 10 30 0",
+        );
+        assert_same_geometry(
+            "M0 0C0 10 10 10 10 0T30 0",
+            "M0 0C0 10 10 10 10 0Q10 0 30 0",
+        );
+        // A smooth curve following one of its family is reflected as before
+        assert_same_geometry(
+            "M0 0C0 10 10 10 10 0S20 -10 30 0T50 0",
+            "M0 0C0 10 10 10 10 0C10 -10 20 -10 30 0Q30 0 50 0",
+        );
+        assert_same_geometry("M0 0Q5 10 10 0t10 0", "M0 0Q5 10 10 0Q15 -10 20 0");
     }
 
     #[test]
-    fn can_convert_svg_h_v_path_test() {
-        let poly: Polygon<f64> = polygon!(
-        exterior: [
-            (x: 0.0_f64, y: 0.0),
-            (x: 0.0, y: 60.0),
-            (x: 60.0, y: 60.0),
-            (x: 60.0, y: 0.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[[
-            (x: 10.0, y: 10.0),
-            (x: 40.0, y: 1.0),
-            (x: 40.0, y: 40.0),
-            (x: 10.50, y: 40.0),
-            (x: 10.0, y: 10.0),]
-            ]
-        )
-        .into();
-        let svg_string =
-            String::from(r#"<path d="M0 0v60h60v-60h-60M10 10L40 1L40 40L10.5 40L10 10"/>"#);
-        let parsed_svg = svg_to_geometry_collection(&svg_string);
-        assert_eq!(parsed_svg.is_ok(), true);
-        let geom = parsed_svg.ok().unwrap();
-        assert_eq!(1, geom.0.len());
-        let pl = geom.0[0].clone().into_polygon();
-        assert_eq!(true, pl.is_some());
-        assert_eq!(poly, pl.unwrap());
+    fn can_convert_svg_path_closed_within_rounding_error() {
+        // 0.1 + 0.2 - 0.2 leaves the end a rounding error away from the start
+        let parsed_svg = svg_d_path_to_geometry("m0.1 0.1l0.2 0l0 0.2l-0.2 0l0 -0.2");
+        assert!(parsed_svg.is_ok());
+        let poly = parsed_svg.ok().unwrap().into_polygon();
+        assert!(poly.is_some());
+        let poly = poly.unwrap();
+        assert_eq!(5, poly.exterior().0.len());
+        assert_eq!(poly.exterior().0[0], poly.exterior().0[4]);
+
+        // Ends further apart are still a LineString
+        let parsed_svg = svg_d_path_to_geometry("M0 0L1 0L1 1L0 0.001");
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().into_line_string().is_some());
     }
 
     #[test]
-    fn can_convert_svg_c_s_path_test() {
-        let solution = String::from(
-            r#"<path d="M0 0L0.00895 0.89401L0.0356 1.7760799999999999L0.07964999999999998 2.6462699999999995L0.14079999999999998 3.5046399999999998L0.21875000000000003 4.35125L0.3132 5.186159999999999L0.42385000000000006 6.00943L0.5504 6.8211200000000005L0.6925499999999999 7.621289999999999L0.8500000000000001 8.41L1.02245 9.18731L1.2095999999999998 9.95328L1.4111500000000001 10.70797L1.6268000000000002 11.451440000000002L1.8562499999999997 12.18375L2.0992 12.90496L2.3553500000000005 13.61513L2.6244 14.314320000000002L2.90605 15.002590000000001L3.2 15.680000000000001L3.5059499999999995 16.34661L3.8236 17.002480000000002L4.15265 17.64767L4.492799999999999 18.282239999999998L4.84375 18.90625L5.2052000000000005 19.51976L5.576850000000001 20.122830000000004L5.9584 20.715519999999998L6.349549999999999 21.297889999999995L6.749999999999999 21.869999999999997L7.15945 22.43191L7.5776 22.98368L8.00415 23.525369999999995L8.4388 24.05704L8.88125 24.578750000000003L9.331199999999999 25.090559999999996L9.788350000000001 25.592530000000004L10.2524 26.084719999999997L10.723050000000002 26.567190000000004L11.200000000000001 27.04L11.68295 27.503210000000003L12.1716 27.956880000000005L12.66565 28.401070000000004L13.164800000000001 28.835840000000005L13.668750000000003 29.261250000000004L14.177200000000003 29.677360000000004L14.689849999999996 30.084229999999998L15.206399999999997 30.481919999999995L15.726550000000001 30.870490000000004L16.25 31.25L16.776449999999997 31.620509999999996L17.305600000000002 31.98208L17.83715 32.334770000000006L18.370800000000003 32.67864L18.906250000000004 33.013749999999995L19.443200000000004 33.34016L19.98135 33.65792999999999L20.520399999999995 33.967119999999994L21.060049999999997 34.26779L21.599999999999998 34.56L22.139950000000002 34.84381L22.6796 35.11928L23.21865 35.38647L23.756800000000002 35.64544L24.293750000000003 35.896249999999995L24.829200000000004 36.138960000000004L25.36285 36.373630000000006L25.8944 36.60032L26.42355 36.81909L26.95 37.03L27.47345 37.233109999999996L27.993599999999994 37.42847999999999L28.510149999999996 37.61617L29.022800000000007 37.796240000000004L29.53125 37.96875L30.035199999999996 38.13376L30.534350000000007 38.29133L31.028400000000005 38.441520000000004L31.517049999999998 38.58439L32 38.72L32.47695 38.84841L32.947599999999994 38.969680000000004L33.41164999999999 39.08387L33.8688 39.19104L34.31875 39.29125L34.7612 39.38456L35.19584999999999 39.47103L35.622400000000006 39.550720000000005L36.040549999999996 39.623689999999996L36.449999999999996 39.69L36.850449999999995 39.74971L37.241600000000005 39.80288L37.62315000000001 39.84957L37.99479999999999 39.88984L38.356249999999996 39.923750000000005L38.70719999999999 39.95136L39.04734999999999 39.97273L39.376400000000004 39.98792L39.69405 39.99699L40 40L40.29702 40.00596L40.588159999999995 40.023680000000006L40.87353999999999 40.052919999999986L41.153279999999995 40.093439999999994L41.427499999999995 40.14499999999999L41.696319999999986 40.207359999999994L41.95985999999999 40.28027999999999L42.21824000000001 40.363520000000015L42.47158 40.45684L42.72 40.56000000000001L42.963620000000006 40.672760000000004L43.20256 40.79488L43.43693999999999 40.92611999999999L43.66688 41.06624L43.8925 41.215L44.11392 41.37216L44.33126 41.537479999999995L44.54464000000001 41.71072L44.754180000000005 41.89164000000001L44.96 42.08L45.162220000000005 42.275560000000006L45.360960000000006 42.478080000000006L45.55634 42.68732L45.74848 42.90304L45.9375 43.125L46.12352 43.35296L46.30666 43.58668L46.48704 43.825919999999996L46.66477999999999 44.07043999999999L46.83999999999999 44.31999999999999L47.01281999999999 44.57436L47.18335999999999 44.83327999999999L47.35173999999999 45.09651999999999L47.51807999999999 45.363839999999996L47.682500000000005 45.635L47.845119999999994 45.909760000000006L48.006060000000005 46.18788L48.165440000000004 46.469120000000004L48.32338 46.753240000000005L48.480000000000004 47.040000000000006L48.63542000000001 47.32916L48.78976000000001 47.62048000000001L48.94314000000001 47.91372000000001L49.09568000000001 48.20864L49.2475 48.505L49.398720000000004 48.80256000000001L49.549459999999996 49.10108000000001L49.69984 49.400319999999994L49.84998 49.70004L50 50L50.15002 50.29996L50.300160000000005 50.59968L50.45054 50.89892L50.60128 51.19744L50.7525 51.495000000000005L50.90432 51.791360000000005L51.05686 52.08628L51.21024 52.37951999999999L51.364580000000004 52.67084L51.519999999999996 52.96L51.67662 53.24676L51.834559999999996 53.53088L51.993939999999995 53.81211999999999L52.15488 54.09024L52.3175 54.364999999999995L52.48192 54.636160000000004L52.64826000000001 54.90348L52.81664000000001 55.16672L52.98718000000001 55.42564L53.160000000000004 55.68000000000001L53.33521999999999 55.929559999999995L53.51295999999999 56.17408L53.69334 56.41332L53.87648 56.64704L54.0625 56.875L54.25152 57.09696L54.44366 57.31268000000001L54.63904000000001 57.52192L54.83778000000001 57.72444L55.04 57.92L55.245819999999995 58.10836L55.45536 58.28928L55.66873999999999 58.46252L55.88608 58.62783999999999L56.1075 58.785L56.33312 58.93376000000001L56.56306000000001 59.07388000000001L56.79744 59.20512L57.036379999999994 59.327239999999996L57.28 59.44L57.528420000000004 59.54316L57.781760000000006 59.63648L58.04014000000001 59.719719999999995L58.30368 59.792640000000006L58.5725 59.855000000000004L58.84672 59.90655999999999L59.12645999999999 59.94708L59.41184 59.976319999999994L59.70298 59.99404L60 60L60 0L0 0M10 10L20 10L20 20L10 20L10 10"/>"#,
-        );
-        let svg_string = String::from(
-            r#"<path d="M0 0C0 30 30 40 40 40S50 60 60 60L60 0ZM10 10L20 10L20 20L10 20L10 10" />"#,
-        );
-        let parsed_svg = svg_to_geometry_collection(&svg_string);
-        assert_eq!(true, parsed_svg.is_ok());
-        let svg = parsed_svg.ok().unwrap().to_svg();
-        assert_eq!(solution, svg);
+    fn can_convert_long_relative_svg_path_without_drift() {
+        // Each delta is a multiple of 1/3 or 1/7, so the absolute coordinates can be computed from
+        // integer sums instead of by repeating the additions the reader makes
+        let (mut relative, mut absolute) = (String::from("M0.5 0.25"), String::from("M0.5 0.25"));
+        let (mut thirds, mut sevenths) = (0_i64, 0_i64);
+        for i in 0..3000_i64 {
+            let (dx, dy) = ((i * 7) % 13 - 6, (i * 5) % 11 - 5);
+            match i % 3 {
+                0 => {
+                    relative.push_str(&format!("l{} {}", dx as f64 / 3., dy as f64 / 7.));
+                    thirds += dx;
+                    sevenths += dy;
+                }
+                1 => {
+                    relative.push_str(&format!("h{}", dx as f64 / 3.));
+                    thirds += dx;
+                }
+                _ => {
+                    relative.push_str(&format!("v{}", dy as f64 / 7.));
+                    sevenths += dy;
+                }
+            }
+            absolute.push_str(&format!(
+                "L{} {}",
+                0.5 + thirds as f64 / 3.,
+                0.25 + sevenths as f64 / 7.
+            ));
+        }
+
+        let relative = svg_d_path_to_geometry_collection(&relative);
+        let absolute = svg_d_path_to_geometry_collection(&absolute);
+        assert!(relative.is_ok() && absolute.is_ok());
+        let (relative, absolute) = (relative.ok().unwrap(), absolute.ok().unwrap());
+        assert_eq!(1, relative.0.len());
+        let relative = relative.0[0].clone().into_line_string().unwrap();
+        let absolute = absolute.0[0].clone().into_line_string().unwrap();
+        assert_eq!(3001, relative.0.len());
+        assert_eq!(absolute.0.len(), relative.0.len());
+        for (r, a) in relative.0.iter().zip(absolute.0.iter()) {
+            assert!((r.x - a.x).abs() < 1e-9 && (r.y - a.y).abs() < 1e-9);
+        }
     }
 
     #[test]
-    fn can_convert_svg_q_t_path_test() {
-        let solution = String::from(
-            r#"<path d="M0 0L0.598 0.796L1.192 1.584L1.7819999999999998 2.364L2.368 3.136L2.95 3.9L3.5279999999999996 4.655999999999999L4.102 5.404L4.672000000000001 6.144000000000001L5.2379999999999995 6.8759999999999994L5.800000000000001 7.6L6.3580000000000005 8.316L6.911999999999999 9.024000000000001L7.462 9.724L8.008000000000001 10.416L8.549999999999999 11.1L9.088000000000001 11.776L9.622 12.444L10 12.914716981132074L10 10L20 10L20 20L15.858156028368795 20L16.2 20.4L16.678 20.956L17.152 21.503999999999998L17.622 22.044L18.088 22.576L18.55 23.1L19.007999999999996 23.616L19.462 24.124000000000002L19.912 24.624L20.358000000000004 25.116L20.8 25.6L21.238 26.076L21.672 26.544000000000004L22.102 27.003999999999998L22.528000000000002 27.456000000000003L22.950000000000003 27.9L23.368000000000006 28.336000000000006L23.781999999999996 28.763999999999996L24.191999999999997 29.183999999999997L24.598000000000003 29.596000000000004L25 30L25.397999999999996 30.395999999999997L25.792 30.784L26.182000000000002 31.164L26.568 31.536L26.950000000000003 31.9L27.328000000000003 32.256L27.701999999999998 32.604L28.071999999999996 32.944L28.438 33.275999999999996L28.799999999999997 33.6L29.158 33.916L29.512000000000004 34.224000000000004L29.862 34.524L30.208 34.816L30.55 35.1L30.888 35.376000000000005L31.222 35.644L31.552 35.904L31.878 36.156L32.2 36.400000000000006L32.518 36.635999999999996L32.831999999999994 36.864L33.141999999999996 37.084L33.44800000000001 37.296L33.75 37.5L34.047999999999995 37.696L34.342000000000006 37.884L34.632000000000005 38.064L34.918 38.236000000000004L35.2 38.4L35.478 38.556000000000004L35.751999999999995 38.704L36.02199999999999 38.843999999999994L36.288000000000004 38.976L36.550000000000004 39.1L36.808 39.216L37.062 39.324L37.312000000000005 39.42400000000001L37.558 39.516L37.8 39.6L38.038 39.675999999999995L38.272000000000006 39.744L38.502 39.804L38.727999999999994 39.855999999999995L38.95 39.9L39.168 39.936L39.38199999999999 39.964L39.592000000000006 39.984L39.797999999999995 39.996L40 40L40.199999999999996 40.002L40.4 40.008L40.599999999999994 40.017999999999994L40.8 40.032L41 40.05L41.199999999999996 40.071999999999996L41.39999999999999 40.09799999999999L41.60000000000001 40.128000000000014L41.8 40.162L42 40.2L42.2 40.242000000000004L42.4 40.288000000000004L42.599999999999994 40.337999999999994L42.8 40.391999999999996L43 40.45L43.2 40.512L43.4 40.577999999999996L43.6 40.648L43.80000000000001 40.72200000000001L44 40.8L44.2 40.882000000000005L44.400000000000006 40.968L44.599999999999994 41.058L44.8 41.152L45 41.25L45.2 41.352000000000004L45.400000000000006 41.458L45.599999999999994 41.568L45.8 41.681999999999995L46 41.8L46.199999999999996 41.922L46.39999999999999 42.047999999999995L46.599999999999994 42.178L46.8 42.312L47 42.45L47.199999999999996 42.592L47.400000000000006 42.738L47.599999999999994 42.888000000000005L47.800000000000004 43.042L48 43.2L48.2 43.362L48.400000000000006 43.528000000000006L48.60000000000001 43.69800000000001L48.80000000000001 43.872L49 44.05L49.2 44.232L49.400000000000006 44.418000000000006L49.599999999999994 44.608L49.8 44.80199999999999L50 45L50.2 45.202L50.400000000000006 45.408L50.599999999999994 45.617999999999995L50.8 45.83200000000001L51 46.05L51.199999999999996 46.272000000000006L51.400000000000006 46.498000000000005L51.599999999999994 46.727999999999994L51.8 46.962L52 47.2L52.2 47.44200000000001L52.400000000000006 47.688L52.6 47.938L52.8 48.192L53 48.45L53.199999999999996 48.712L53.400000000000006 48.97800000000001L53.6 49.248L53.80000000000001 49.52199999999999L54 49.8L54.2 50.081999999999994L54.4 50.368L54.599999999999994 50.658L54.8 50.952L55 51.25L55.2 51.55200000000001L55.400000000000006 51.858000000000004L55.6 52.168L55.800000000000004 52.482000000000006L56 52.800000000000004L56.199999999999996 53.122L56.4 53.448L56.599999999999994 53.778000000000006L56.8 54.111999999999995L57 54.449999999999996L57.2 54.792L57.400000000000006 55.138000000000005L57.6 55.48799999999999L57.8 55.842L58 56.2L58.2 56.562000000000005L58.400000000000006 56.928L58.60000000000001 57.298L58.800000000000004 57.672L59 58.05L59.199999999999996 58.431999999999995L59.39999999999999 58.818L59.6 59.208L59.8 59.602L60 60L60 0L0 0"/>
-<path d="M10 12.914716981132074L10 20L15.858156028368795 20L15.717999999999998 19.836L15.232 19.264000000000003L14.742 18.684L14.248000000000001 18.096L13.75 17.5L13.248 16.896L12.742 16.284000000000002L12.232 15.664000000000001L11.718 15.036000000000001L11.200000000000001 14.4L10.678 13.756L10.152000000000001 13.104L10 12.914716981132074"/>"#,
+    fn can_convert_svg_points_without_separators() {
+        let parsed_svg = svg_to_geometry(r#"<polyline points="10-5 20-5"/>"#);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Geometry::LineString(line_string![(x: 10.0, y: -5.0), (x: 20.0, y: -5.0)]),
+            parsed_svg.ok().unwrap()
         );
-        let svg_string = String::from(
-            r#"<path d="M0 0Q30 40 40 40T60 60L60 0ZM10 10L20 10L20 20L10 20L10 10" />"#,
+
+        let parsed_svg = svg_to_geometry(r#"<polygon points=" 0,0 10-5,20-5 "/>"#);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Geometry::Polygon(polygon![(x: 0.0, y: 0.0), (x: 10.0, y: -5.0), (x: 20.0, y: -5.0)]),
+            parsed_svg.ok().unwrap()
         );
-        let parsed_svg = svg_to_geometry_collection(&svg_string);
-        assert_eq!(true, parsed_svg.is_ok());
-        let svg = parsed_svg.ok().unwrap().to_svg();
-        assert_eq!(solution, svg);
     }
 
     #[test]
-    fn can_convert_svg_polygon_test() {
-        let poly: Polygon<f64> = polygon!(
-        exterior: [
-            (x: 0.0_f64, y: 0.0),
-            (x: 0.0, y: 60.0),
-            (x: 60.0, y: 60.0),
-            (x: 60.0, y: 0.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[]
-        )
-        .into();
-        let svg_string = String::from(r#"<polygon points="0, 0 60, 0 60, 60 0, 60 0, 0"/>"#);
-        let parsed_svg = svg_to_geometry_collection(&svg_string);
-        assert_eq!(parsed_svg.is_ok(), true);
-        let geom = parsed_svg.ok().unwrap();
-        assert_eq!(1, geom.0.len());
-        let pl = geom.0[0].clone().into_polygon();
-        assert_eq!(true, pl.is_some());
-        assert_eq!(poly, pl.unwrap());
+    fn can_convert_svg_points_with_any_whitespace() {
+        let expected = line_string![(x: 0.0_f64, y: 0.0), (x: 10.0, y: 5.0), (x: 20.0, y: -2.5)];
+        for points in [
+            "0\t0\t10\t5\t20\t-2.5",
+            "0,0\n10,5\n20,-2.5\n",
+            "\r\n  0 ,\t0\r\n10\n,\n5 20-2.5",
+        ] {
+            let parsed_svg = svg_to_geometry(&format!(r#"<polyline points="{}"/>"#, points));
+            assert!(parsed_svg.is_ok());
+            assert_eq!(
+                Geometry::LineString(expected.clone()),
+                parsed_svg.ok().unwrap()
+            );
+
+            let parsed_svg = svg_to_geometry(&format!(r#"<polygon points="{}"/>"#, points));
+            assert!(parsed_svg.is_ok());
+            assert_eq!(
+                Geometry::Polygon(Polygon::new(expected.clone(), vec![])),
+                parsed_svg.ok().unwrap()
+            );
+        }
     }
 
     #[test]
-    fn can_convert_svg_polyline_test() {
-        let line: LineString<f64> = line_string![
-            (x: 0.0_f64, y: 0.0),
-            (x: 0.0, y: 60.0),
-            (x: 60.0, y: 60.0),
-            (x: 60.0, y: 0.0),]
-        .into();
-        let svg_string = String::from(r#"<polyline points="0, 0 0, 60 60, 60 60, 0"/>"#);
-        let parsed_svg = svg_to_geometry_collection(&svg_string);
-        assert_eq!(parsed_svg.is_ok(), true);
-        let geom = parsed_svg.ok().unwrap();
-        assert_eq!(1, geom.0.len());
-        let pl = geom.0[0].clone().into_line_string();
-        assert_eq!(true, pl.is_some());
-        assert_eq!(line, pl.unwrap());
+    fn can_split_coordinate_pairs() {
+        let pairs = coordinate_pairs("1,2 3 4\t5,\n6");
+        assert!(pairs.is_ok());
+        assert_eq!(vec![(1., 2.), (3., 4.), (5., 6.)], pairs.ok().unwrap());
+
+        let pairs = coordinate_pairs("+1e2-.5 .5.25 -0. 1E-1");
+        assert!(pairs.is_ok());
+        assert_eq!(
+            vec![(100., -0.5), (0.5, 0.25), (-0., 0.1)],
+            pairs.ok().unwrap()
+        );
+
+        for (points, reason) in [
+            ("1,2 3", "unpaired coordinate in"),
+            ("1\t2\n3\t", "unpaired coordinate in"),
+            ("1,2 3,4,", "unpaired coordinate in"),
+            (",1,2", "invalid"),
+            ("1,,2", "invalid"),
+            ("1 . 2", "invalid"),
+            ("1 2 - 3", "invalid"),
+            ("1;2", "invalid"),
+        ] {
+            match coordinate_pairs(points) {
+                Err(SvgError::InvalidSvgError(error)) => assert_eq!(
+                    format!(r#"{} attribute "points""#, reason),
+                    error.to_string()
+                ),
+                _ => panic!("expected an InvalidSvgError for {:?}", points),
+            }
+        }
     }
 
     #[test]
-    fn can_convert_svg_rect_test() {
-        let poly: Polygon<f64> = polygon!(
-        exterior: [
-            (x: 0.0_f64, y: 0.0),
-            (x: 0.0, y: 60.0),
-            (x: 60.0, y: 60.0),
-            (x: 60.0, y: 0.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[]
-        )
-        .into();
-        let svg_string = String::from(r#"<rect x="0" y="0" width="60" height="60"/>"#);
-        let parsed_svg = svg_to_geometry_collection(&svg_string);
-        assert_eq!(parsed_svg.is_ok(), true);
-        let geom = parsed_svg.ok().unwrap();
-        assert_eq!(1, geom.0.len());
-        let pl = geom.0[0].clone().into_polygon();
-        assert_eq!(true, pl.is_some());
-        assert_eq!(poly, pl.unwrap());
+    fn cannot_convert_invalid_svg_points() {
+        for points in &["0,0 10,10,", "0 0 10", "0,0 10,,10", "0,0 10 x"] {
+            let polyline = format!(r#"<polyline points="{}"/>"#, points);
+            match svg_to_geometry(&polyline) {
+                Err(SvgError::InvalidSvgError(_)) => (),
+                _ => panic!("expected an InvalidSvgError for {}", points),
+            }
+            let polygon = format!(r#"<polygon points="{}"/>"#, points);
+            match svg_to_geometry(&polygon) {
+                Err(SvgError::InvalidSvgError(_)) => (),
+                _ => panic!("expected an InvalidSvgError for {}", points),
+            }
+        }
     }
 
     #[test]
-    fn can_convert_svg_path_to_single_geom() {
-        let poly: Polygon<f64> = polygon!(
+    fn can_convert_svg_to_f32_coordinates() {
+        let svg = r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#;
+        let parsed_svg = svg_to_geometry_collection_as::<f32>(svg);
+        assert!(parsed_svg.is_ok());
+        let poly = polygon!(
             exterior: [
-                (x: 0.0_f64, y: 0.0),
+                (x: 0.0_f32, y: 0.0),
                 (x: 0.0, y: 60.0),
                 (x: 60.0, y: 60.0),
                 (x: 60.0, y: 0.0),
                 (x: 0.0, y: 0.0),],
-            interiors:[[
+            interiors: [[
                 (x: 10.0, y: 10.0),
                 (x: 40.0, y: 1.0),
                 (x: 40.0, y: 40.0),
-                (x: 10.50, y: 40.0),
-                (x: 10.0, y: 10.0),]
-                ]
-            );
-        let svg_string =
-            String::from(r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#);
+                (x: 10.5, y: 40.0),
+                (x: 10.0, y: 10.0),]]
+        );
+        assert_eq!(Geometry::Polygon(poly), parsed_svg.ok().unwrap().0[0]);
 
-        let parsed_svg = svg_to_geometry(&svg_string);
+        // Curves are flattened exactly as for f64
+        let d_path = "M0 0C10 20 30 20 40 0Q50 -20 60 0";
+        let parsed_f32 = svg_d_path_to_geometry_as::<f32>(d_path).ok().unwrap();
+        let parsed_f64 = svg_d_path_to_geometry(d_path).ok().unwrap();
+        let as_f32 = parsed_f64
+            .into_line_string()
+            .unwrap()
+            .0
+            .iter()
+            .map(|c| (c.x as f32, c.y as f32))
+            .collect::<LineString<f32>>();
+        assert_eq!(Geometry::LineString(as_f32), parsed_f32);
+
+        let document =
+            r#"<svg><g transform="translate(1 2)"><circle cx="0" cy="0" r="1"/></g></svg>"#;
+        let parsed_document = svg_document_to_geometry_collection_as::<f32>(document);
+        assert!(parsed_document.is_ok());
+        assert_eq!(1, parsed_document.ok().unwrap().0.len());
+    }
+
+    #[test]
+    fn cannot_convert_svg_overflowing_f32_coordinates() {
+        let parsed_svg = svg_to_geometry_as::<f32>(r#"<line x1="0" y1="0" x2="1e300" y2="1"/>"#);
+        match parsed_svg {
+            Err(SvgError::CoordinateOverflow { value }) => assert_eq!(1e300, value),
+            _ => panic!("expected a CoordinateOverflow error"),
+        }
+        assert!(svg_to_geometry_as::<f64>(r#"<line x1="0" y1="0" x2="1e300" y2="1"/>"#).is_ok());
+
+        let options = ParseOptions {
+            prefer_rect: true,
+            ..Default::default()
+        };
+        let parsed_svg =
+            svg_d_path_to_geometry_collection_with_options_as::<f32>("M0 0H1e39V1Z", &options);
+        assert!(matches!(
+            parsed_svg,
+            Err(SvgError::CoordinateOverflow { .. })
+        ));
+        let parsed_svg = svg_to_geometry_collection_with_options_as::<f32>(
+            r#"<path d="M0 0H60V30H0Z"/>"#,
+            &options,
+        );
         assert!(parsed_svg.is_ok());
-        let parsed_poly = parsed_svg.ok().unwrap().into_polygon();
-        assert!(parsed_poly.is_some());
-        assert_eq!(poly, parsed_poly.unwrap());
+        assert!(matches!(parsed_svg.ok().unwrap().0[0], Geometry::Rect(_)));
     }
 
     #[test]
-    fn can_convert_svg_polygon_to_single_geom() {
-        let poly: Polygon<f64> = polygon!(
-            exterior: [
-                (x: 0.0_f64, y: 0.0),
-                (x: 0.0, y: 60.0),
-                (x: 60.0, y: 60.0),
-                (x: 60.0, y: 0.0),
-                (x: 0.0, y: 0.0),],
-            interiors:[]
-            );
-        let svg_string = String::from(r#"<polygon points="0, 0 60, 0 60, 60 0, 60 0, 0"/>"#);
+    fn can_round_trip_document_units() {
+        let svg_string = String::from(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="210mm" height="297mm" viewBox="0 0 2100 2970"><rect x="100" y="100" width="500" height="200"/></svg>"#,
+        );
+        let dimensions = svg_document_dimensions(&svg_string);
+        assert!(dimensions.is_ok());
+        let dimensions = dimensions.ok().unwrap();
+        assert_eq!(Some(SvgUnit::Mm), dimensions.unit);
+        assert!(dimensions.view_box.is_some());
 
-        let parsed_svg = svg_to_geometry(&svg_string);
+        let parsed_svg = svg_to_geometry_collection(&svg_string);
         assert!(parsed_svg.is_ok());
-        let parsed_poly = parsed_svg.ok().unwrap().into_polygon();
-        assert!(parsed_poly.is_some());
-        assert_eq!(poly, parsed_poly.unwrap());
+        let written = svg_document(
+            &parsed_svg.ok().unwrap(),
+            &dimensions.view_box.unwrap(),
+            dimensions.size(),
+        );
+        assert!(written.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="210mm" height="297mm" viewBox="0 0 2100 2970">"#
+        ));
+
+        let reparsed = svg_document_dimensions(&written);
+        assert!(reparsed.is_ok());
+        assert_eq!(dimensions, reparsed.ok().unwrap());
+    }
+
+    #[test]
+    fn document_dimensions_require_svg_element() {
+        assert!(svg_document_dimensions(r#"<rect x="0" y="0" width="60" height="60"/>"#).is_err());
+    }
+
+    #[test]
+    fn can_convert_empty_svg_document() {
+        let parsed_svg = svg_document_to_geometry_collection("<svg></svg>");
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0.is_empty());
+
+        let parsed_svg =
+            svg_document_to_geometry_collection(r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#);
+        assert!(parsed_svg.is_ok());
+        assert!(parsed_svg.ok().unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn cannot_convert_non_svg_document() {
+        assert!(svg_document_to_geometry_collection("<html><body></body></html>").is_err());
+        assert!(svg_document_to_geometry_collection("not xml at all").is_err());
+        assert!(svg_document_to_geometry_collection("").is_err());
+        assert!(svg_document_to_geometry_collection("<svg><rect").is_err());
+    }
+
+    #[test]
+    fn can_normalize_via_svg() {
+        // A bowtie, whose edges cross at (5, 5)
+        let bowtie = Geometry::Polygon(polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 10.0, y: 0.0),
+            (x: 0.0, y: 10.0),
+        ]);
+        let normalized = normalize_via_svg(&bowtie);
+        assert!(normalized.is_ok());
+        let normalized = normalized.ok().unwrap();
+        assert_eq!(bowtie, normalized);
+        assert_eq!(normalized, normalize_via_svg(&bowtie).ok().unwrap());
+        assert_eq!(normalized, normalize_via_svg(&normalized).ok().unwrap());
+
+        let gc = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::LineString(line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 5.0)]),
+            bowtie.clone(),
+        ]));
+        let normalized = normalize_via_svg(&gc);
+        assert!(normalized.is_ok());
+        let normalized = normalized.ok().unwrap();
+        // A LineString of two points is read back as a Line
+        let expected = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Line(Line::new(
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 5.0, y: 5.0 },
+            )),
+            bowtie,
+        ]));
+        assert_eq!(expected, normalized);
+        assert_eq!(normalized, normalize_via_svg(&normalized).ok().unwrap());
+
+        // Points are kept as they are
+        for geom in [
+            Geometry::Point(Point::new(1.0, 2.0)),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)])),
+        ] {
+            let normalized = normalize_via_svg(&geom);
+            assert!(normalized.is_ok());
+            assert_eq!(geom, normalized.ok().unwrap());
+        }
+
+        // A Polygon inside another member of a collection does not become its hole
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Polygon(polygon![
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 10.0),
+                (x: 0.0, y: 10.0),
+            ]),
+            Geometry::Polygon(polygon![
+                (x: 2.0, y: 2.0),
+                (x: 8.0, y: 2.0),
+                (x: 8.0, y: 8.0),
+                (x: 2.0, y: 8.0),
+            ]),
+            Geometry::Point(Point::new(5.0, 5.0)),
+        ]));
+        let normalized = normalize_via_svg(&nested);
+        assert!(normalized.is_ok());
+        assert_eq!(nested, normalized.ok().unwrap());
     }
 }
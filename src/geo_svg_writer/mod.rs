@@ -1,12 +1,19 @@
 extern crate geo_types;
 
+use crate::affine::Affine2;
+pub use crate::attributes::FillRule;
+use crate::attributes::{SvgSize, SvgUnit};
 use geo_types::{
-    CoordNum, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
-    MultiPolygon, Polygon, Rect, Triangle,
+    CoordFloat, CoordNum, Coordinate, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use std::cmp::Ordering;
 use std::fmt;
+use std::fmt::Write;
+use std::marker::PhantomData;
+use std::sync::Arc;
 
-pub trait ToSvg {
+pub trait ToSvg<T: CoordNum> {
     /// Return the Geometry as an SVG element (**Note** this does not return a full SVG)
     ///
     /// This function produces an SVG element of the simplest type possible:
@@ -16,12 +23,15 @@ pub trait ToSvg {
     /// * Line &rarr; \<line\>
     /// * Triangle &rarr; \<polygon\> with three points
     /// * Rect &rarr; \<rect\> with `x`, `y`, `width`, and `height`
+    /// * Point &rarr; \<circle\> with a radius of 1
     ///
     /// Complex Geometry types will return multiple SVG elements separated by `newline`s:
     ///
     /// * GeometryCollection &rarr; `newline` separated SVG elements corresponding to the individual Geometries it contains
+    /// * GeometryCollection nested in a GeometryCollection &rarr; \<g\> wrapping the elements of its Geometries
     /// * MultiPolygon &rarr; `newline` separated <path> elements
     /// * MultiLineString &rarr; `newline` separated <polyline> elements
+    /// * MultiPoint &rarr; `newline` separated <circle> elements
     ///
     /// # Examples
     ///
@@ -64,12 +74,166 @@ pub trait ToSvg {
     /// assert_eq!(wkt_out, expected);
     /// ```
     ///
-    fn to_svg(&self) -> String;
+    fn to_svg(&self) -> String {
+        self.to_svg_with_options(&SvgWriteOptions::default())
+    }
+
+    /// Return the Geometry as an SVG element (**Note** this does not return a full SVG) rendered
+    /// according to the supplied `SvgWriteOptions`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::Point;
+    /// use geo_svg_io::geo_svg_writer::{MarkerShape, SvgWriteOptions, ToSvg};
+    ///
+    /// let mut options = SvgWriteOptions::default();
+    /// options.marker_shape = MarkerShape::Square;
+    /// options.point_radius = 2.0;
+    ///
+    /// let svg = Point::new(10.0, 10.0).to_svg_with_options(&options);
+    /// assert_eq!(svg, r#"<rect x="8" y="8" width="4" height="4"/>"#);
+    /// ```
+    ///
+    fn to_svg_with_options(&self, options: &SvgWriteOptions<T>) -> String {
+        let mut svg = String::new();
+        // Writing to a String cannot fail
+        self.write_svg_with_options(&mut svg, options).unwrap();
+        svg
+    }
+
+    /// Return the Geometry as an SVG element (**Note** this does not return a full SVG) with every
+    /// number rounded to at most `decimals` decimals, which keeps the output small
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::line_string;
+    /// use geo_svg_io::geo_svg_writer::ToSvg;
+    ///
+    /// let line = line_string![(x: 0.00895, y: 59.99404), (x: 10.0, y: 1.0 / 3.0)];
+    /// assert_eq!(
+    ///     line.to_svg_with_precision(3),
+    ///     r#"<polyline points="0.009,59.994 10,0.333"/>"#
+    /// );
+    /// ```
+    ///
+    fn to_svg_with_precision(&self, decimals: usize) -> String {
+        self.to_svg_with_options(&SvgWriteOptions {
+            decimals: Some(decimals),
+            ..Default::default()
+        })
+    }
+
+    /// Return the Geometry as SVG elements (**Note** this does not return a full SVG) carrying
+    /// the presentation attributes of `style`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::line_string;
+    /// use geo_svg_io::geo_svg_writer::{SvgStyle, ToSvg};
+    ///
+    /// let mut style = SvgStyle::default();
+    /// style.fill = Some("none".into());
+    /// style.stroke = Some("#1f77b4".into());
+    /// style.stroke_width = Some(0.5);
+    ///
+    /// let line = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 5.0)];
+    /// assert_eq!(
+    ///     line.to_svg_styled(&style),
+    ///     r##"<polyline points="0,0 10,5" fill="none" stroke="#1f77b4" stroke-width="0.5"/>"##
+    /// );
+    /// ```
+    ///
+    fn to_svg_styled(&self, style: &SvgStyle) -> String {
+        self.to_svg_with_options(&SvgWriteOptions {
+            style: style.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Write the Geometry as SVG elements (**Note** this does not write a full SVG) into `w`,
+    /// producing the same output as `to_svg` without building intermediate `String`s
+    ///
+    /// This is the cheaper choice for large geometries, e.g., streaming thousands of polygons into
+    /// a buffer that is reused or flushed to a file. Only a `std::fmt::Write` target is supported,
+    /// such as a `String` or a `fmt::Formatter`. To write into a `std::io::Write` such as a `File`,
+    /// format `svg_display` with `write!`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{line_string, polygon, Geometry, GeometryCollection};
+    /// use geo_svg_io::geo_svg_writer::ToSvg;
+    ///
+    /// let gc = GeometryCollection(vec![
+    ///     Geometry::LineString(line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 5.0)]),
+    ///     Geometry::Polygon(polygon![(x: 1.0, y: 1.0), (x: 4.0, y: 1.0), (x: 4.0, y: 4.0)]),
+    /// ]);
+    ///
+    /// let mut svg = String::from("<svg xmlns=\"http://www.w3.org/2000/svg\">\n");
+    /// gc.write_svg(&mut svg).unwrap();
+    /// svg.push_str("\n</svg>");
+    ///
+    /// assert_eq!(
+    ///     svg,
+    ///     r#"<svg xmlns="http://www.w3.org/2000/svg">
+    /// <polyline points="0,0 5,5"/>
+    /// <path d="M1 1L4 1L4 4L1 1"/>
+    /// </svg>"#
+    /// );
+    /// ```
+    ///
+    fn write_svg(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.write_svg_with_options(w, &SvgWriteOptions::default())
+    }
+
+    /// Return a wrapper that implements `Display` by writing the Geometry as SVG elements
+    /// (**Note** this does not write a full SVG), so it can be used with `format!`, `write!`, and
+    /// `println!` without an intermediate `String`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::line_string;
+    /// use geo_svg_io::geo_svg_writer::ToSvg;
+    ///
+    /// let line = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 5.0)];
+    /// assert_eq!(
+    ///     format!("<svg>{}</svg>", line.svg_display()),
+    ///     r#"<svg><polyline points="0,0 10,5"/></svg>"#
+    /// );
+    /// ```
+    ///
+    fn svg_display(&self) -> SvgDisplay<'_, T, Self>
+    where
+        Self: Sized,
+    {
+        SvgDisplay {
+            geometry: self,
+            coord_type: PhantomData,
+        }
+    }
+
+    /// Write the Geometry as SVG elements (**Note** this does not write a full SVG) into `w`,
+    /// rendered according to the supplied `SvgWriteOptions`
+    ///
+    /// Every other method of this trait is expressed through this one, so it is the only method
+    /// an implementation needs to provide.
+    ///
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result;
 }
 
 pub trait ToSvgString {
     /// Returns a valid SVG `d`-string for the points in the Geometry, which can be used in an SVG `<path>` element
     ///
+    /// A Point has no extent, so it is written as a lone move (`M1 2`).
+    ///
     /// # Examples
     /// ```rust
     /// use geo_types::{polygon, MultiPolygon};
@@ -109,554 +273,3821 @@ pub trait ToSvgString {
     fn to_svg_string(&self) -> String;
 }
 
-/** Geometries */
-
-impl<T: CoordNum + fmt::Display> ToSvg for GeometryCollection<T> {
-    fn to_svg(&self) -> String {
-        if self.is_empty() {
-            "".into()
-        } else {
-            self.0
-                .iter()
-                .map(|p| p.to_svg())
-                .collect::<Vec<String>>()
-                .join("\n")
-        }
-    }
+pub trait ToSvgStringWithOptions<T: CoordNum> {
+    /// Returns the SVG `d`-string of the Geometry, as `ToSvgString` does, with its numbers and
+    /// commands written according to the supplied `SvgWriteOptions`
+    ///
+    /// The options affecting path data apply, e.g., `decimals`, `relative_commands`,
+    /// `axis_aligned_commands` or `close_rings`, while those of elements, such as `style`, do
+    /// not. `to_svg_string` is the same as this with the default options.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use geo_types::line_string;
+    /// use geo_svg_io::geo_svg_writer::{SvgWriteOptions, ToSvgStringWithOptions};
+    ///
+    /// let line = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 5.26)];
+    /// let options = SvgWriteOptions {
+    ///     relative_commands: true,
+    ///     axis_aligned_commands: true,
+    ///     decimals: Some(1),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!("M0 0h10v5.3", line.to_svg_string_with_options(&options));
+    /// ```
+    ///
+    fn to_svg_string_with_options(&self, options: &SvgWriteOptions<T>) -> String;
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for GeometryCollection<T> {
-    fn to_svg_string(&self) -> String {
-        if self.is_empty() {
-            "".into()
-        } else {
-            self.0
-                .iter()
-                .map(|p| p.to_svg_string())
-                .collect::<Vec<String>>()
-                .join("")
+// Implements ToSvgString and ToSvgStringWithOptions through a function writing the path data of
+// the Geometry
+macro_rules! impl_to_svg_string {
+    ($geometry:ident, $to_svg_path:ident) => {
+        impl<T: CoordNum + fmt::Display> ToSvgStringWithOptions<T> for $geometry<T> {
+            fn to_svg_string_with_options(&self, options: &SvgWriteOptions<T>) -> String {
+                let mut svg = String::new();
+                // Writing to a String cannot fail
+                $to_svg_path(&mut svg, self, options).unwrap();
+                svg
+            }
         }
-    }
-}
 
-impl<T: CoordNum + fmt::Display> ToSvg for Geometry<T> {
-    fn to_svg(&self) -> String {
-        match self {
-            Geometry::MultiPolygon { .. } => self.clone().into_multi_polygon().unwrap().to_svg(),
-            Geometry::Polygon { .. } => self.clone().into_polygon().unwrap().to_svg(),
-            Geometry::MultiLineString { .. } => {
-                self.clone().into_multi_line_string().unwrap().to_svg()
+        impl<T: CoordNum + fmt::Display> ToSvgString for $geometry<T> {
+            fn to_svg_string(&self) -> String {
+                self.to_svg_string_with_options(&SvgWriteOptions::default())
             }
-            Geometry::LineString { .. } => self.clone().into_line_string().unwrap().to_svg(),
-            _ => "".into(),
         }
-    }
+    };
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for Geometry<T> {
-    fn to_svg_string(&self) -> String {
-        match self {
-            Geometry::MultiPolygon { .. } => {
-                self.clone().into_multi_polygon().unwrap().to_svg_string()
-            }
-            Geometry::Polygon { .. } => self.clone().into_polygon().unwrap().to_svg_string(),
-            Geometry::MultiLineString { .. } => self
-                .clone()
-                .into_multi_line_string()
-                .unwrap()
-                .to_svg_string(),
-            Geometry::LineString { .. } => self.clone().into_line_string().unwrap().to_svg_string(),
-            Geometry::Line { .. } => self.clone().into_line().unwrap().to_svg_string(),
-            _ => "".into(),
-        }
-    }
+pub trait ToSvgLayered<T: CoordNum> {
+    /// Return the Geometries as SVG elements grouped into one `<g id="layer">` per layer, where
+    /// each Geometry's layer is the name returned by the `classifier`
+    ///
+    /// The layers appear in the order their first Geometry appears, and the Geometries within a
+    /// layer keep their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Geometry, GeometryCollection, line_string, polygon};
+    /// use geo_svg_io::geo_svg_writer::ToSvgLayered;
+    ///
+    /// let gc = GeometryCollection(vec![
+    ///     Geometry::LineString(line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]),
+    ///     Geometry::Polygon(polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 2.0, y: 2.0)]),
+    ///     Geometry::LineString(line_string![(x: 5.0, y: 5.0), (x: 6.0, y: 6.0)]),
+    /// ]);
+    ///
+    /// let svg = gc.to_svg_layered(|g| match g {
+    ///     Geometry::LineString(_) => String::from("roads"),
+    ///     _ => String::from("buildings"),
+    /// });
+    /// assert_eq!(
+    ///     svg,
+    ///     r#"<g id="roads">
+    /// <polyline points="0,0 1,1"/>
+    /// <polyline points="5,5 6,6"/>
+    /// </g>
+    /// <g id="buildings">
+    /// <path d="M0 0L2 0L2 2L0 0"/>
+    /// </g>"#
+    /// );
+    /// ```
+    ///
+    fn to_svg_layered<F: Fn(&Geometry<T>) -> String>(&self, classifier: F) -> String;
 }
 
-/** Polygons */
+pub trait ToSvgWithCentroids<T: CoordFloat> {
+    /// Return the Geometries as SVG elements followed by a `<g class="centroids">` layer holding a
+    /// \<circle\> marker at the centroid of each Geometry
+    ///
+    /// Polygonal Geometries use their area-weighted centroid and linear Geometries their
+    /// length-weighted centroid; Points are marked where they are. Empty Geometries have no
+    /// centroid and get no marker.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Geometry, GeometryCollection, line_string, polygon};
+    /// use geo_svg_io::geo_svg_writer::ToSvgWithCentroids;
+    ///
+    /// let gc = GeometryCollection(vec![
+    ///     Geometry::Polygon(polygon![(x: 0.0, y: 0.0), (x: 6.0, y: 0.0), (x: 6.0, y: 6.0), (x: 0.0, y: 6.0)]),
+    ///     Geometry::LineString(line_string![(x: 0.0, y: 10.0), (x: 4.0, y: 10.0)]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     gc.to_svg_with_centroids(),
+    ///     r#"<path d="M0 0L6 0L6 6L0 6L0 0"/>
+    /// <polyline points="0,10 4,10"/>
+    /// <g class="centroids">
+    /// <circle cx="3" cy="3" r="1"/>
+    /// <circle cx="2" cy="10" r="1"/>
+    /// </g>"#
+    /// );
+    /// ```
+    ///
+    fn to_svg_with_centroids(&self) -> String;
+}
 
-impl<T: CoordNum + fmt::Display> ToSvg for MultiPolygon<T> {
-    fn to_svg(&self) -> String {
-        multi_polygon_to_svg(self)
-    }
+pub trait ToSvgWithBoundingBoxes<T: CoordNum> {
+    /// Return the Geometries as SVG elements followed by a dashed overlay `<g class="bboxes">`
+    /// holding the bounding box \<rect\> of each Geometry
+    ///
+    /// Empty Geometries have no extent and get no bounding box.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Geometry, GeometryCollection, line_string};
+    /// use geo_svg_io::geo_svg_writer::ToSvgWithBoundingBoxes;
+    ///
+    /// let gc = GeometryCollection(vec![
+    ///     Geometry::LineString(line_string![(x: 1.0, y: 2.0), (x: 4.0, y: 0.0), (x: 3.0, y: 8.0)]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     gc.to_svg_with_bboxes(),
+    ///     r#"<polyline points="1,2 4,0 3,8"/>
+    /// <g class="bboxes" fill="none" stroke="currentColor" stroke-dasharray="4">
+    /// <rect x="1" y="0" width="3" height="8"/>
+    /// </g>"#
+    /// );
+    /// ```
+    ///
+    fn to_svg_with_bboxes(&self) -> String;
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for MultiPolygon<T> {
-    fn to_svg_string(&self) -> String {
-        multi_polygon_to_svg_string(self)
-    }
+pub trait ToSvgDocument<T: CoordNum> {
+    /// Return the Geometries as a full `<svg>` document whose `viewBox` is the bounding box of
+    /// all of them
+    ///
+    /// The `viewBox` fits the coordinates exactly, so strokes and Point markers at its edges are
+    /// clipped. An empty collection returns an empty `<svg/>` with a zero `viewBox`. Use
+    /// `svg_document` to supply the `viewBox` or a unit yourself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Geometry, GeometryCollection, line_string, polygon};
+    /// use geo_svg_io::geo_svg_writer::ToSvgDocument;
+    ///
+    /// let gc = GeometryCollection(vec![
+    ///     Geometry::LineString(line_string![(x: 1.0, y: 2.0), (x: 4.0, y: 0.0)]),
+    ///     Geometry::Polygon(polygon![(x: 2.0, y: 2.0), (x: 6.0, y: 2.0), (x: 2.0, y: 8.0)]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     gc.to_svg_document(),
+    ///     r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="1 0 5 8">
+    /// <polyline points="1,2 4,0"/>
+    /// <path d="M2 2L6 2L2 8L2 2"/>
+    /// </svg>"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     GeometryCollection::<f64>(vec![]).to_svg_document(),
+    ///     r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 0 0"/>"#
+    /// );
+    /// ```
+    ///
+    fn to_svg_document(&self) -> String;
 }
 
-fn multi_polygon_to_svg<T: CoordNum + fmt::Display>(poly: &MultiPolygon<T>) -> String {
-    if poly.0.is_empty() {
-        "".into()
-    } else {
-        poly.0
-            .iter()
-            .map(|p| polygon_to_svg(&p))
-            .collect::<Vec<String>>()
-            .join("\n")
-    }
+pub trait ToSvgWithSeparator {
+    /// Return the SVG elements of the Geometries joined by `separator` instead of a newline
+    ///
+    /// This is the same as `to_svg_with_options` with `SvgWriteOptions::separator` set, which
+    /// combines with the other options, and `to_svg` is the same as joining with `"\n"`. Only the
+    /// members of the collection (and of nested collections) are separated, the elements within
+    /// a member (e.g., the \<path\>s of a MultiPolygon) are still separated by newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Geometry, GeometryCollection, line_string, polygon};
+    /// use geo_svg_io::geo_svg_writer::{ToSvg, ToSvgWithSeparator};
+    ///
+    /// let gc = GeometryCollection(vec![
+    ///     Geometry::LineString(line_string![(x: 1.0, y: 2.0), (x: 4.0, y: 0.0)]),
+    ///     Geometry::Polygon(polygon![(x: 2.0, y: 2.0), (x: 6.0, y: 2.0), (x: 2.0, y: 8.0)]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     gc.to_svg_with_separator(" "),
+    ///     r#"<polyline points="1,2 4,0"/> <path d="M2 2L6 2L2 8L2 2"/>"#
+    /// );
+    /// assert_eq!(gc.to_svg_with_separator("\n"), gc.to_svg());
+    /// ```
+    ///
+    fn to_svg_with_separator(&self, separator: &str) -> String;
 }
 
-fn multi_polygon_to_svg_string<T: CoordNum + fmt::Display>(poly: &MultiPolygon<T>) -> String {
-    if poly.0.is_empty() {
-        "".into()
-    } else {
-        poly.0
-            .iter()
-            .map(|p| polygon_to_svg_string(&p))
-            .collect::<Vec<String>>()
-            .join("")
-    }
+pub trait ToSvgDocumentFit<T: CoordFloat> {
+    /// Return the Geometries scaled and moved into a `width` by `height` canvas, as a full
+    /// `<svg>` document of that size in pixels
+    ///
+    /// The Geometries are scaled uniformly, preserving their aspect ratio, to fill the canvas less
+    /// `padding` on every side, and centered along the axis they do not fill. The coordinates
+    /// themselves are transformed, so the `viewBox` is the canvas. Geometries without any extent,
+    /// e.g., a single Point, are centered without scaling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Geometry, GeometryCollection, line_string};
+    /// use geo_svg_io::geo_svg_writer::ToSvgDocumentFit;
+    ///
+    /// let gc = GeometryCollection(vec![Geometry::LineString(line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 10.0, y: 20.0),
+    /// ])]);
+    ///
+    /// assert_eq!(
+    ///     gc.to_svg_document_fit(100.0, 100.0, 5.0),
+    ///     r#"<svg xmlns="http://www.w3.org/2000/svg" width="100px" height="100px" viewBox="0 0 100 100">
+    /// <polyline points="27.5,5 72.5,95"/>
+    /// </svg>"#
+    /// );
+    /// ```
+    ///
+    fn to_svg_document_fit(&self, width: T, height: T, padding: T) -> String;
 }
 
-impl<T: CoordNum + fmt::Display> ToSvg for Polygon<T> {
-    fn to_svg(&self) -> String {
-        polygon_to_svg(self)
-    }
+/** Display */
+
+/// A Geometry that is formatted as SVG elements, as returned by `ToSvg::svg_display`
+pub struct SvgDisplay<'a, T: CoordNum, G: ?Sized> {
+    geometry: &'a G,
+    coord_type: PhantomData<T>,
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for Polygon<T> {
-    fn to_svg_string(&self) -> String {
-        polygon_to_svg_string(self)
+impl<'a, T: CoordNum, G: ToSvg<T> + ?Sized> fmt::Display for SvgDisplay<'a, T, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.geometry.write_svg(f)
     }
 }
 
-fn polygon_to_svg<T: CoordNum + fmt::Display>(poly: &Polygon<T>) -> String {
-    if poly.exterior().0.is_empty() {
-        "".into()
-    } else {
-        format!("<path d=\"M{}\"/>", polygon_rings_to_svg(poly))
-    }
+/** Options */
+
+/// The shape used to mark the location of a Point
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MarkerShape {
+    /// A \<circle\> centered on the Point
+    #[default]
+    Circle,
+    /// An axis-aligned \<rect\> centered on the Point
+    Square,
+    /// A \<path\> with a horizontal and a vertical stroke crossing at the Point
+    Cross,
 }
 
-fn polygon_to_svg_string<T: CoordNum + fmt::Display>(poly: &Polygon<T>) -> String {
-    if poly.exterior().0.is_empty() {
-        "".into()
-    } else {
-        format!("M{}", polygon_rings_to_svg(poly))
-    }
+/// The separator written between the x and y of each coordinate pair in a `points` attribute
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PointSeparator {
+    /// `points="0,0 60,0"`
+    #[default]
+    Comma,
+    /// `points="0 0 60 0"`
+    Space,
 }
 
-fn polygon_rings_to_svg<T: CoordNum + fmt::Display>(poly: &Polygon<T>) -> String {
-    let mut lines: Vec<LineString<T>> = poly.interiors().into();
-    let exterior: &LineString<T> = poly.exterior();
-    lines.insert(0, exterior.clone());
+/// The direction in which a ring runs around its area
+///
+/// Directions are those of the coordinates in a y-up system, the same as the windings of
+/// `geo_svg_reader::svg_d_path_subpaths_with_winding`, so they appear mirrored on screen, where
+/// SVG's y axis points down.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
 
-    lines
-        .iter()
-        .map(|l| poly_ring_to_svg(&l))
-        .collect::<Vec<String>>()
-        .join("M")
+/// The order in which the members of a GeometryCollection are written
+///
+/// Sorting makes the output independent of the order of the collection, e.g., for snapshot tests.
+/// Members with an equal key are ordered by their SVG, so the output is fully deterministic.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ElementOrder {
+    /// Write the members in the order of the collection
+    #[default]
+    Preserve,
+    /// Write the members with the largest area first
+    AreaDescending,
+    /// Write the members by the top-left corner of their bounding box, top to bottom and then
+    /// left to right
+    TopLeft,
 }
 
-fn poly_ring_to_svg<T: CoordNum + fmt::Display>(line: &LineString<T>) -> String {
-    line.0
-        .iter()
-        .map(|c| coord_to_svg(&c))
-        .collect::<Vec<String>>()
-        .join("L")
+/// Presentation attributes added to every element written for a Geometry
+///
+/// Fields left as `None` are not written, so the elements keep inheriting those styles.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgStyle {
+    /// The `fill` paint, e.g., `none`, `red` or `#ff0000`
+    pub fill: Option<String>,
+    /// The `stroke` paint
+    pub stroke: Option<String>,
+    /// The `stroke-width` in user units
+    pub stroke_width: Option<f64>,
+    /// The `fill-rule`
+    pub fill_rule: Option<FillRule>,
 }
 
-/** Rect */
+/// A function formatting a single number of the output, e.g., a coordinate or a radius
+pub type NumberFormatter<T> = Arc<dyn Fn(T) -> String + Send + Sync>;
 
-impl<T: CoordNum + fmt::Display> ToSvg for Rect<T> {
-    fn to_svg(&self) -> String {
-        rect_to_svg(self)
-    }
+/// The built-in formatting as a `NumberFormatter`, writing numbers as their Display does without
+/// trailing zeros, e.g., to fall back on from a formatter handling only some numbers
+pub fn plain_formatter<T>() -> NumberFormatter<T>
+where
+    T: CoordNum + fmt::Display + Send + Sync + 'static,
+{
+    builtin_formatter(SvgWriteOptions::default())
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for Rect<T> {
-    fn to_svg_string(&self) -> String {
-        rect_to_svg_string(self)
-    }
+/// The built-in formatting of `SvgWriteOptions::decimals` as a `NumberFormatter`, rounding
+/// numbers to at most this many decimals
+///
+/// # Examples
+/// ```rust
+/// use geo_svg_io::geo_svg_writer::decimals_formatter;
+///
+/// let formatter = decimals_formatter(2);
+/// assert_eq!("0.33", formatter(1.0 / 3.0));
+/// assert_eq!("2.5", formatter(2.5));
+/// ```
+///
+pub fn decimals_formatter<T>(decimals: usize) -> NumberFormatter<T>
+where
+    T: CoordNum + fmt::Display + Send + Sync + 'static,
+{
+    builtin_formatter(SvgWriteOptions {
+        decimals: Some(decimals),
+        ..Default::default()
+    })
 }
 
-fn rect_to_svg<T: CoordNum + fmt::Display>(rect: &Rect<T>) -> String {
-    format!(
-        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
-        rect.min().x,
-        rect.min().y,
-        rect.width(),
-        rect.height()
-    )
+/// The built-in formatting of `SvgWriteOptions::significant_digits` as a `NumberFormatter`,
+/// rounding numbers to this many significant figures
+pub fn significant_digits_formatter<T>(digits: usize) -> NumberFormatter<T>
+where
+    T: CoordNum + fmt::Display + Send + Sync + 'static,
+{
+    builtin_formatter(SvgWriteOptions {
+        significant_digits: Some(digits),
+        ..Default::default()
+    })
 }
 
-fn rect_to_svg_string<T: CoordNum + fmt::Display>(rect: &Rect<T>) -> String {
-    format!(
-        "M{} {}L{} {}L{} {}L{} {}Z",
-        rect.min().x,
-        rect.min().y,
-        rect.min().x,
-        rect.min().y + rect.height(),
-        rect.min().x + rect.width(),
-        rect.min().y + rect.height(),
-        rect.min().x + rect.width(),
-        rect.min().y,
-    )
+fn builtin_formatter<T>(options: SvgWriteOptions<T>) -> NumberFormatter<T>
+where
+    T: CoordNum + fmt::Display + Send + Sync + 'static,
+{
+    Arc::new(move |value| SvgNumber(value, &options).to_string())
 }
 
-/** Triangle */
+/// Options controlling how Geometries are written to SVG
+///
+/// Every setting of the writer is a field here, so they combine freely in a single value passed
+/// to `ToSvg::to_svg_with_options`, while `to_svg` uses `SvgWriteOptions::default()`. Set the
+/// fields to change on top of the defaults, e.g.,
+/// `SvgWriteOptions { close_rings: true, ..Default::default() }`.
+#[derive(Clone)]
+pub struct SvgWriteOptions<T: CoordNum> {
+    /// The shape used to render Points
+    pub marker_shape: MarkerShape,
+    /// The radius of a Point marker (half the side length for `Square` and `Cross` markers)
+    pub point_radius: T,
+    /// The separator between x and y in the `points` of \<polyline\> and \<polygon\> elements
+    pub point_separator: PointSeparator,
+    /// Round every number to this many significant figures (rather than a fixed number of
+    /// decimals), so large and small coordinates keep the same relative precision. This applies
+    /// to the elements of `ToSvg` and the `d`-strings of `ToSvgStringWithOptions` alike.
+    pub significant_digits: Option<usize>,
+    /// Round every number to at most this many decimals, dropping trailing zeros
+    pub decimals: Option<usize>,
+    /// The order in which the members of a GeometryCollection are written
+    pub element_order: ElementOrder,
+    /// The text written between the members of a GeometryCollection, and around the members of
+    /// a nested GeometryCollection inside its \<g\>, e.g., `""` to write every element on a
+    /// single line. The elements within a member (e.g., the \<path\>s of a MultiPolygon) are
+    /// still separated by newlines. Defaults to `"\n"`.
+    pub separator: String,
+    /// End the closed rings of Polygons with a `Z` close command instead of repeating their first
+    /// coordinate
+    pub close_rings: bool,
+    /// Write path data with relative `l` commands after the initial move of each ring or line,
+    /// which is usually shorter. This applies to the \<path\> of Polygons and to every
+    /// `ToSvgStringWithOptions` `d`-string. Each offset is taken from the rounded position written
+    /// before it, so rounding to `decimals` or `significant_digits` does not accumulate. Without
+    /// rounding, the offsets between decimals can carry float rounding errors.
+    pub relative_commands: bool,
+    /// Write the horizontal and vertical edges in path data with the shorter `H` and `V` commands
+    /// (`h` and `v` with `relative_commands`), e.g., for rectilinear building footprints
+    pub axis_aligned_commands: bool,
+    /// Write Polygons without holes as a \<polygon\> with the `points` of their exterior, which is
+    /// implicitly closed, instead of a \<path\>. Polygons with holes are still written as a
+    /// \<path\>, as a \<polygon\> cannot express them.
+    pub polygon_elements: bool,
+    /// Write LineStrings as an open \<path\> instead of a \<polyline\>, e.g., for output made of
+    /// \<path\> elements only. Their path data follows `relative_commands` and
+    /// `axis_aligned_commands` like that of Polygons, but never ends in a `Z` close command.
+    /// The reader still takes a subpath ending where it started for a ring, so a closed
+    /// LineString written this way is read back as a Polygon, unlike from a \<polyline\>.
+    pub linestring_as_path: bool,
+    /// Presentation attributes added to every element
+    pub style: SvgStyle,
+    /// Format every number with this function instead of the built-in formatting, overriding
+    /// `significant_digits`. The built-in formatting never writes thousands separators or
+    /// locale-specific decimal marks, even for coordinate types whose Display does (those numbers
+    /// are written as an f64 instead), and the function must likewise return valid SVG numbers.
+    /// `plain_formatter`, `decimals_formatter` and `significant_digits_formatter` provide the
+    /// built-in formatting, and this applies to `ToSvgStringWithOptions` `d`-strings as well.
+    pub formatter: Option<NumberFormatter<T>>,
+    /// Check every number returned by `formatter`, falling back to the built-in formatting for
+    /// those that are not valid SVG numbers
+    pub validate_formatter: bool,
+    /// Write every number that is whole, after rounding to `decimals` or `significant_digits`, as
+    /// an integer without a decimal point, e.g., for pixel-aligned output. This takes precedence
+    /// over `formatter`, which still formats the numbers that are not whole.
+    pub integer_when_whole: bool,
+    /// Write the exterior rings of Polygons (and Triangles) in this direction and their holes in
+    /// the opposite one, reversing the rings that run the other way, e.g., for tools culling
+    /// back faces by their winding. Rings without area are written as they are.
+    pub normalize_winding: Option<Winding>,
+}
 
-impl<T: CoordNum + fmt::Display> ToSvg for Triangle<T> {
-    fn to_svg(&self) -> String {
-        triangle_to_svg(self)
+impl<T: CoordNum> fmt::Debug for SvgWriteOptions<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SvgWriteOptions")
+            .field("marker_shape", &self.marker_shape)
+            .field("point_radius", &self.point_radius)
+            .field("point_separator", &self.point_separator)
+            .field("significant_digits", &self.significant_digits)
+            .field("decimals", &self.decimals)
+            .field("element_order", &self.element_order)
+            .field("separator", &self.separator)
+            .field("close_rings", &self.close_rings)
+            .field("relative_commands", &self.relative_commands)
+            .field("axis_aligned_commands", &self.axis_aligned_commands)
+            .field("polygon_elements", &self.polygon_elements)
+            .field("linestring_as_path", &self.linestring_as_path)
+            .field("style", &self.style)
+            .field(
+                "formatter",
+                &self.formatter.as_ref().map(|_| "Fn(T) -> String"),
+            )
+            .field("validate_formatter", &self.validate_formatter)
+            .field("integer_when_whole", &self.integer_when_whole)
+            .field("normalize_winding", &self.normalize_winding)
+            .finish()
     }
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for Triangle<T> {
-    fn to_svg_string(&self) -> String {
-        triangle_to_svg_string(self)
+impl<T: CoordNum> Default for SvgWriteOptions<T> {
+    fn default() -> Self {
+        SvgWriteOptions {
+            marker_shape: MarkerShape::default(),
+            point_radius: T::one(),
+            point_separator: PointSeparator::default(),
+            significant_digits: None,
+            decimals: None,
+            element_order: ElementOrder::default(),
+            separator: String::from("\n"),
+            close_rings: false,
+            relative_commands: false,
+            axis_aligned_commands: false,
+            polygon_elements: false,
+            linestring_as_path: false,
+            style: SvgStyle::default(),
+            formatter: None,
+            validate_formatter: false,
+            integer_when_whole: false,
+            normalize_winding: None,
+        }
     }
 }
 
-fn triangle_to_svg<T: CoordNum + fmt::Display>(triangle: &Triangle<T>) -> String {
-    format!(
-        "<polygon points=\"{},{} {},{} {},{}\"/>",
-        triangle.0.x, triangle.0.y, triangle.1.x, triangle.1.y, triangle.2.x, triangle.2.y
-    )
-}
+/** Geometries */
 
-fn triangle_to_svg_string<T: CoordNum + fmt::Display>(triangle: &Triangle<T>) -> String {
-    format!(
-        "M{} {}L{} {}L{} {}Z",
-        triangle.0.x, triangle.0.y, triangle.1.x, triangle.1.y, triangle.2.x, triangle.2.y
-    )
+impl<T: CoordNum + fmt::Display> ToSvg<T> for GeometryCollection<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        geometry_collection_to_svg(w, self, options)
+    }
 }
 
-/** Lines */
+impl<T: CoordNum + fmt::Display> ToSvgWithSeparator for GeometryCollection<T> {
+    fn to_svg_with_separator(&self, separator: &str) -> String {
+        self.to_svg_with_options(&SvgWriteOptions {
+            separator: separator.into(),
+            ..Default::default()
+        })
+    }
+}
 
-impl<T: CoordNum + fmt::Display> ToSvg for MultiLineString<T> {
-    fn to_svg(&self) -> String {
-        multi_linestring_to_svg(self)
+fn geometry_collection_to_svg<T: CoordNum + fmt::Display>(
+    w: &mut dyn fmt::Write,
+    gc: &GeometryCollection<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    if options.element_order == ElementOrder::Preserve {
+        for (i, geom) in gc.0.iter().enumerate() {
+            if i > 0 {
+                w.write_str(&options.separator)?;
+            }
+            geom.write_svg_with_options(w, options)?;
+        }
+        return Ok(());
+    }
+    // Sorting breaks ties on the SVG of each member, so it has to be written first
+    let mut elements =
+        gc.0.iter()
+            .map(|p| (p, p.to_svg_with_options(options)))
+            .collect::<Vec<(&Geometry<T>, String)>>();
+    sort_elements(&mut elements, options.element_order);
+    for (i, (_, svg)) in elements.iter().enumerate() {
+        if i > 0 {
+            w.write_str(&options.separator)?;
+        }
+        w.write_str(svg)?;
     }
+    Ok(())
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for MultiLineString<T> {
-    fn to_svg_string(&self) -> String {
-        multi_linestring_to_svg_string(self)
+impl_to_svg_string!(GeometryCollection, geometry_collection_to_svg_path);
+
+fn geometry_collection_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    gc: &GeometryCollection<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    for geom in &gc.0 {
+        geometry_to_svg_path(w, geom, options)?;
     }
+    Ok(())
 }
 
-fn multi_linestring_to_svg<T: CoordNum + fmt::Display>(multi_line: &MultiLineString<T>) -> String {
-    if multi_line.0.is_empty() {
-        "".into()
-    } else {
-        multi_line
-            .0
+impl<T: CoordNum + fmt::Display> ToSvgLayered<T> for GeometryCollection<T> {
+    fn to_svg_layered<F: Fn(&Geometry<T>) -> String>(&self, classifier: F) -> String {
+        let mut layers = vec![] as Vec<(String, Vec<String>)>;
+        for geom in self.0.iter() {
+            let layer = classifier(geom);
+            let svg = geom.to_svg();
+            match layers.iter_mut().find(|(name, _)| *name == layer) {
+                Some((_, elements)) => elements.push(svg),
+                None => layers.push((layer, vec![svg])),
+            }
+        }
+
+        layers
             .iter()
-            .map(|l| linestring_to_svg(&l))
+            .map(|(name, elements)| {
+                let mut group = format!("<g id=\"{}\">", escape_attribute(name));
+                for element in elements.iter().filter(|e| !e.is_empty()) {
+                    group.push('\n');
+                    group.push_str(element);
+                }
+                group.push_str("\n</g>");
+                group
+            })
             .collect::<Vec<String>>()
             .join("\n")
     }
 }
 
-fn multi_linestring_to_svg_string<T: CoordNum + fmt::Display>(
-    multi_line: &MultiLineString<T>,
-) -> String {
-    if multi_line.0.is_empty() {
-        "".into()
-    } else {
-        multi_line
-            .0
-            .iter()
-            .map(|l| linestring_to_svg_string(&l))
-            .collect::<Vec<String>>()
-            .join("")
+impl<T: CoordFloat + fmt::Display> ToSvgWithCentroids<T> for GeometryCollection<T> {
+    fn to_svg_with_centroids(&self) -> String {
+        if self.is_empty() {
+            return "".into();
+        }
+        let options = SvgWriteOptions::default();
+        let mut svg = self.to_svg();
+        svg.push_str("\n<g class=\"centroids\">");
+        for centroid in self.0.iter().filter_map(geometry_centroid) {
+            svg.push('\n');
+            // Writing to a String cannot fail
+            point_to_svg(&mut svg, &Point(centroid), &options).unwrap();
+        }
+        svg.push_str("\n</g>");
+        svg
     }
 }
 
-impl<T: CoordNum + fmt::Display> ToSvg for LineString<T> {
-    fn to_svg(&self) -> String {
-        linestring_to_svg(self)
+impl<T: CoordNum + fmt::Display> ToSvgWithBoundingBoxes<T> for GeometryCollection<T> {
+    fn to_svg_with_bboxes(&self) -> String {
+        if self.is_empty() {
+            return "".into();
+        }
+        let options = SvgWriteOptions::default();
+        let mut svg = self.to_svg();
+        svg.push_str(
+            "\n<g class=\"bboxes\" fill=\"none\" stroke=\"currentColor\" stroke-dasharray=\"4\">",
+        );
+        for bbox in self.0.iter().filter_map(bounding_box) {
+            svg.push('\n');
+            // Writing to a String cannot fail
+            rect_to_svg(&mut svg, &bbox, &options).unwrap();
+        }
+        svg.push_str("\n</g>");
+        svg
     }
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for LineString<T> {
-    fn to_svg_string(&self) -> String {
-        linestring_to_svg_string(self)
+impl<T: CoordNum + fmt::Display> ToSvgDocument<T> for GeometryCollection<T> {
+    fn to_svg_document(&self) -> String {
+        let zero = Coordinate {
+            x: T::zero(),
+            y: T::zero(),
+        };
+        let view_box = geometry_collection_bounds(self).unwrap_or_else(|| Rect::new(zero, zero));
+        svg_document(self, &view_box, None)
     }
 }
 
-fn linestring_to_svg<T: CoordNum + fmt::Display>(line: &LineString<T>) -> String {
-    if line.0.is_empty() {
-        "".into()
-    } else {
-        format!("<polyline points=\"{}\"/>", line_to_svg(line))
+impl<T: CoordFloat + fmt::Display> ToSvgDocumentFit<T> for GeometryCollection<T> {
+    fn to_svg_document_fit(&self, width: T, height: T, padding: T) -> String {
+        let zero = Coordinate {
+            x: T::zero(),
+            y: T::zero(),
+        };
+        let canvas = Rect::new(
+            zero,
+            Coordinate {
+                x: width,
+                y: height,
+            },
+        );
+        let size = SvgSize {
+            width,
+            height,
+            unit: Some(SvgUnit::Px),
+        };
+        let bounds = match geometry_collection_bounds(self) {
+            Some(bounds) => bounds,
+            None => return svg_document(self, &canvas, Some(size)),
+        };
+
+        let two = T::one() + T::one();
+        let fit_width = (width - two * padding).max(T::zero());
+        let fit_height = (height - two * padding).max(T::zero());
+        // An axis without extent does not constrain the scale
+        let scale = match (bounds.width() > T::zero(), bounds.height() > T::zero()) {
+            (true, true) => (fit_width / bounds.width()).min(fit_height / bounds.height()),
+            (true, false) => fit_width / bounds.width(),
+            (false, true) => fit_height / bounds.height(),
+            (false, false) => T::one(),
+        };
+        let transform = Affine2::translate(-bounds.min().x, -bounds.min().y)
+            .then(&Affine2::scale(scale, scale))
+            .then(&Affine2::translate(
+                (width - bounds.width() * scale) / two,
+                (height - bounds.height() * scale) / two,
+            ));
+        let fitted = GeometryCollection(
+            self.0
+                .iter()
+                .map(|geom| transform.apply_geometry(geom))
+                .collect(),
+        );
+        svg_document(&fitted, &canvas, Some(size))
     }
 }
 
-fn linestring_to_svg_string<T: CoordNum + fmt::Display>(line: &LineString<T>) -> String {
-    if line.0.is_empty() {
-        "".into()
-    } else {
-        format!("M{}", line_to_svg_string(line))
+impl<T: CoordNum + fmt::Display> ToSvgDocument<T> for Geometry<T> {
+    fn to_svg_document(&self) -> String {
+        let zero = Coordinate {
+            x: T::zero(),
+            y: T::zero(),
+        };
+        let view_box = bounding_box(self).unwrap_or_else(|| Rect::new(zero, zero));
+        svg_document(self, &view_box, None)
     }
 }
 
-fn line_to_svg<T: CoordNum + fmt::Display>(line: &LineString<T>) -> String {
-    line.0
-        .iter()
-        .map(|c| coord_to_svg_point(&c))
-        .collect::<Vec<String>>()
-        .join(" ")
+impl<T: CoordNum + fmt::Display> ToSvg<T> for Geometry<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        match self {
+            Geometry::MultiPolygon(multi_polygon) => {
+                multi_polygon.write_svg_with_options(w, options)
+            }
+            Geometry::Polygon(polygon) => polygon.write_svg_with_options(w, options),
+            Geometry::MultiLineString(multi_line) => multi_line.write_svg_with_options(w, options),
+            Geometry::LineString(line) => line.write_svg_with_options(w, options),
+            Geometry::MultiPoint(multi_point) => multi_point.write_svg_with_options(w, options),
+            Geometry::Point(point) => point.write_svg_with_options(w, options),
+            Geometry::Line(line) => line.write_svg_with_options(w, options),
+            Geometry::Rect(rect) => rect.write_svg_with_options(w, options),
+            Geometry::Triangle(triangle) => triangle.write_svg_with_options(w, options),
+            Geometry::GeometryCollection(gc) => {
+                // A nested collection keeps its grouping as a <g>
+                w.write_str("<g>")?;
+                if !gc.0.is_empty() {
+                    w.write_str(&options.separator)?;
+                    gc.write_svg_with_options(w, options)?;
+                }
+                w.write_str(&options.separator)?;
+                w.write_str("</g>")
+            }
+        }
+    }
 }
 
-fn line_to_svg_string<T: CoordNum + fmt::Display>(line: &LineString<T>) -> String {
-    line.0
-        .iter()
-        .map(|c| coord_to_svg(&c))
-        .collect::<Vec<String>>()
-        .join("L")
+// Writes the SVG of each geometry, separated by newlines
+fn elements_to_svg<T: CoordNum, G: ToSvg<T>>(
+    w: &mut dyn fmt::Write,
+    geoms: &[G],
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    for (i, geom) in geoms.iter().enumerate() {
+        if i > 0 {
+            w.write_char('\n')?;
+        }
+        geom.write_svg_with_options(w, options)?;
+    }
+    Ok(())
 }
 
-/** Line */
-
-impl<T: CoordNum + fmt::Display> ToSvg for Line<T> {
-    fn to_svg(&self) -> String {
-        single_line_to_svg(self)
+impl_to_svg_string!(Geometry, geometry_to_svg_path);
+
+fn geometry_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    geom: &Geometry<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    match geom {
+        Geometry::MultiPolygon(mp) => multi_polygon_to_svg_path(w, mp, options),
+        Geometry::Polygon(poly) => polygon_to_svg_path(w, poly, options),
+        Geometry::MultiLineString(mls) => multi_linestring_to_svg_path(w, mls, options),
+        Geometry::LineString(ls) => linestring_to_svg_path(w, ls, options),
+        Geometry::Line(line) => single_line_to_svg_path(w, line, options),
+        Geometry::MultiPoint(mp) => multi_point_to_svg_path(w, mp, options),
+        Geometry::Point(point) => point_to_svg_path(w, point, options),
+        Geometry::Rect(rect) => rect_to_svg_path(w, rect, options),
+        Geometry::Triangle(triangle) => triangle_to_svg_path(w, triangle, options),
+        Geometry::GeometryCollection(gc) => geometry_collection_to_svg_path(w, gc, options),
     }
 }
 
-impl<T: CoordNum + fmt::Display> ToSvgString for Line<T> {
-    fn to_svg_string(&self) -> String {
-        single_line_to_svg_string(self)
+/** Polygons */
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for MultiPolygon<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        elements_to_svg(w, &self.0, options)
     }
 }
 
-fn single_line_to_svg<T: CoordNum + fmt::Display>(line: &Line<T>) -> String {
-    format!(
-        "<line x1=\"{}\" x2=\"{}\" y1=\"{}\" y2=\"{}\"/>",
-        line.start.x, line.end.x, line.start.y, line.end.y
-    )
+impl_to_svg_string!(MultiPolygon, multi_polygon_to_svg_path);
+
+fn multi_polygon_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    multi_poly: &MultiPolygon<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    for poly in &multi_poly.0 {
+        polygon_to_svg_path(w, poly, options)?;
+    }
+    Ok(())
+}
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for Polygon<T> {
+    fn to_svg_with_options(&self, options: &SvgWriteOptions<T>) -> String {
+        let mut buffer = polygon_buffer(self);
+        // Writing to a String cannot fail
+        polygon_to_svg(&mut buffer, self, options).unwrap();
+        buffer
+    }
+
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        polygon_to_svg(w, self, options)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> ToSvgStringWithOptions<T> for Polygon<T> {
+    fn to_svg_string_with_options(&self, options: &SvgWriteOptions<T>) -> String {
+        let mut buffer = polygon_buffer(self);
+        // Writing to a String cannot fail
+        polygon_to_svg_path(&mut buffer, self, options).unwrap();
+        buffer
+    }
+}
+
+impl<T: CoordNum + fmt::Display> ToSvgString for Polygon<T> {
+    fn to_svg_string(&self) -> String {
+        self.to_svg_string_with_options(&SvgWriteOptions::default())
+    }
+}
+
+fn polygon_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    poly: &Polygon<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    if poly.exterior().0.is_empty() {
+        return Ok(());
+    }
+    let wound: Polygon<T>;
+    let poly = match options.normalize_winding {
+        Some(winding) => {
+            wound = wound_polygon(poly, winding);
+            &wound
+        }
+        None => poly,
+    };
+    if options.polygon_elements && poly.interiors().is_empty() {
+        // A <polygon> is implicitly closed, so the closing coordinate is not repeated
+        let ring = &poly.exterior().0;
+        let coords = if ring.len() > 1 && ring.first() == ring.last() {
+            &ring[..ring.len() - 1]
+        } else {
+            &ring[..]
+        };
+        w.write_str("<polygon points=\"")?;
+        for (i, c) in coords.iter().enumerate() {
+            if i > 0 {
+                w.write_char(' ')?;
+            }
+            coord_to_svg_point(w, c, options)?;
+        }
+        w.write_char('"')?;
+        return close_element(w, options);
+    }
+    w.write_str("<path d=\"M")?;
+    polygon_rings_to_svg(w, poly, options)?;
+    w.write_char('"')?;
+    close_element(w, options)
+}
+
+// The Polygon with its exterior running in the `winding` direction and its holes in the opposite
+// one
+fn wound_polygon<T: CoordNum>(poly: &Polygon<T>, winding: Winding) -> Polygon<T> {
+    let opposite = match winding {
+        Winding::Clockwise => Winding::CounterClockwise,
+        Winding::CounterClockwise => Winding::Clockwise,
+    };
+    let wind = |ring: &LineString<T>, winding: Winding| {
+        let mut ring = ring.clone();
+        if matches!(ring_winding(&ring.0), Some(w) if w != winding) {
+            ring.0.reverse();
+        }
+        ring
+    };
+    Polygon::new(
+        wind(poly.exterior(), winding),
+        poly.interiors()
+            .iter()
+            .map(|ring| wind(ring, opposite))
+            .collect(),
+    )
+}
+
+// The direction of a ring from the sign of its (shoelace) area, or None if it has no area
+fn ring_winding<T: CoordNum>(coords: &[Coordinate<T>]) -> Option<Winding> {
+    let (positive, negative) = shoelace_terms(coords);
+    match positive.partial_cmp(&negative) {
+        Some(Ordering::Greater) => Some(Winding::CounterClockwise),
+        Some(Ordering::Less) => Some(Winding::Clockwise),
+        _ => None,
+    }
+}
+
+// The sums of the positive and negative terms of the doubled (shoelace) area of a ring, which are
+// kept apart so that unsigned coordinates cannot underflow
+fn shoelace_terms<T: CoordNum>(coords: &[Coordinate<T>]) -> (T, T) {
+    let next = coords.iter().cycle().skip(1);
+    coords
+        .iter()
+        .zip(next)
+        .fold((T::zero(), T::zero()), |(positive, negative), (a, b)| {
+            (positive + a.x * b.y, negative + b.x * a.y)
+        })
+}
+
+fn polygon_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    poly: &Polygon<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    if poly.exterior().0.is_empty() {
+        return Ok(());
+    }
+    w.write_char('M')?;
+    match options.normalize_winding {
+        Some(winding) => polygon_rings_to_svg(w, &wound_polygon(poly, winding), options),
+        None => polygon_rings_to_svg(w, poly, options),
+    }
+}
+
+// A rough guess of the bytes needed per coordinate (e.g., "L123.45 678.9"), so that large
+// polygons are written without repeatedly growing the buffer
+const ESTIMATED_BYTES_PER_COORD: usize = 16;
+
+fn polygon_buffer<T: CoordNum>(poly: &Polygon<T>) -> String {
+    let coords = poly.exterior().0.len()
+        + poly
+            .interiors()
+            .iter()
+            .map(|ring| ring.0.len())
+            .sum::<usize>();
+    String::with_capacity(coords * ESTIMATED_BYTES_PER_COORD + 16)
+}
+
+fn polygon_rings_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    poly: &Polygon<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    poly_ring_to_svg(w, poly.exterior(), options)?;
+    for interior in poly.interiors() {
+        w.write_char('M')?;
+        poly_ring_to_svg(w, interior, options)?;
+    }
+    Ok(())
+}
+
+fn poly_ring_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    line: &LineString<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    // A closed ring can end in a close command instead of repeating its first coordinate
+    let close = options.close_rings && line.0.len() > 1 && line.0.first() == line.0.last();
+    let coords = if close {
+        &line.0[..line.0.len() - 1]
+    } else {
+        &line.0[..]
+    };
+    path_coords_to_svg(w, coords, options)?;
+    if close {
+        w.write_char('Z')?;
+    }
+    Ok(())
+}
+
+// The path data of the coordinates after the initial `M`, written as line commands according to
+// `relative_commands` and `axis_aligned_commands`
+fn path_coords_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    coords: &[Coordinate<T>],
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    let relative = options.relative_commands;
+    // The position reached by the coordinates written so far
+    let mut position: Option<Coordinate<T>> = None;
+    for c in coords {
+        let p = match position {
+            Some(p) => p,
+            None => {
+                write!(w, "{} {}", SvgNumber(c.x, options), SvgNumber(c.y, options))?;
+                position = Some(Coordinate {
+                    x: written_number(c.x, options),
+                    y: written_number(c.y, options),
+                });
+                continue;
+            }
+        };
+        let (x, y, next) = if relative {
+            let x = PathOffset::new(p.x, c.x, options);
+            let y = PathOffset::new(p.y, c.y, options);
+            let next = Coordinate {
+                x: x.apply(p.x),
+                y: y.apply(p.y),
+            };
+            (x, y, next)
+        } else {
+            let next = Coordinate {
+                x: written_number(c.x, options),
+                y: written_number(c.y, options),
+            };
+            (PathOffset::absolute(c.x), PathOffset::absolute(c.y), next)
+        };
+        if options.axis_aligned_commands && next.y == p.y {
+            write!(
+                w,
+                "{}{}",
+                if relative { 'h' } else { 'H' },
+                x.display(options)
+            )?;
+        } else if options.axis_aligned_commands && next.x == p.x {
+            write!(
+                w,
+                "{}{}",
+                if relative { 'v' } else { 'V' },
+                y.display(options)
+            )?;
+        } else {
+            write!(
+                w,
+                "{}{} {}",
+                if relative { 'l' } else { 'L' },
+                x.display(options),
+                y.display(options)
+            )?;
+        }
+        position = Some(next);
+    }
+    Ok(())
+}
+
+// A relative offset (or an absolute number) in path data, kept as its sign and magnitude like the
+// area in ring_winding, so the offsets between unsigned coordinates cannot underflow
+#[derive(Clone, Copy)]
+struct PathOffset<T: CoordNum> {
+    negative: bool,
+    magnitude: T,
+}
+
+impl<T: CoordNum + fmt::Display> PathOffset<T> {
+    // The offset from `from` to `to`, rounded as it is written
+    fn new(from: T, to: T, options: &SvgWriteOptions<T>) -> Self {
+        let (negative, magnitude) = if to < from {
+            (true, from - to)
+        } else {
+            (false, to - from)
+        };
+        let magnitude = written_number(magnitude, options);
+        PathOffset {
+            // An offset rounded to zero is written without a sign
+            negative: negative && magnitude != T::zero(),
+            magnitude,
+        }
+    }
+
+    fn absolute(value: T) -> Self {
+        PathOffset {
+            negative: false,
+            magnitude: value,
+        }
+    }
+
+    fn apply(&self, position: T) -> T {
+        if self.negative {
+            position - self.magnitude
+        } else {
+            position + self.magnitude
+        }
+    }
+
+    fn display<'a>(&self, options: &'a SvgWriteOptions<T>) -> impl fmt::Display + 'a {
+        let sign = if self.negative { "-" } else { "" };
+        SignedNumber(sign, SvgNumber(self.magnitude, options))
+    }
+}
+
+struct SignedNumber<'a, T: CoordNum>(&'static str, SvgNumber<'a, T>);
+
+impl<'a, T: CoordNum + fmt::Display> fmt::Display for SignedNumber<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)?;
+        self.1.fmt(f)
+    }
+}
+
+/** Rect */
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for Rect<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        rect_to_svg(w, self, options)
+    }
+}
+
+impl_to_svg_string!(Rect, rect_to_svg_path);
+
+fn rect_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    rect: &Rect<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    write!(
+        w,
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+        SvgNumber(rect.min().x, options),
+        SvgNumber(rect.min().y, options),
+        SvgNumber(rect.width(), options),
+        SvgNumber(rect.height(), options)
+    )?;
+    close_element(w, options)
+}
+
+fn rect_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    rect: &Rect<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    let (min, max) = (rect.min(), rect.max());
+    let corners = [
+        min,
+        Coordinate { x: min.x, y: max.y },
+        max,
+        Coordinate { x: max.x, y: min.y },
+    ];
+    w.write_char('M')?;
+    path_coords_to_svg(w, &corners, options)?;
+    w.write_char('Z')
+}
+
+/** Triangle */
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for Triangle<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        triangle_to_svg(w, self, options)
+    }
+}
+
+impl_to_svg_string!(Triangle, triangle_to_svg_path);
+
+fn triangle_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    triangle: &Triangle<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    let mut triangle = *triangle;
+    if let Some(winding) = options.normalize_winding {
+        let coords = [triangle.0, triangle.1, triangle.2];
+        if matches!(ring_winding(&coords), Some(w) if w != winding) {
+            std::mem::swap(&mut triangle.1, &mut triangle.2);
+        }
+    }
+    w.write_str("<polygon points=\"")?;
+    coord_to_svg_point(w, &triangle.0, options)?;
+    w.write_char(' ')?;
+    coord_to_svg_point(w, &triangle.1, options)?;
+    w.write_char(' ')?;
+    coord_to_svg_point(w, &triangle.2, options)?;
+    w.write_char('"')?;
+    close_element(w, options)
+}
+
+fn triangle_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    triangle: &Triangle<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    let mut coords = [triangle.0, triangle.1, triangle.2];
+    if let Some(winding) = options.normalize_winding {
+        if matches!(ring_winding(&coords), Some(w) if w != winding) {
+            coords.swap(1, 2);
+        }
+    }
+    w.write_char('M')?;
+    path_coords_to_svg(w, &coords, options)?;
+    w.write_char('Z')
+}
+
+/** Lines */
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for MultiLineString<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        elements_to_svg(w, &self.0, options)
+    }
+}
+
+impl_to_svg_string!(MultiLineString, multi_linestring_to_svg_path);
+
+fn multi_linestring_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    multi_line: &MultiLineString<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    for line in &multi_line.0 {
+        linestring_to_svg_path(w, line, options)?;
+    }
+    Ok(())
+}
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for LineString<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        linestring_to_svg(w, self, options)
+    }
+}
+
+impl_to_svg_string!(LineString, linestring_to_svg_path);
+
+fn linestring_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    line: &LineString<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    if line.0.is_empty() {
+        return Ok(());
+    }
+    if options.linestring_as_path {
+        // No close command is written, though a path ending where it started is read as a ring
+        w.write_str("<path d=\"M")?;
+        path_coords_to_svg(w, &line.0, options)?;
+        w.write_char('"')?;
+        return close_element(w, options);
+    }
+    w.write_str("<polyline points=\"")?;
+    for (i, c) in line.0.iter().enumerate() {
+        if i > 0 {
+            w.write_char(' ')?;
+        }
+        coord_to_svg_point(w, c, options)?;
+    }
+    w.write_char('"')?;
+    close_element(w, options)
+}
+
+fn linestring_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    line: &LineString<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    if line.0.is_empty() {
+        return Ok(());
+    }
+    w.write_char('M')?;
+    path_coords_to_svg(w, &line.0, options)
+}
+
+/** Line */
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for Line<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        single_line_to_svg(w, self, options)
+    }
+}
+
+impl_to_svg_string!(Line, single_line_to_svg_path);
+
+fn single_line_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    line: &Line<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    write!(
+        w,
+        "<line x1=\"{}\" x2=\"{}\" y1=\"{}\" y2=\"{}\"",
+        SvgNumber(line.start.x, options),
+        SvgNumber(line.end.x, options),
+        SvgNumber(line.start.y, options),
+        SvgNumber(line.end.y, options)
+    )?;
+    close_element(w, options)
+}
+
+fn single_line_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    line: &Line<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    w.write_char('M')?;
+    path_coords_to_svg(w, &[line.start, line.end], options)
+}
+
+/** Point */
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for MultiPoint<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        elements_to_svg(w, &self.0, options)
+    }
+}
+
+impl_to_svg_string!(MultiPoint, multi_point_to_svg_path);
+
+fn multi_point_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    multi_point: &MultiPoint<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    for point in &multi_point.0 {
+        point_to_svg_path(w, point, options)?;
+    }
+    Ok(())
+}
+
+impl<T: CoordNum + fmt::Display> ToSvg<T> for Point<T> {
+    fn write_svg_with_options(
+        &self,
+        w: &mut dyn fmt::Write,
+        options: &SvgWriteOptions<T>,
+    ) -> fmt::Result {
+        point_to_svg(w, self, options)
+    }
+}
+
+impl_to_svg_string!(Point, point_to_svg_path);
+
+// A point has no extent, so it is a lone move in path data
+fn point_to_svg_path<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    point: &Point<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    w.write_char('M')?;
+    path_coords_to_svg(w, &[point.0], options)
+}
+
+fn point_to_svg<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    point: &Point<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    let r = options.point_radius;
+    match options.marker_shape {
+        MarkerShape::Circle => write!(
+            w,
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"",
+            SvgNumber(point.x(), options),
+            SvgNumber(point.y(), options),
+            SvgNumber(r, options)
+        ),
+        MarkerShape::Square => write!(
+            w,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+            difference(point.x(), r, options),
+            difference(point.y(), r, options),
+            SvgNumber(r + r, options),
+            SvgNumber(r + r, options)
+        ),
+        MarkerShape::Cross => write!(
+            w,
+            "<path d=\"M{} {}L{} {}M{} {}L{} {}\"",
+            difference(point.x(), r, options),
+            SvgNumber(point.y(), options),
+            SvgNumber(point.x() + r, options),
+            SvgNumber(point.y(), options),
+            SvgNumber(point.x(), options),
+            difference(point.y(), r, options),
+            SvgNumber(point.x(), options),
+            SvgNumber(point.y() + r, options)
+        ),
+    }?;
+    close_element(w, options)
+}
+
+// The number `value - offset`, which is written as `offset - value` with a minus sign when it is
+// below zero for an unsigned type, as a PathOffset is, since subtracting would underflow
+fn difference<T: CoordNum + fmt::Display>(
+    value: T,
+    offset: T,
+    options: &SvgWriteOptions<T>,
+) -> SignedNumber<'_, T> {
+    let unsigned = T::from(-1).is_none();
+    if unsigned && value < offset {
+        SignedNumber("-", SvgNumber(offset - value, options))
+    } else {
+        SignedNumber("", SvgNumber(value - offset, options))
+    }
+}
+
+/** Points */
+
+// Writes the presentation attributes of the SvgWriteOptions and ends the element
+fn close_element<T: CoordNum, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    let style = &options.style;
+    if let Some(fill) = &style.fill {
+        write!(w, " fill=\"{}\"", escape_attribute(fill))?;
+    }
+    if let Some(stroke) = &style.stroke {
+        write!(w, " stroke=\"{}\"", escape_attribute(stroke))?;
+    }
+    if let Some(stroke_width) = style.stroke_width {
+        write!(w, " stroke-width=\"{}\"", stroke_width)?;
+    }
+    if let Some(fill_rule) = style.fill_rule {
+        write!(w, " fill-rule=\"{}\"", fill_rule)?;
+    }
+    w.write_str("/>")
+}
+
+// The number as written by its Display implementation and trimmed by trim_fraction, unless that
+// is not a valid SVG number (e.g., with thousands separators or a decimal comma), in which case
+// it is written as an f64, whose Display always uses a `.` and never groups digits
+struct PlainNumber<T: CoordNum>(T);
+
+impl<T: CoordNum + fmt::Display> fmt::Display for PlainNumber<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buffer = NumberBuffer::default();
+        let formatted = match write!(buffer, "{}", self.0) {
+            Ok(()) => trim_fraction(buffer.as_str()),
+            // Only numbers too long for the buffer are written to the heap
+            Err(_) => return self.fmt_trimmed(f, trim_fraction(&self.0.to_string())),
+        };
+        self.fmt_trimmed(f, formatted)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> PlainNumber<T> {
+    fn fmt_trimmed(&self, f: &mut fmt::Formatter, formatted: &str) -> fmt::Result {
+        match self.0.to_f64() {
+            Some(number) if number.is_finite() && !is_svg_number(formatted) => {
+                write!(f, "{}", number + 0.)
+            }
+            _ => f.write_str(formatted),
+        }
+    }
+}
+
+// A number written on the stack, which fits any coordinate short of a whole f64 above 1e63
+struct NumberBuffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Default for NumberBuffer {
+    fn default() -> Self {
+        NumberBuffer {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+}
+
+impl NumberBuffer {
+    fn as_str(&self) -> &str {
+        // Only whole strs are written, so the bytes are valid UTF-8
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for NumberBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// The number without the trailing zeros of a fraction (and the decimal point, if no fraction is
+// left), so `60.0` is written as `60` and `10.50` as `10.5` even for coordinate types that
+// write them
+fn trim_fraction(formatted: &str) -> &str {
+    if formatted.contains('.') && !formatted.contains(&['e', 'E'][..]) {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        formatted
+    }
+}
+
+fn coord_to_svg_point<T: CoordNum + fmt::Display, W: fmt::Write + ?Sized>(
+    w: &mut W,
+    coord: &Coordinate<T>,
+    options: &SvgWriteOptions<T>,
+) -> fmt::Result {
+    let separator = match options.point_separator {
+        PointSeparator::Comma => ',',
+        PointSeparator::Space => ' ',
+    };
+    write!(
+        w,
+        "{}{}{}",
+        SvgNumber(coord.x, options),
+        separator,
+        SvgNumber(coord.y, options)
+    )
+}
+
+// A number formatted according to the SvgWriteOptions
+struct SvgNumber<'a, T: CoordNum>(T, &'a SvgWriteOptions<T>);
+
+impl<'a, T: CoordNum + fmt::Display> fmt::Display for SvgNumber<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.1.integer_when_whole {
+            if let Some(written) = fmt_whole_number(f, self.0, self.1) {
+                return written;
+            }
+        }
+        if let Some(formatter) = &self.1.formatter {
+            let formatted = formatter(self.0);
+            if !self.1.validate_formatter || is_svg_number(&formatted) {
+                return write!(f, "{}", formatted);
+            }
+        }
+        match rounded_number(self.0, self.1) {
+            // Small negative numbers round to -0, which is written as 0
+            Some(rounded) => write!(f, "{}", rounded + 0.),
+            None => write!(f, "{}", PlainNumber(self.0)),
+        }
+    }
+}
+
+// Writes the number as an integer if it is whole after rounding to the SvgWriteOptions, or
+// returns None without writing anything
+fn fmt_whole_number<T: CoordNum + fmt::Display>(
+    f: &mut fmt::Formatter,
+    value: T,
+    options: &SvgWriteOptions<T>,
+) -> Option<fmt::Result> {
+    let number = match rounded_number(value, options) {
+        Some(rounded) => rounded,
+        None => {
+            // Integer types and whole floats are usually written as integers already, which
+            // keeps integers beyond the precision of an f64 exact
+            let mut buffer = NumberBuffer::default();
+            let long_number;
+            let formatted = match write!(buffer, "{}", PlainNumber(value)) {
+                Ok(()) => buffer.as_str(),
+                Err(_) => {
+                    long_number = PlainNumber(value).to_string();
+                    &long_number
+                }
+            };
+            let digits = formatted.strip_prefix('-').unwrap_or(formatted);
+            if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Some(f.write_str(formatted));
+            }
+            value.to_f64().filter(|number| number.is_finite())?
+        }
+    };
+    if number.fract() != 0. {
+        return None;
+    }
+    // Small negative numbers round to -0, which is written as 0
+    Some(write!(f, "{:.0}", number + 0.))
+}
+
+// The number rounded to the `significant_digits` and `decimals` of the SvgWriteOptions, or None
+// if it is written unrounded
+fn rounded_number<T: CoordNum>(value: T, options: &SvgWriteOptions<T>) -> Option<f64> {
+    let significant_digits = options.significant_digits.filter(|digits| *digits > 0);
+    if significant_digits.is_none() && options.decimals.is_none() {
+        return None;
+    }
+    let mut rounded = value.to_f64().filter(|value| value.is_finite())?;
+    if let Some(digits) = significant_digits {
+        // Rounding through scientific notation keeps exactly `digits` significant
+        // figures, and parsing it back drops the exponent and any trailing zeros
+        rounded = format!("{:.*e}", digits - 1, rounded)
+            .parse::<f64>()
+            .unwrap_or(rounded);
+    }
+    if let Some(decimals) = options.decimals {
+        rounded = format!("{:.*}", decimals, rounded)
+            .parse::<f64>()
+            .unwrap_or(rounded);
+    }
+    Some(rounded)
+}
+
+// The number as the built-in formatting writes it
+fn written_number<T: CoordNum>(value: T, options: &SvgWriteOptions<T>) -> T {
+    rounded_number(value, options)
+        .and_then(T::from)
+        .unwrap_or(value)
+}
+
+// Whether the string matches the SVG number grammar, e.g., `-1`, `.5`, `2.`, or `1.5e-3`
+fn is_svg_number(value: &str) -> bool {
+    let digits = |s: &str| s.chars().take_while(|c| c.is_ascii_digit()).count();
+    let value = value.strip_prefix(&['+', '-'][..]).unwrap_or(value);
+    let integer = digits(value);
+    let mut rest = &value[integer..];
+    let mut fraction = 0;
+    if let Some(after_point) = rest.strip_prefix('.') {
+        fraction = digits(after_point);
+        rest = &after_point[fraction..];
+    }
+    if integer + fraction == 0 {
+        return false;
+    }
+    match rest.strip_prefix(&['e', 'E'][..]) {
+        Some(exponent) => {
+            let exponent = exponent.strip_prefix(&['+', '-'][..]).unwrap_or(exponent);
+            !exponent.is_empty() && digits(exponent) == exponent.len()
+        }
+        None => rest.is_empty(),
+    }
+}
+
+/** Centroids */
+
+fn geometry_centroid<T: CoordFloat>(geom: &Geometry<T>) -> Option<Coordinate<T>> {
+    match geom {
+        Geometry::Point(p) => Some(p.0),
+        Geometry::Line(l) => linestring_centroid(&LineString(vec![l.start, l.end])).map(|(c, _)| c),
+        Geometry::LineString(ls) => linestring_centroid(ls).map(|(c, _)| c),
+        Geometry::MultiLineString(mls) => weighted_centroid(mls.0.iter().map(linestring_centroid)),
+        Geometry::Polygon(poly) => polygon_centroid(poly).map(|(c, _)| c),
+        Geometry::MultiPolygon(mp) => weighted_centroid(mp.0.iter().map(polygon_centroid)),
+        Geometry::Rect(r) => polygon_centroid(&r.to_polygon()).map(|(c, _)| c),
+        Geometry::Triangle(t) => polygon_centroid(&t.to_polygon()).map(|(c, _)| c),
+        Geometry::MultiPoint(mp) => weighted_centroid(mp.0.iter().map(|p| Some((p.0, T::one())))),
+        Geometry::GeometryCollection(gc) => weighted_centroid(
+            gc.0.iter()
+                .map(|g| geometry_centroid(g).map(|c| (c, T::one()))),
+        ),
+    }
+}
+
+// The mean of the centroids weighted by their length or area
+fn weighted_centroid<T: CoordFloat>(
+    centroids: impl Iterator<Item = Option<(Coordinate<T>, T)>>,
+) -> Option<Coordinate<T>> {
+    let centroids = centroids.flatten().collect::<Vec<(Coordinate<T>, T)>>();
+    let total = centroids
+        .iter()
+        .fold(T::zero(), |total, (_, weight)| total + *weight);
+    if centroids.is_empty() {
+        None
+    } else if total == T::zero() {
+        Some(centroids[0].0)
+    } else {
+        Some(centroids.iter().fold(
+            Coordinate {
+                x: T::zero(),
+                y: T::zero(),
+            },
+            |sum, (c, weight)| Coordinate {
+                x: sum.x + c.x * *weight / total,
+                y: sum.y + c.y * *weight / total,
+            },
+        ))
+    }
+}
+
+// The length-weighted centroid of the line and its length
+fn linestring_centroid<T: CoordFloat>(line: &LineString<T>) -> Option<(Coordinate<T>, T)> {
+    let two = T::one() + T::one();
+    weighted_centroid(line.lines().map(|l| {
+        let midpoint = Coordinate {
+            x: (l.start.x + l.end.x) / two,
+            y: (l.start.y + l.end.y) / two,
+        };
+        Some((midpoint, l.dx().hypot(l.dy())))
+    }))
+    .or_else(|| line.0.first().copied())
+    .map(|c| {
+        let length = line
+            .lines()
+            .fold(T::zero(), |total, l| total + l.dx().hypot(l.dy()));
+        (c, length)
+    })
+}
+
+// The area-weighted centroid of the polygon and its area, falling back to the centroid of the
+// exterior when the polygon has no area
+fn polygon_centroid<T: CoordFloat>(poly: &Polygon<T>) -> Option<(Coordinate<T>, T)> {
+    let (exterior_centroid, exterior_area) = ring_centroid(poly.exterior())?;
+    let mut area = exterior_area;
+    let mut x = exterior_centroid.x * exterior_area;
+    let mut y = exterior_centroid.y * exterior_area;
+    for (centroid, hole_area) in poly.interiors().iter().filter_map(ring_centroid) {
+        area = area - hole_area;
+        x = x - centroid.x * hole_area;
+        y = y - centroid.y * hole_area;
+    }
+    if area == T::zero() {
+        return linestring_centroid(poly.exterior()).map(|(c, _)| (c, T::zero()));
+    }
+    Some((
+        Coordinate {
+            x: x / area,
+            y: y / area,
+        },
+        area,
+    ))
+}
+
+// The centroid of the ring and its (unsigned) area
+fn ring_centroid<T: CoordFloat>(ring: &LineString<T>) -> Option<(Coordinate<T>, T)> {
+    let first = *ring.0.first()?;
+    let mut signed_area = T::zero();
+    let mut x = T::zero();
+    let mut y = T::zero();
+    // Closing the ring, in case it is not already closed
+    for l in ring
+        .lines()
+        .chain(std::iter::once(Line::new(*ring.0.last().unwrap(), first)))
+    {
+        let cross = l.start.x * l.end.y - l.end.x * l.start.y;
+        signed_area = signed_area + cross;
+        x = x + (l.start.x + l.end.x) * cross;
+        y = y + (l.start.y + l.end.y) * cross;
+    }
+    if signed_area == T::zero() {
+        return Some((first, T::zero()));
+    }
+    let three = T::one() + T::one() + T::one();
+    Some((
+        Coordinate {
+            x: x / (three * signed_area),
+            y: y / (three * signed_area),
+        },
+        (signed_area / (T::one() + T::one())).abs(),
+    ))
+}
+
+/** Bounding boxes */
+
+/// Returns the axis-aligned bounding rectangle of every coordinate in the GeometryCollection, or
+/// `None` when it has no coordinates
+///
+/// This is the `viewBox` used by `ToSvgDocument`. Points are bounded by their coordinate, without
+/// the radius of their marker.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::{Coordinate, Geometry, GeometryCollection, Point, Rect, line_string};
+/// use geo_svg_io::geo_svg_writer::geometry_collection_bounds;
+///
+/// let gc = GeometryCollection(vec![
+///     Geometry::LineString(line_string![(x: 1.0_f32, y: 5.0), (x: 4.0, y: 2.0)]),
+///     Geometry::Point(Point::new(-2.0, 3.0)),
+/// ]);
+/// assert_eq!(
+///     Some(Rect::new(Coordinate { x: -2.0, y: 2.0 }, Coordinate { x: 4.0, y: 5.0 })),
+///     geometry_collection_bounds(&gc)
+/// );
+/// assert_eq!(None, geometry_collection_bounds(&GeometryCollection::<f64>(vec![])));
+/// ```
+///
+pub fn geometry_collection_bounds<T: CoordNum>(gc: &GeometryCollection<T>) -> Option<Rect<T>> {
+    let coords =
+        gc.0.iter()
+            .flat_map(geometry_coordinates)
+            .collect::<Vec<Coordinate<T>>>();
+    coordinates_bounding_box(&coords)
+}
+
+fn bounding_box<T: CoordNum>(geom: &Geometry<T>) -> Option<Rect<T>> {
+    coordinates_bounding_box(&geometry_coordinates(geom))
+}
+
+fn coordinates_bounding_box<T: CoordNum>(coords: &[Coordinate<T>]) -> Option<Rect<T>> {
+    let first = coords.first()?;
+    let (min, max) = coords.iter().fold((*first, *first), |(min, max), c| {
+        (
+            Coordinate {
+                x: if c.x < min.x { c.x } else { min.x },
+                y: if c.y < min.y { c.y } else { min.y },
+            },
+            Coordinate {
+                x: if c.x > max.x { c.x } else { max.x },
+                y: if c.y > max.y { c.y } else { max.y },
+            },
+        )
+    });
+    Some(Rect::new(min, max))
+}
+
+fn geometry_coordinates<T: CoordNum>(geom: &Geometry<T>) -> Vec<Coordinate<T>> {
+    match geom {
+        Geometry::Point(p) => vec![p.0],
+        Geometry::Line(l) => vec![l.start, l.end],
+        Geometry::LineString(ls) => ls.0.clone(),
+        Geometry::Polygon(poly) => polygon_coordinates(poly),
+        Geometry::MultiPoint(mp) => mp.0.iter().map(|p| p.0).collect(),
+        Geometry::MultiLineString(mls) => mls.0.iter().flat_map(|ls| ls.0.clone()).collect(),
+        Geometry::MultiPolygon(mp) => mp.0.iter().flat_map(polygon_coordinates).collect(),
+        Geometry::GeometryCollection(gc) => gc.0.iter().flat_map(geometry_coordinates).collect(),
+        Geometry::Rect(r) => vec![r.min(), r.max()],
+        Geometry::Triangle(t) => vec![t.0, t.1, t.2],
+    }
+}
+
+fn polygon_coordinates<T: CoordNum>(poly: &Polygon<T>) -> Vec<Coordinate<T>> {
+    poly.exterior()
+        .0
+        .iter()
+        .chain(poly.interiors().iter().flat_map(|ring| ring.0.iter()))
+        .copied()
+        .collect()
+}
+
+/** Ordering */
+
+fn sort_elements<T: CoordNum>(elements: &mut [(&Geometry<T>, String)], order: ElementOrder) {
+    let compare = |a: T, b: T| a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+    match order {
+        ElementOrder::Preserve => {}
+        ElementOrder::AreaDescending => elements.sort_by(|(a, a_svg), (b, b_svg)| {
+            compare(doubled_area(b), doubled_area(a)).then_with(|| a_svg.cmp(b_svg))
+        }),
+        ElementOrder::TopLeft => elements.sort_by(|(a, a_svg), (b, b_svg)| {
+            // Geometries without coordinates (and so without a bounding box) go last
+            let corner = |g: &Geometry<T>| bounding_box(g).map(|r| r.min());
+            match (corner(a), corner(b)) {
+                (Some(a), Some(b)) => compare(a.y, b.y).then_with(|| compare(a.x, b.x)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+            .then_with(|| a_svg.cmp(b_svg))
+        }),
+    }
+}
+
+// Twice the area of the geometry, which avoids dividing integer coordinates
+fn doubled_area<T: CoordNum>(geom: &Geometry<T>) -> T {
+    match geom {
+        Geometry::Polygon(poly) => polygon_doubled_area(poly),
+        Geometry::MultiPolygon(mp) => {
+            mp.0.iter()
+                .fold(T::zero(), |total, poly| total + polygon_doubled_area(poly))
+        }
+        Geometry::Rect(r) => (T::one() + T::one()) * r.width() * r.height(),
+        Geometry::Triangle(t) => polygon_doubled_area(&t.to_polygon()),
+        Geometry::GeometryCollection(gc) => {
+            gc.0.iter()
+                .fold(T::zero(), |total, g| total + doubled_area(g))
+        }
+        _ => T::zero(),
+    }
 }
 
-fn single_line_to_svg_string<T: CoordNum + fmt::Display>(line: &Line<T>) -> String {
-    format!(
-        "M{} {}L{} {}",
-        line.start.x, line.end.x, line.start.y, line.end.y
-    )
-}
+fn polygon_doubled_area<T: CoordNum>(poly: &Polygon<T>) -> T {
+    poly.interiors()
+        .iter()
+        .fold(ring_doubled_area(poly.exterior()), |area, ring| {
+            // Holes larger than the exterior (of invalid Polygons) leave no area rather than
+            // underflowing
+            let hole = ring_doubled_area(ring);
+            if hole < area {
+                area - hole
+            } else {
+                T::zero()
+            }
+        })
+}
+
+fn ring_doubled_area<T: CoordNum>(ring: &LineString<T>) -> T {
+    let (positive, negative) = shoelace_terms(&ring.0);
+    if positive < negative {
+        negative - positive
+    } else {
+        positive - negative
+    }
+}
+
+/** Attributes */
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/** Documents */
+
+/// Returns the SVG elements of the Geometry wrapped in a full `<svg>` document with the given
+/// `viewBox`
+///
+/// When a `size` is supplied, it is written as the document `width` and `height`, which the
+/// `viewBox` is scaled to fill, e.g., to print a drawing in user units of 0.1 mm on A4 paper.
+/// The size recorded by `geo_svg_reader::svg_document_dimensions` can be passed back in to keep
+/// print workflows round-trip safe.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::{Coordinate, Rect, line_string};
+/// use geo_svg_io::attributes::{SvgSize, SvgUnit};
+/// use geo_svg_io::geo_svg_writer::svg_document;
+///
+/// let line = line_string![
+///             (x: 100.0, y: 100.0),
+///             (x: 2000.0, y: 2870.0),
+///         ];
+/// let view_box = Rect::new(
+///     Coordinate { x: 0.0, y: 0.0 },
+///     Coordinate { x: 2100.0, y: 2970.0 },
+/// );
+/// let size = SvgSize { width: 210.0, height: 297.0, unit: Some(SvgUnit::Mm) };
+///
+/// let svg = svg_document(&line, &view_box, Some(size));
+/// assert_eq!(
+///     svg,
+///     r#"<svg xmlns="http://www.w3.org/2000/svg" width="210mm" height="297mm" viewBox="0 0 2100 2970">
+/// <polyline points="100,100 2000,2870"/>
+/// </svg>"#
+/// );
+/// ```
+///
+pub fn svg_document<T: CoordNum + fmt::Display>(
+    geom: &impl ToSvg<T>,
+    view_box: &Rect<T>,
+    size: Option<SvgSize<T>>,
+) -> String {
+    let size = match size {
+        Some(size) => {
+            let unit = size.unit.map(|unit| unit.to_string()).unwrap_or_default();
+            format!(
+                " width=\"{}{}\" height=\"{}{}\"",
+                PlainNumber(size.width),
+                unit,
+                PlainNumber(size.height),
+                unit
+            )
+        }
+        None => "".into(),
+    };
+    let open = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\"{} viewBox=\"{} {} {} {}\"",
+        size,
+        PlainNumber(view_box.min().x),
+        PlainNumber(view_box.min().y),
+        PlainNumber(view_box.width()),
+        PlainNumber(view_box.height())
+    );
+    let body = geom.to_svg();
+    if body.is_empty() {
+        format!("{}/>", open)
+    } else {
+        format!("{}>\n{}\n</svg>", open, body)
+    }
+}
+
+/** Reuse */
+
+/// Returns the Geometry as a single reusable `<path>` inside `<defs>`, followed by a `<use>`
+/// element referencing it for every transform
+///
+/// Repeated shapes (e.g., map symbols) are written only once, and each instance only carries
+/// its `transform` (any SVG transform list, such as `translate(10 20) rotate(45)`, or an
+/// `affine::Affine2`), which is much smaller than repeating the full path data. The path data is
+/// the `d`-string produced by `ToSvgString`. The `id` and transforms are escaped for use in
+/// attribute values.
+///
+/// The references are written as the SVG 2 `href` attribute. Renderers only supporting SVG 1.1
+/// need `xlink:href` instead, which is only valid in a document declaring the
+/// `xmlns:xlink="http://www.w3.org/1999/xlink"` namespace.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::polygon;
+/// use geo_svg_io::geo_svg_writer::svg_defs_with_uses;
+///
+/// let poly = polygon![
+///             (x: 0.0, y: 0.0),
+///             (x: 4.0, y: 0.0),
+///             (x: 2.0, y: 3.0),
+///             (x: 0.0, y: 0.0),
+///         ];
+///
+/// let svg = svg_defs_with_uses(&poly, "s1", &["translate(10 10)", "translate(20 10) scale(2)"]);
+/// assert_eq!(
+///     svg,
+///     r##"<defs>
+/// <path id="s1" d="M0 0L4 0L2 3L0 0"/>
+/// </defs>
+/// <use href="#s1" transform="translate(10 10)"/>
+/// <use href="#s1" transform="translate(20 10) scale(2)"/>"##
+/// );
+/// ```
+///
+pub fn svg_defs_with_uses(
+    geom: &impl ToSvgString,
+    id: &str,
+    transforms: &[impl fmt::Display],
+) -> String {
+    let id = escape_attribute(id);
+    let mut svg = format!(
+        "<defs>\n<path id=\"{}\" d=\"{}\"/>\n</defs>",
+        id,
+        geom.to_svg_string()
+    );
+    for transform in transforms {
+        svg.push_str(&format!(
+            "\n<use href=\"#{}\" transform=\"{}\"/>",
+            id,
+            escape_attribute(&transform.to_string())
+        ));
+    }
+    svg
+}
+
+/** Templates */
+
+pub struct PlaceholderNotFoundError;
+
+// Implement std::fmt::Display for PlaceholderNotFoundError
+impl fmt::Display for PlaceholderNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The placeholder could not be found in the SVG template") // user-facing output
+    }
+}
+
+// Implement std::fmt::Debug for PlaceholderNotFoundError
+impl fmt::Debug for PlaceholderNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{ file: {}, line: {} }}", file!(), line!()) // programmer-facing output
+    }
+}
+
+/// Returns the SVG template with every occurrence of `placeholder` replaced by the SVG elements
+/// of the Geometry
+///
+/// This is useful for embedding generated shapes into a hand-authored SVG that already carries
+/// its own styles and `<defs>`. The geometry output is already valid markup, so it is inserted
+/// as-is without any escaping.
+///
+/// A `PlaceholderNotFoundError` is returned when the template does not contain the placeholder
+/// (or the placeholder is empty).
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_types::polygon;
+/// use geo_svg_io::geo_svg_writer::render_into_template;
+///
+/// let poly = polygon![
+///             (x: 1.0, y: 1.0),
+///             (x: 4.0, y: 1.0),
+///             (x: 4.0, y: 4.0),
+///             (x: 1.0, y: 1.0),
+///         ];
+/// let template = r#"<svg xmlns="http://www.w3.org/2000/svg"><g class="shapes">{{shapes}}</g></svg>"#;
+///
+/// let svg = render_into_template(template, "{{shapes}}", &poly);
+/// assert!(svg.is_ok());
+/// assert_eq!(
+///     svg.ok().unwrap(),
+///     r#"<svg xmlns="http://www.w3.org/2000/svg"><g class="shapes"><path d="M1 1L4 1L4 4L1 1"/></g></svg>"#
+/// );
+/// ```
+///
+pub fn render_into_template<T: CoordNum>(
+    template: &str,
+    placeholder: &str,
+    geom: &impl ToSvg<T>,
+) -> Result<String, PlaceholderNotFoundError> {
+    if placeholder.is_empty() || !template.contains(placeholder) {
+        return Err(PlaceholderNotFoundError);
+    }
+    Ok(template.replace(placeholder, &geom.to_svg()))
+}
+
+/** Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo_svg_reader::{
+        svg_d_path_to_geometry, svg_document_to_geometry_collection, svg_to_geometry_collection,
+    };
+    use geo_types::{line_string, polygon};
+    use std::fmt::Write;
+    use svgtypes::PointsParser;
+
+    #[test]
+    fn can_format_geom_collection() {
+        let poly = Geometry::Polygon(polygon![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.0, y: 4.0),
+            (x: 1.0, y: 1.0),
+        ]);
+        let line = Geometry::LineString(line_string![
+            (x: 11.0, y: 21.0),
+            (x: 34.0, y: 21.0),
+            (x: 24.0, y: 54.0),
+            (x: 31.50, y: 34.0),
+        ]);
+        let gc = GeometryCollection(vec![line, poly]);
+        let wkt_out = gc.to_svg();
+        let expected = String::from(
+            r#"<polyline points="11,21 34,21 24,54 31.5,34"/>
+<path d="M1 1L4 1L4 4L1 4L1 1"/>"#,
+        );
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_nested_geom_collection_as_group() {
+        let line = Geometry::Line(Line::new(
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 10.0, y: 10.0 },
+        ));
+        let poly = Geometry::Polygon(polygon![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.0, y: 1.0),
+        ]);
+        let layer = GeometryCollection(vec![
+            poly.clone(),
+            Geometry::GeometryCollection(GeometryCollection(vec![line.clone()])),
+        ]);
+        let gc = GeometryCollection(vec![
+            line.clone(),
+            Geometry::GeometryCollection(layer),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]);
+        let expected = String::from(
+            r#"<line x1="0" x2="10" y1="0" y2="10"/>
+<g>
+<path d="M1 1L4 1L4 4L1 1"/>
+<g>
+<line x1="0" x2="10" y1="0" y2="10"/>
+</g>
+</g>
+<g>
+</g>"#,
+        );
+        assert_eq!(expected, gc.to_svg());
+
+        // The document reader reads the shapes of every group
+        let parsed_svg = svg_document_to_geometry_collection(&gc.to_svg_document());
+        assert!(parsed_svg.is_ok());
+        assert_eq!(vec![line.clone(), poly, line], parsed_svg.ok().unwrap().0);
+    }
+
+    #[test]
+    fn can_format_svg_document_fit_to_canvas() {
+        // A 10 by 20 bounding box is scaled by 4.5 to the 90 units left inside the padding
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(polygon![
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 20.0),
+                (x: 0.0, y: 20.0),
+            ]),
+            Geometry::Point(Point::new(5.0, 10.0)),
+        ]);
+        let svg = gc.to_svg_document_fit(100.0, 100.0, 5.0);
+        assert_eq!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100px" height="100px" viewBox="0 0 100 100">
+<path d="M27.5 5L72.5 5L72.5 95L27.5 95L27.5 5"/>
+<circle cx="50" cy="50" r="1"/>
+</svg>"#
+        );
+        let parsed_svg = svg_document_to_geometry_collection(&svg);
+        assert!(parsed_svg.is_ok());
+        let bounds = geometry_collection_bounds(&parsed_svg.ok().unwrap()).unwrap();
+        assert!(bounds.min().x >= 5.0 && bounds.min().y >= 5.0);
+        assert!(bounds.max().x <= 95.0 && bounds.max().y <= 95.0);
+
+        // The aspect ratio is kept on a wide canvas, too
+        let svg = gc.to_svg_document_fit(200.0, 50.0, 0.0);
+        assert!(svg.contains(r#"<path d="M87.5 0L112.5 0L112.5 50L87.5 50L87.5 0"/>"#));
+
+        // Geometries without extent are centered, and nothing is written without any
+        let point = GeometryCollection(vec![Geometry::Point(Point::new(-3.0, 7.0))]);
+        assert!(point
+            .to_svg_document_fit(100.0, 100.0, 5.0)
+            .contains(r#"<circle cx="50" cy="50" r="1"/>"#));
+        assert_eq!(
+            GeometryCollection::<f64>(vec![]).to_svg_document_fit(100.0, 100.0, 5.0),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100px" height="100px" viewBox="0 0 100 100"/>"#
+        );
+    }
+
+    #[test]
+    fn can_format_geom_collection_with_separator() {
+        let gc = GeometryCollection(vec![
+            Geometry::Line(Line::new(
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 1.0 },
+            )),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(2.0, 3.0), Point::new(4.0, 5.0)])),
+        ]);
+        assert_eq!(
+            gc.to_svg_with_separator(""),
+            r#"<line x1="0" x2="1" y1="0" y2="1"/><circle cx="2" cy="3" r="1"/>
+<circle cx="4" cy="5" r="1"/>"#
+        );
+        assert_eq!(
+            gc.to_svg_with_separator("<!-- next -->"),
+            r#"<line x1="0" x2="1" y1="0" y2="1"/><!-- next --><circle cx="2" cy="3" r="1"/>
+<circle cx="4" cy="5" r="1"/>"#
+        );
+        assert_eq!(gc.to_svg_with_separator("\n"), gc.to_svg());
+        assert_eq!(
+            "",
+            GeometryCollection::<f64>(vec![]).to_svg_with_separator(" ")
+        );
+
+        // The separator combines with the other options and applies to nested collections
+        let nested = GeometryCollection(vec![
+            Geometry::Point(Point::new(0.5, 0.25)),
+            Geometry::GeometryCollection(gc),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]);
+        let options = SvgWriteOptions {
+            separator: String::from(" "),
+            decimals: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            nested.to_svg_with_options(&options),
+            r#"<circle cx="0.5" cy="0.2" r="1"/> <g> <line x1="0" x2="1" y1="0" y2="1"/> <circle cx="2" cy="3" r="1"/>
+<circle cx="4" cy="5" r="1"/> </g> <g> </g>"#
+        );
+    }
+
+    #[test]
+    fn can_format_empty_geom_collection() {
+        let gc = GeometryCollection(vec![] as Vec<Geometry<f64>>);
+        let wkt_out = gc.to_svg();
+        let expected = String::from("");
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_multi_polygon() {
+        let poly1 = polygon![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.0, y: 4.0),
+            (x: 1.0, y: 1.0),
+        ];
+        let poly2 = polygon!(
+        exterior: [
+            (x: 0.0, y: 0.0),
+            (x: 6.0, y: 0.0),
+            (x: 6.0, y: 6.0),
+            (x: 0.0, y: 6.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[[
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.50, y: 4.0),
+            (x: 1.0, y: 1.0),]
+            ]
+        );
+        let mp = MultiPolygon(vec![poly1, poly2]);
+        let wkt_out = mp.to_svg();
+        let expected = String::from(
+            r#"<path d="M1 1L4 1L4 4L1 4L1 1"/>
+<path d="M0 0L6 0L6 6L0 6L0 0M1 1L4 1L4 4L1.5 4L1 1"/>"#,
+        );
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_multi_polygon_to_d_string() {
+        let poly1 = polygon![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.0, y: 4.0),
+            (x: 1.0, y: 1.0),
+        ];
+        let poly2 = polygon!(
+        exterior: [
+            (x: 0.0, y: 0.0),
+            (x: 6.0, y: 0.0),
+            (x: 6.0, y: 6.0),
+            (x: 0.0, y: 6.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[[
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.50, y: 4.0),
+            (x: 1.0, y: 1.0),]
+            ]
+        );
+        let mp = MultiPolygon(vec![poly1, poly2]);
+        let wkt_out = mp.to_svg_string();
+        let expected =
+            String::from("M1 1L4 1L4 4L1 4L1 1M0 0L6 0L6 6L0 6L0 0M1 1L4 1L4 4L1.5 4L1 1");
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_empty_multi_polygon() {
+        let mp = MultiPolygon(vec![] as Vec<Polygon<f64>>);
+        let wkt_out = mp.to_svg();
+        let expected = String::from("");
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_polygon() {
+        let poly = polygon![
+            (x: 1.0, y: 1.0),
+            (x: 40.0, y: 1.0),
+            (x: 40.0, y: 40.0),
+            (x: 1.0, y: 40.0),
+            (x: 1.0, y: 1.0),
+        ];
+        let wkt_out = poly.to_svg();
+        let expected = String::from(r#"<path d="M1 1L40 1L40 40L1 40L1 1"/>"#);
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_empty_polygon() {
+        let poly: Polygon<f64> =
+            Polygon::new(LineString::from(vec![] as Vec<Coordinate<f64>>), vec![]);
+        let wkt_out = poly.to_svg();
+        let expected = String::from("");
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_polygon_with_hole() {
+        let poly = polygon!(
+        exterior: [
+            (x: 0.0, y: 0.0),
+            (x: 0.0, y: 60.0),
+            (x: 60.0, y: 60.0),
+            (x: 60.0, y: 0.0),
+            (x: 0.0, y: 0.0),],
+        interiors:[[
+            (x: 10.0, y: 10.0),
+            (x: 40.0, y: 1.0),
+            (x: 40.0, y: 40.0),
+            (x: 10.50, y: 40.0),
+            (x: 10.0, y: 10.0),]
+            ]
+        );
+        let wkt_out = poly.to_svg();
+        let expected =
+            String::from(r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#);
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_multi_line_string() {
+        let line1 = line_string![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.50, y: 4.0),
+        ];
+        let line2 = line_string![
+            (x: 11.0, y: 21.0),
+            (x: 34.0, y: 21.0),
+            (x: 24.0, y: 54.0),
+            (x: 31.50, y: 34.0),
+        ];
+        let ml = MultiLineString(vec![line1, line2]);
+        let wkt_out = ml.to_svg();
+        let expected = String::from(
+            r#"<polyline points="1,1 4,1 4,4 1.5,4"/>
+<polyline points="11,21 34,21 24,54 31.5,34"/>"#,
+        );
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_empty_multi_line_string() {
+        let ml = MultiLineString(vec![] as Vec<LineString<f64>>);
+        let wkt_out = ml.to_svg();
+        let expected = String::from("");
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_format_line_string() {
+        let line = line_string![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.50, y: 4.0),
+            (x: 1.0, y: 1.0),
+        ];
+        let wkt_out = line.to_svg();
+        let expected = String::from(r#"<polyline points="1,1 4,1 4,4 1.5,4 1,1"/>"#);
+        assert_eq!(wkt_out, expected);
+    }
+
+    #[test]
+    fn can_round_trip_line_svg_string() {
+        let line = Line::new(
+            Coordinate { x: 1.0, y: 2.0 },
+            Coordinate { x: 3.5, y: -4.0 },
+        );
+        let svg_out = line.to_svg_string();
+        assert_eq!(svg_out, "M1 2L3.5 -4");
+
+        let parsed_svg = svg_d_path_to_geometry(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(Some(line), parsed_svg.ok().unwrap().into_line());
+    }
+
+    #[test]
+    fn can_format_line_string_with_point_separators() {
+        let line = line_string![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 1.50, y: 4.0),
+        ];
+        let comma_out = line.to_svg();
+        let space_out = line.to_svg_with_options(&SvgWriteOptions {
+            point_separator: PointSeparator::Space,
+            ..Default::default()
+        });
+        assert_eq!(comma_out, r#"<polyline points="1,1 4,1 1.5,4"/>"#);
+        assert_eq!(space_out, r#"<polyline points="1 1 4 1 1.5 4"/>"#);
+
+        // Both forms must read back to the same coordinates
+        let parse_points = |svg: &str| {
+            let points = svg
+                .trim_start_matches("<polyline points=\"")
+                .trim_end_matches("\"/>");
+            PointsParser::from(points)
+                .map(|(x, y)| Coordinate { x, y })
+                .collect::<LineString<f64>>()
+        };
+        assert_eq!(line, parse_points(&comma_out));
+        assert_eq!(line, parse_points(&space_out));
+    }
+
+    #[test]
+    fn can_format_empty_line_string() {
+        let line = LineString::from(vec![] as Vec<Coordinate<f64>>);
+        let wkt_out = line.to_svg();
+        let expected = String::from("");
+        assert_eq!(wkt_out, expected);
+    }
+
+    //TODO: add tests for Line, Triangle, and Rect
+
+    #[test]
+    fn can_format_defs_with_uses() {
+        let line = line_string![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+        ];
+        let transforms = ["translate(10 0)", "rotate(90)", "matrix(1 0 0 -1 0 50)"];
+        let svg_out = svg_defs_with_uses(&line, "symbol", &transforms);
+        let lines = svg_out.lines().collect::<Vec<&str>>();
+
+        assert_eq!(3 + transforms.len(), lines.len());
+        assert_eq!("<defs>", lines[0]);
+        assert_eq!(r#"<path id="symbol" d="M1 1L4 1L4 4"/>"#, lines[1]);
+        assert_eq!("</defs>", lines[2]);
+        for (use_line, transform) in lines[3..].iter().zip(transforms.iter()) {
+            assert_eq!(
+                &format!(r##"<use href="#symbol" transform="{}"/>"##, transform),
+                use_line
+            );
+        }
+
+        // Affine transformations are written as the equivalent matrix
+        let svg_out = svg_defs_with_uses(&line, "symbol", &[Affine2::flip_y(50.0)]);
+        assert!(svg_out.ends_with(r##"<use href="#symbol" transform="matrix(1 0 0 -1 0 50)"/>"##));
+
+        // Ids and transforms are escaped, so they cannot break out of their attributes
+        let svg_out = svg_defs_with_uses(&line, r#"a"b&c"#, &[r#"scale(2)"/><script/>"#]);
+        assert!(svg_out.contains(r#"<path id="a&quot;b&amp;c" d="#));
+        assert!(svg_out.ends_with(
+            r##"<use href="#a&quot;b&amp;c" transform="scale(2)&quot;/&gt;&lt;script/&gt;"/>"##
+        ));
+        let svg_out = svg_defs_with_uses(&line, r#"a"b&c"#, &["translate(1 0)"]);
+        let parsed_svg = svg_document_to_geometry_collection(&format!("<svg>{}</svg>", svg_out));
+        assert!(parsed_svg.is_ok());
+        assert_eq!(1, parsed_svg.ok().unwrap().0.len());
+    }
+
+    #[test]
+    fn can_format_layered_geom_collection() {
+        let small = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+            (x: 0.0, y: 0.0),
+        ];
+        let large = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 0.0),
+        ];
+        let line = line_string![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+        ];
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(large.clone()),
+            Geometry::LineString(line),
+            Geometry::Polygon(small),
+            Geometry::Polygon(large),
+        ]);
+        let svg_out = gc.to_svg_layered(|g| match g {
+            Geometry::Polygon(p) if p.exterior().0.iter().any(|c| c.x > 5.) => {
+                String::from("large & \"bold\"")
+            }
+            Geometry::Polygon(_) => String::from("small"),
+            _ => String::from("lines"),
+        });
+        let expected = String::from(
+            r#"<g id="large &amp; &quot;bold&quot;">
+<path d="M0 0L10 0L10 10L0 0"/>
+<path d="M0 0L10 0L10 10L0 0"/>
+</g>
+<g id="lines">
+<polyline points="1,1 4,1"/>
+</g>
+<g id="small">
+<path d="M0 0L1 0L1 1L0 0"/>
+</g>"#,
+        );
+        assert_eq!(svg_out, expected);
+    }
+
+    #[test]
+    fn can_format_empty_layered_geom_collection() {
+        let gc: GeometryCollection<f64> = GeometryCollection(vec![]);
+        assert_eq!("", gc.to_svg_layered(|_| String::from("layer")));
+    }
+
+    #[test]
+    fn can_format_centroid_markers() {
+        let donut = polygon!(
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 10.0),
+                (x: 0.0, y: 10.0),
+                (x: 0.0, y: 0.0),],
+            interiors: [[
+                (x: 5.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 10.0),
+                (x: 5.0, y: 10.0),
+                (x: 5.0, y: 0.0),],
+            ],
+        );
+        let line = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 2.0, y: 0.0),
+            (x: 2.0, y: 6.0),
+        ];
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(donut),
+            Geometry::LineString(line),
+            Geometry::MultiPolygon(MultiPolygon(vec![])),
+        ]);
+        let svg_out = gc.to_svg_with_centroids();
+        let centroids = &svg_out[svg_out.find("<g class=\"centroids\">").unwrap()..];
+        assert_eq!(
+            centroids,
+            r#"<g class="centroids">
+<circle cx="2.5" cy="5" r="1"/>
+<circle cx="1.75" cy="2.25" r="1"/>
+</g>"#
+        );
+        assert_eq!(2, svg_out.matches("<circle").count());
+    }
+
+    #[test]
+    fn can_format_with_significant_digits() {
+        let options = SvgWriteOptions {
+            significant_digits: Some(4),
+            ..Default::default()
+        };
+        let line = line_string![
+            (x: 123456.789, y: 0.0123456),
+            (x: -98.7654, y: 1.0),
+            (x: 0.000987654, y: 5e-7),
+        ];
+        assert_eq!(
+            line.to_svg_with_options(&options),
+            r#"<polyline points="123500,0.01235 -98.77,1 0.0009877,0.0000005"/>"#
+        );
+
+        let poly = polygon![
+            (x: 1.23456, y: 12.3456),
+            (x: 123.456, y: 1234.56),
+            (x: 12345.6, y: 0.123456),
+        ];
+        assert_eq!(
+            poly.to_svg_with_options(&options),
+            r#"<path d="M1.235 12.35L123.5 1235L12350 0.1235L1.235 12.35"/>"#
+        );
+        assert_eq!(
+            "M1.235 12.35L123.5 1235L12350 0.1235L1.235 12.35",
+            poly.to_svg_string_with_options(&options)
+        );
+        assert_eq!(
+            "M123500 0.01235L-98.77 1L0.0009877 0.0000005",
+            line.to_svg_string_with_options(&options)
+        );
+    }
+
+    #[test]
+    fn can_format_integers_with_significant_digits() {
+        let options = SvgWriteOptions {
+            significant_digits: Some(2),
+            ..Default::default()
+        };
+        let line = Line::new(Coordinate { x: 12345, y: 7 }, Coordinate { x: -987, y: 0 });
+        assert_eq!(
+            line.to_svg_with_options(&options),
+            r#"<line x1="12000" x2="-990" y1="7" y2="0"/>"#
+        );
+    }
+
+    #[test]
+    fn can_format_bounding_boxes() {
+        let poly = polygon![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 4.0),
+            (x: 1.5, y: 6.5),
+        ];
+        let line = line_string![
+            (x: -2.0, y: 10.0),
+            (x: 3.0, y: 12.0),
+        ];
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(poly),
+            Geometry::LineString(line),
+            Geometry::LineString(LineString(vec![])),
+        ]);
+        let svg_out = gc.to_svg_with_bboxes();
+        let bboxes = &svg_out[svg_out.find("<g class=\"bboxes\"").unwrap()..];
+        assert_eq!(
+            bboxes,
+            r#"<g class="bboxes" fill="none" stroke="currentColor" stroke-dasharray="4">
+<rect x="1" y="1" width="3" height="5.5"/>
+<rect x="-2" y="10" width="5" height="2"/>
+</g>"#
+        );
+    }
+
+    #[test]
+    fn can_format_geom_collection_in_sorted_order() {
+        let small = Geometry::Polygon(polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+        ]);
+        let large = Geometry::Polygon(polygon![
+            (x: 5.0, y: 5.0),
+            (x: 15.0, y: 5.0),
+            (x: 15.0, y: 15.0),
+            (x: 5.0, y: 15.0),
+        ]);
+        let upper_line = Geometry::LineString(line_string![(x: 20.0, y: -1.0), (x: 30.0, y: -1.0)]);
+        let lower_line = Geometry::LineString(line_string![(x: -5.0, y: 2.0), (x: 0.0, y: 2.0)]);
+        let gc = GeometryCollection(vec![
+            small.clone(),
+            upper_line.clone(),
+            large.clone(),
+            lower_line.clone(),
+        ]);
+        let reordered = GeometryCollection(vec![lower_line, large, upper_line, small]);
+
+        // Collections differing only in order write different SVG by default
+        assert_ne!(gc.to_svg(), reordered.to_svg());
+
+        let options = SvgWriteOptions {
+            element_order: ElementOrder::AreaDescending,
+            ..Default::default()
+        };
+        let svg_out = gc.to_svg_with_options(&options);
+        assert_eq!(svg_out, reordered.to_svg_with_options(&options));
+        assert_eq!(
+            svg_out,
+            r#"<path d="M5 5L15 5L15 15L5 15L5 5"/>
+<path d="M0 0L1 0L1 1L0 0"/>
+<polyline points="-5,2 0,2"/>
+<polyline points="20,-1 30,-1"/>"#
+        );
+
+        let options = SvgWriteOptions {
+            element_order: ElementOrder::TopLeft,
+            ..Default::default()
+        };
+        let svg_out = gc.to_svg_with_options(&options);
+        assert_eq!(svg_out, reordered.to_svg_with_options(&options));
+        assert_eq!(
+            svg_out,
+            r#"<polyline points="20,-1 30,-1"/>
+<path d="M0 0L1 0L1 1L0 0"/>
+<polyline points="-5,2 0,2"/>
+<path d="M5 5L15 5L15 15L5 15L5 5"/>"#
+        );
+
+        // Clockwise rings of unsigned coordinates do not underflow
+        let clockwise: Polygon<u32> =
+            polygon![(x: 0, y: 0), (x: 0, y: 4), (x: 4, y: 4), (x: 4, y: 0)];
+        let counter_clockwise: Polygon<u32> = polygon![(x: 5, y: 0), (x: 6, y: 0), (x: 6, y: 1)];
+        let gc = GeometryCollection(vec![counter_clockwise.into(), clockwise.into()]);
+        let options = SvgWriteOptions {
+            element_order: ElementOrder::AreaDescending,
+            ..Default::default()
+        };
+        assert_eq!(
+            gc.to_svg_with_options(&options),
+            r#"<path d="M0 0L0 4L4 4L4 0L0 0"/>
+<path d="M5 0L6 0L6 1L5 0"/>"#
+        );
+    }
+
+    #[test]
+    fn can_format_with_custom_formatter() {
+        let poly = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0 / 3.0, y: 0.0),
+            (x: 1.0 / 3.0, y: 2.5),
+        ];
+        let options = SvgWriteOptions {
+            formatter: Some(Arc::new(|value: f64| format!("{:.3}", value))),
+            significant_digits: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            poly.to_svg_with_options(&options),
+            r#"<path d="M0.000 0.000L0.333 0.000L0.333 2.500L0.000 0.000"/>"#
+        );
+        assert_eq!(
+            "M0.000 0.000L0.333 0.000L0.333 2.500L0.000 0.000",
+            poly.to_svg_string_with_options(&options)
+        );
+
+        // The built-in formatters write the same numbers as the matching options
+        let line = line_string![(x: 123456.789, y: 1.0 / 3.0), (x: -0.0001, y: 2.50)];
+        for (formatter, builtin) in [
+            (plain_formatter(), SvgWriteOptions::default()),
+            (
+                decimals_formatter(2),
+                SvgWriteOptions {
+                    decimals: Some(2),
+                    ..Default::default()
+                },
+            ),
+            (
+                significant_digits_formatter(3),
+                SvgWriteOptions {
+                    significant_digits: Some(3),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            let options = SvgWriteOptions {
+                formatter: Some(formatter),
+                ..Default::default()
+            };
+            assert_eq!(
+                line.to_svg_with_options(&builtin),
+                line.to_svg_with_options(&options)
+            );
+            assert_eq!(
+                line.to_svg_string_with_options(&builtin),
+                line.to_svg_string_with_options(&options)
+            );
+        }
+
+        // Invalid numbers fall back to the built-in formatting when validated
+        let options = SvgWriteOptions {
+            formatter: Some(Arc::new(|value: f64| {
+                format!("{:.3}", value).replace('.', ",")
+            })),
+            validate_formatter: true,
+            ..Default::default()
+        };
+        let svg_out = Line::new(Coordinate { x: 0.5, y: 1.0 }, Coordinate { x: 2.0, y: 3.0 })
+            .to_svg_with_options(&options);
+        assert_eq!(svg_out, r#"<line x1="0.5" x2="2" y1="1" y2="3"/>"#);
+    }
+
+    #[test]
+    fn can_validate_svg_numbers() {
+        for valid in ["0", "-1", "+2.5", ".5", "2.", "1e10", "1.5E-3", "-.5e+2"] {
+            assert!(is_svg_number(valid), "{}", valid);
+        }
+        for invalid in [
+            "", "-", ".", "1,5", "1 000", "1e", "e5", "1.2.3", "NaN", "inf",
+        ] {
+            assert!(!is_svg_number(invalid), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn can_format_whole_numbers_as_integers() {
+        let poly =
+            polygon![(x: 0.0, y: 0.0), (x: 16.0, y: 0.0), (x: 16.0, y: 1e21), (x: -8.0, y: 8.0)];
+        // A formatter writing a fixed number of decimals, which would write whole numbers with a
+        // decimal point
+        let options = SvgWriteOptions {
+            formatter: Some(Arc::new(|value: f64| format!("{:.2}", value))),
+            integer_when_whole: true,
+            polygon_elements: true,
+            ..Default::default()
+        };
+        let svg = poly.to_svg_with_options(&options);
+        assert!(!svg.contains('.'), "{}", svg);
+        assert_eq!(
+            svg,
+            r#"<polygon points="0,0 16,0 16,1000000000000000000000 -8,8"/>"#
+        );
+
+        // Numbers are checked after rounding, and the others are still formatted
+        let line = line_string![(x: 2.0004, y: -0.0004), (x: 2.5, y: 1.0 / 3.0)];
+        let svg = line.to_svg_with_options(&SvgWriteOptions {
+            decimals: Some(2),
+            ..options.clone()
+        });
+        assert_eq!(svg, r#"<polyline points="2,0 2.50,0.33"/>"#);
+        let svg = line.to_svg_with_options(&SvgWriteOptions {
+            significant_digits: Some(1),
+            integer_when_whole: true,
+            ..Default::default()
+        });
+        assert_eq!(svg, r#"<polyline points="2,-0.0004 2,0.3"/>"#);
+
+        // Integer coordinates are written exactly
+        let line = line_string![(x: 9007199254740993_i64, y: -1), (x: 0, y: 2)];
+        let svg = line.to_svg_with_options(&SvgWriteOptions {
+            integer_when_whole: true,
+            ..Default::default()
+        });
+        assert_eq!(svg, r#"<polyline points="9007199254740993,-1 0,2"/>"#);
+    }
+
+    #[test]
+    fn can_trim_trailing_zeros_of_coordinates() {
+        // A coordinate type writing a fixed number of decimals
+        struct Fixed(f64);
+        impl fmt::Display for Fixed {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{:.2}", self.0)
+            }
+        }
+
+        assert_eq!("60", trim_fraction(&Fixed(60.).to_string()));
+        assert_eq!("10.5", trim_fraction(&Fixed(10.5).to_string()));
+        assert_eq!("-0.25", trim_fraction(&Fixed(-0.25).to_string()));
+        assert_eq!("0", trim_fraction(&Fixed(0.).to_string()));
+        assert_eq!("60", trim_fraction(&60.0_f64.to_string()));
+        assert_eq!("10.5", trim_fraction(&10.50_f32.to_string()));
+        // Whole numbers and exponents have no fraction to trim
+        assert_eq!("100", trim_fraction("100"));
+        assert_eq!("1.50e2", trim_fraction("1.50e2"));
+
+        // Numbers too long to be trimmed on the stack are written all the same
+        assert_eq!("7.5", PlainNumber(7.5_f64).to_string());
+        assert_eq!(1e70_f64.to_string(), PlainNumber(1e70_f64).to_string());
+        let options = SvgWriteOptions {
+            integer_when_whole: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            1e70_f64.to_string(),
+            SvgNumber(1e70_f64, &options).to_string()
+        );
+
+        let line = line_string![(x: 60.0, y: 10.50), (x: 100.0, y: 0.0)];
+        assert_eq!(line.to_svg(), r#"<polyline points="60,10.5 100,0"/>"#);
+        assert_eq!(line.to_svg_string(), "M60 10.5L100 0");
+    }
+
+    #[test]
+    fn can_format_numbers_without_thousands_separators() {
+        // A coordinate type whose Display groups the digits of the integer part, e.g., `1,234,567`
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        struct Grouped(f64);
+        impl fmt::Display for Grouped {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let digits = format!("{}", self.0.abs().trunc());
+                let mut grouped = String::new();
+                for (i, digit) in digits.chars().enumerate() {
+                    if i > 0 && (digits.len() - i) % 3 == 0 {
+                        grouped.push(',');
+                    }
+                    grouped.push(digit);
+                }
+                let sign = if self.0 < 0. { "-" } else { "" };
+                let fraction = format!("{}", self.0.abs().fract());
+                write!(f, "{}{}{}", sign, grouped, fraction.trim_start_matches('0'))
+            }
+        }
+        macro_rules! grouped_op {
+            ($($op:ident $method:ident),*) => {$(
+                impl std::ops::$op for Grouped {
+                    type Output = Grouped;
+                    fn $method(self, other: Grouped) -> Grouped {
+                        Grouped(std::ops::$op::$method(self.0, other.0))
+                    }
+                }
+            )*};
+        }
+        grouped_op!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+        impl num_traits::Zero for Grouped {
+            fn zero() -> Self {
+                Grouped(0.)
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == 0.
+            }
+        }
+        impl num_traits::One for Grouped {
+            fn one() -> Self {
+                Grouped(1.)
+            }
+        }
+        impl num_traits::Num for Grouped {
+            type FromStrRadixErr = num_traits::ParseFloatError;
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                f64::from_str_radix(s, radix).map(Grouped)
+            }
+        }
+        impl num_traits::ToPrimitive for Grouped {
+            fn to_i64(&self) -> Option<i64> {
+                self.0.to_i64()
+            }
+            fn to_u64(&self) -> Option<u64> {
+                self.0.to_u64()
+            }
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0)
+            }
+        }
+        impl num_traits::NumCast for Grouped {
+            fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+                n.to_f64().map(Grouped)
+            }
+        }
+
+        assert_eq!("1,234,567.5", Grouped(1234567.5).to_string());
+        let line = LineString(vec![
+            Coordinate {
+                x: Grouped(1234567.5),
+                y: Grouped(-7654321.),
+            },
+            Coordinate {
+                x: Grouped(0.25),
+                y: Grouped(999.),
+            },
+        ]);
+        let options = SvgWriteOptions {
+            point_separator: PointSeparator::Space,
+            ..Default::default()
+        };
+        let svg = line.to_svg_with_options(&options);
+        assert_eq!(svg, r#"<polyline points="1234567.5 -7654321 0.25 999"/>"#);
+        assert_eq!(line.to_svg_string(), "M1234567.5 -7654321L0.25 999");
+        let view_box = Rect::new(line.0[0], line.0[1]);
+        assert!(svg_document(&line, &view_box, None)
+            .contains(r#"viewBox="0.25 -7654321 1234567.25 7655320""#));
+
+        // Large values of the built-in coordinate types never carry a comma either
+        let line = line_string![(x: 1234567.0, y: 12345678.9), (x: -1e15, y: 0.5)];
+        for options in [
+            SvgWriteOptions::default(),
+            SvgWriteOptions {
+                decimals: Some(2),
+                ..Default::default()
+            },
+            SvgWriteOptions {
+                significant_digits: Some(3),
+                ..Default::default()
+            },
+        ] {
+            let path = line.to_svg_with_options(&SvgWriteOptions {
+                point_separator: PointSeparator::Space,
+                ..options
+            });
+            assert!(!path.contains(','), "{}", path);
+        }
+        assert_eq!(
+            line.to_svg_string(),
+            "M1234567 12345678.9L-1000000000000000 0.5"
+        );
+        let line = line_string![(x: 1234567, y: -1234567), (x: 0, y: 0)];
+        assert_eq!(line.to_svg_string(), "M1234567 -1234567L0 0");
+    }
+
+    #[test]
+    fn can_format_multi_point() {
+        let multi_point = MultiPoint(vec![Point::new(0.0, 0.5), Point::new(10.0, -2.0)]);
+        let expected = String::from(
+            r#"<circle cx="0" cy="0.5" r="1"/>
+<circle cx="10" cy="-2" r="1"/>"#,
+        );
+        assert_eq!(expected, multi_point.to_svg());
+        assert_eq!("", MultiPoint(vec![] as Vec<Point<f64>>).to_svg());
+    }
+
+    #[test]
+    fn can_format_points_in_geometry() {
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(1.0, 2.0)),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(3.0, 4.0), Point::new(5.5, 6.0)])),
+        ]);
+        assert_eq!(
+            gc.to_svg(),
+            r#"<circle cx="1" cy="2" r="1"/>
+<circle cx="3" cy="4" r="1"/>
+<circle cx="5.5" cy="6" r="1"/>"#
+        );
+        assert_eq!(gc.to_svg_string(), "M1 2M3 4M5.5 6");
+
+        let empty = Geometry::MultiPoint(MultiPoint(vec![] as Vec<Point<f64>>));
+        assert_eq!(empty.to_svg(), "");
+        assert_eq!(empty.to_svg_string(), "");
+    }
+
+    #[test]
+    fn can_format_rect_and_triangle_in_geom_collection() {
+        let gc = GeometryCollection(vec![
+            Geometry::Rect(Rect::new(
+                Coordinate { x: 1.0, y: 2.0 },
+                Coordinate { x: 4.0, y: 6.5 },
+            )),
+            Geometry::Triangle(Triangle(
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 3.0, y: 0.0 },
+                Coordinate { x: 0.0, y: 4.0 },
+            )),
+            Geometry::Line(Line::new(
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 1.0 },
+            )),
+        ]);
+        assert_eq!(
+            gc.to_svg(),
+            r#"<rect x="1" y="2" width="3" height="4.5"/>
+<polygon points="0,0 3,0 0,4"/>
+<line x1="0" x2="1" y1="0" y2="1"/>"#
+        );
+        assert_eq!(
+            gc.to_svg_string(),
+            "M1 2L1 6.5L4 6.5L4 2ZM0 0L3 0L0 4ZM0 0L1 1"
+        );
+    }
+
+    #[test]
+    fn can_format_with_precision() {
+        let poly = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 59.99404, y: 0.00895),
+            (x: -0.0004, y: 2.0 / 3.0),
+        ];
+        assert_eq!(
+            poly.to_svg_with_precision(3),
+            r#"<path d="M0 0L59.994 0.009L0 0.667L0 0"/>"#
+        );
+        assert_eq!(
+            poly.to_svg_with_precision(0),
+            r#"<path d="M0 0L60 0L0 1L0 0"/>"#
+        );
+        // The default output is unchanged
+        assert_eq!(
+            poly.to_svg(),
+            r#"<path d="M0 0L59.99404 0.00895L-0.0004 0.6666666666666666L0 0"/>"#
+        );
+
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(1.23456, 2.0)),
+            Geometry::Line(Line::new(
+                Coordinate { x: 0.1111, y: 0.0 },
+                Coordinate { x: 1.0, y: 1.0 },
+            )),
+        ]);
+        assert_eq!(
+            gc.to_svg_with_precision(2),
+            r#"<circle cx="1.23" cy="2" r="1"/>
+<line x1="0.11" x2="1" y1="0" y2="1"/>"#
+        );
+    }
+
+    #[test]
+    fn can_format_polygon_with_closed_rings() {
+        let poly = polygon!(
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 0.0, y: 60.0),
+                (x: 60.0, y: 60.0),
+                (x: 60.0, y: 0.0),
+                (x: 0.0, y: 0.0),],
+            interiors: [[
+                (x: 10.0, y: 10.0),
+                (x: 40.0, y: 1.0),
+                (x: 40.0, y: 40.0),
+                (x: 10.0, y: 10.0),]]
+        );
+        let options = SvgWriteOptions {
+            close_rings: true,
+            ..Default::default()
+        };
+        let svg_out = poly.to_svg_with_options(&options);
+        assert_eq!(
+            svg_out,
+            r#"<path d="M0 0L0 60L60 60L60 0ZM10 10L40 1L40 40Z"/>"#
+        );
+
+        let parsed_svg = svg_to_geometry_collection(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(poly),
+            parsed_svg.ok().unwrap().0[0].clone().into_polygon()
+        );
+    }
+
+    #[test]
+    fn can_format_polygon_with_relative_commands() {
+        let poly = polygon!(
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 0.0, y: 60.0),
+                (x: 60.0, y: 60.0),
+                (x: 60.0, y: 0.0),
+                (x: 0.0, y: 0.0),],
+            interiors: [[
+                (x: 10.0, y: 10.0),
+                (x: 40.0, y: 1.0),
+                (x: 40.0, y: 40.0),
+                (x: 10.0, y: 10.0),]]
+        );
+        let mut options = SvgWriteOptions {
+            relative_commands: true,
+            ..Default::default()
+        };
+        let svg_out = poly.to_svg_with_options(&options);
+        assert_eq!(
+            svg_out,
+            r#"<path d="M0 0l0 60l60 0l0 -60l-60 0M10 10l30 -9l0 39l-30 -30"/>"#
+        );
+        let parsed_svg = svg_to_geometry_collection(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(poly.clone()),
+            parsed_svg.ok().unwrap().0[0].clone().into_polygon()
+        );
+
+        options.close_rings = true;
+        assert_eq!(
+            poly.to_svg_with_options(&options),
+            r#"<path d="M0 0l0 60l60 0l0 -60ZM10 10l30 -9l0 39Z"/>"#
+        );
 
-/** Points */
+        // Offsets are rounded from the rounded position, so the rounding does not accumulate
+        let poly = polygon![
+            (x: 0.4, y: 0.0),
+            (x: 0.8, y: 0.0),
+            (x: 1.2, y: 0.0),
+            (x: 1.6, y: 5.0),
+        ];
+        let options = SvgWriteOptions {
+            relative_commands: true,
+            decimals: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(
+            poly.to_svg_with_options(&options),
+            r#"<path d="M0 0l1 0l0 0l1 5l-2 -5"/>"#
+        );
+    }
 
-fn coord_to_svg<T: CoordNum + fmt::Display>(coord: &Coordinate<T>) -> String {
-    format!("{} {}", coord.x, coord.y)
-}
+    #[test]
+    fn can_format_svg_string_with_options() {
+        let geoms: Vec<Geometry<f64>> = vec![
+            polygon![
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 10.0),
+                (x: 0.0, y: 0.0),
+            ]
+            .into(),
+            line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 5.0, y: 5.0)].into(),
+            Line::new((1.0, 1.0), (1.0, 4.0)).into(),
+            Rect::new((0.0, 0.0), (2.0, 3.0)).into(),
+            Triangle((0.0, 0.0).into(), (4.0, 0.0).into(), (0.0, 3.0).into()).into(),
+            Point::new(1.5, 2.5).into(),
+        ];
+        for geom in &geoms {
+            assert_eq!(
+                geom.to_svg_string(),
+                geom.to_svg_string_with_options(&SvgWriteOptions::default())
+            );
+        }
 
-fn coord_to_svg_point<T: CoordNum + fmt::Display>(coord: &Coordinate<T>) -> String {
-    format!("{},{}", coord.x, coord.y)
-}
+        let options = SvgWriteOptions {
+            relative_commands: true,
+            axis_aligned_commands: true,
+            close_rings: true,
+            ..Default::default()
+        };
+        let svg_out = geoms
+            .iter()
+            .map(|geom| geom.to_svg_string_with_options(&options))
+            .collect::<Vec<String>>();
+        assert_eq!(
+            vec![
+                "M0 0h10v10Z",
+                "M0 0h10l-5 5",
+                "M1 1v3",
+                "M0 0v3h2v-3Z",
+                "M0 0h4l-4 3Z",
+                "M1.5 2.5",
+            ],
+            svg_out
+        );
+        let gc = GeometryCollection(geoms);
+        assert_eq!(svg_out.concat(), gc.to_svg_string_with_options(&options));
+
+        // Offsets back towards the origin do not underflow unsigned coordinates
+        let poly: Polygon<u32> = polygon![
+            (x: 10, y: 10),
+            (x: 20, y: 10),
+            (x: 20, y: 20),
+            (x: 5, y: 15),
+        ];
+        assert_eq!(
+            "M10 10h10v10l-15 -5Z",
+            poly.to_svg_string_with_options(&SvgWriteOptions {
+                relative_commands: true,
+                axis_aligned_commands: true,
+                close_rings: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            r#"<path d="M10 10l10 0l0 10l-15 -5l5 -5"/>"#,
+            poly.to_svg_with_options(&SvgWriteOptions {
+                relative_commands: true,
+                ..Default::default()
+            })
+        );
+    }
 
-/** Tests */
+    #[test]
+    fn can_format_polygon_as_polygon_element() {
+        let square = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 60.0, y: 0.0),
+            (x: 60.0, y: 60.0),
+            (x: 0.0, y: 60.0),
+            (x: 0.0, y: 0.0),
+        ];
+        let donut = polygon!(
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 60.0, y: 0.0),
+                (x: 60.0, y: 60.0),
+                (x: 0.0, y: 0.0),],
+            interiors: [[
+                (x: 40.0, y: 10.0),
+                (x: 50.0, y: 10.0),
+                (x: 50.0, y: 20.0),
+                (x: 40.0, y: 10.0),]]
+        );
+        let options = SvgWriteOptions {
+            polygon_elements: true,
+            point_separator: PointSeparator::Space,
+            ..Default::default()
+        };
+        let svg_out = square.to_svg_with_options(&options);
+        assert_eq!(svg_out, r#"<polygon points="0 0 60 0 60 60 0 60"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(square),
+            parsed_svg.ok().unwrap().0[0].clone().into_polygon()
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use geo_types::{line_string, polygon};
+        // A <polygon> cannot have holes
+        assert_eq!(
+            donut.to_svg_with_options(&options),
+            r#"<path d="M0 0L60 0L60 60L0 0M40 10L50 10L50 20L40 10"/>"#
+        );
+    }
 
     #[test]
-    fn can_format_geom_collection() {
-        let poly = Geometry::Polygon(polygon![
-            (x: 1.0, y: 1.0),
-            (x: 4.0, y: 1.0),
-            (x: 4.0, y: 4.0),
-            (x: 1.0, y: 4.0),
-            (x: 1.0, y: 1.0),
+    fn can_format_polygon_with_axis_aligned_commands() {
+        let staircase = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 20.0, y: 10.0),
+            (x: 20.0, y: 20.0),
+            (x: 5.0, y: 25.0),
+            (x: 0.0, y: 20.0),
+            (x: 0.0, y: 0.0),
+        ];
+        let mut options = SvgWriteOptions {
+            axis_aligned_commands: true,
+            ..Default::default()
+        };
+        let svg_out = staircase.to_svg_with_options(&options);
+        assert_eq!(svg_out, r#"<path d="M0 0H10V10H20V20L5 25L0 20V0"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(staircase.clone()),
+            parsed_svg.ok().unwrap().0[0].clone().into_polygon()
+        );
+
+        options.relative_commands = true;
+        options.close_rings = true;
+        let svg_out = staircase.to_svg_with_options(&options);
+        assert_eq!(svg_out, r#"<path d="M0 0h10v10h10v10l-15 5l-5 -5Z"/>"#);
+        let parsed_svg = svg_to_geometry_collection(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            Some(staircase),
+            parsed_svg.ok().unwrap().0[0].clone().into_polygon()
+        );
+
+        // Edges are axis-aligned once rounded
+        let poly = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.01), (x: 10.01, y: 10.0)];
+        options.decimals = Some(1);
+        assert_eq!(
+            poly.to_svg_with_options(&options),
+            r#"<path d="M0 0h10v10Z"/>"#
+        );
+    }
+
+    #[test]
+    fn can_format_with_combined_options() {
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(polygon!(
+                exterior: [
+                    (x: 0.0, y: 0.0),
+                    (x: 10.04, y: 0.0),
+                    (x: 10.04, y: 10.0),
+                    (x: 0.0, y: 10.0),
+                    (x: 0.0, y: 0.0),],
+                interiors: [[
+                    (x: 2.0, y: 2.0),
+                    (x: 2.0, y: 4.0),
+                    (x: 4.0, y: 2.0),
+                    (x: 2.0, y: 2.0),]]
+            )),
+            Geometry::Polygon(
+                polygon![(x: 20.0, y: 0.0), (x: 30.0, y: 0.0), (x: 20.0, y: 1.0 / 3.0)],
+            ),
+            Geometry::Point(Point::new(1.0 / 3.0, 0.0)),
         ]);
-        let line = Geometry::LineString(line_string![
-            (x: 11.0, y: 21.0),
-            (x: 34.0, y: 21.0),
-            (x: 24.0, y: 54.0),
-            (x: 31.50, y: 34.0),
+        let options = SvgWriteOptions {
+            marker_shape: MarkerShape::Square,
+            point_separator: PointSeparator::Space,
+            decimals: Some(1),
+            close_rings: true,
+            relative_commands: true,
+            axis_aligned_commands: true,
+            polygon_elements: true,
+            style: SvgStyle {
+                fill: Some("none".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = r#"<path d="M0 0h10v10h-10ZM2 2v2l2 -2Z" fill="none"/>
+<polygon points="20 0 30 0 20 0.3" fill="none"/>
+<rect x="-0.7" y="-1" width="2" height="2" fill="none"/>"#;
+        assert_eq!(gc.to_svg_with_options(&options), expected);
+
+        // The zero-config methods are the default options
+        for geom in gc.0.iter() {
+            assert_eq!(
+                geom.to_svg(),
+                geom.to_svg_with_options(&SvgWriteOptions::default())
+            );
+        }
+        assert_eq!(
+            gc.to_svg(),
+            gc.to_svg_with_options(&SvgWriteOptions::default())
+        );
+    }
+
+    #[test]
+    fn can_format_linestring_as_path() {
+        let line = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 5.5)];
+        let options = SvgWriteOptions {
+            linestring_as_path: true,
+            close_rings: true,
+            ..Default::default()
+        };
+        let svg_out = line.to_svg_with_options(&options);
+        assert_eq!(r#"<path d="M0 0L10 0L10 5.5"/>"#, svg_out);
+
+        // The open path is read back as a LineString, not a Polygon
+        let parsed_svg = svg_to_geometry_collection(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            vec![Geometry::LineString(line.clone())],
+            parsed_svg.ok().unwrap().0
+        );
+
+        let svg_out = MultiLineString(vec![line.clone(), line.clone()]).to_svg_with_options(
+            &SvgWriteOptions {
+                relative_commands: true,
+                axis_aligned_commands: true,
+                ..options.clone()
+            },
+        );
+        assert_eq!(
+            r#"<path d="M0 0h10v5.5"/>
+<path d="M0 0h10v5.5"/>"#,
+            svg_out
+        );
+
+        // A closed LineString is read back as a Polygon from a path, but not from a polyline
+        let ring =
+            line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 5.5), (x: 0.0, y: 0.0)];
+        let svg_out = ring.to_svg_with_options(&options);
+        assert_eq!(r#"<path d="M0 0L10 0L10 5.5L0 0"/>"#, svg_out);
+        let parsed_svg = svg_to_geometry_collection(&svg_out);
+        assert!(parsed_svg.is_ok());
+        assert_eq!(
+            vec![Geometry::Polygon(Polygon::new(ring.clone(), vec![]))],
+            parsed_svg.ok().unwrap().0
+        );
+        let parsed_svg = svg_to_geometry_collection(&ring.to_svg());
+        assert!(parsed_svg.is_ok());
+        assert_eq!(vec![Geometry::LineString(ring)], parsed_svg.ok().unwrap().0);
+    }
+
+    #[test]
+    fn can_format_polygon_with_normalized_winding() {
+        // A clockwise exterior (in y-up coordinates) with a clockwise hole
+        let poly = polygon!(
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 0.0, y: 10.0),
+                (x: 10.0, y: 10.0),
+                (x: 10.0, y: 0.0),
+                (x: 0.0, y: 0.0),],
+            interiors: [[
+                (x: 2.0, y: 2.0),
+                (x: 2.0, y: 4.0),
+                (x: 4.0, y: 2.0),
+                (x: 2.0, y: 2.0),]]
+        );
+        let ccw = SvgWriteOptions {
+            normalize_winding: Some(Winding::CounterClockwise),
+            ..Default::default()
+        };
+        assert_eq!(
+            poly.to_svg_with_options(&ccw),
+            r#"<path d="M0 0L10 0L10 10L0 10L0 0M2 2L2 4L4 2L2 2"/>"#
+        );
+        let cw = SvgWriteOptions {
+            normalize_winding: Some(Winding::Clockwise),
+            ..Default::default()
+        };
+        assert_eq!(
+            poly.to_svg_with_options(&cw),
+            r#"<path d="M0 0L0 10L10 10L10 0L0 0M2 2L4 2L2 4L2 2"/>"#
+        );
+        assert_eq!(Some(Winding::Clockwise), ring_winding(&poly.exterior().0));
+
+        // The other writers of Polygons follow the option too
+        let svg = MultiPolygon(vec![poly.clone()]).to_svg_with_options(&SvgWriteOptions {
+            polygon_elements: true,
+            ..ccw.clone()
+        });
+        assert_eq!(
+            svg,
+            r#"<path d="M0 0L10 0L10 10L0 10L0 0M2 2L2 4L4 2L2 2"/>"#
+        );
+        let svg =
+            Polygon::new(poly.exterior().clone(), vec![]).to_svg_with_options(&SvgWriteOptions {
+                polygon_elements: true,
+                ..ccw.clone()
+            });
+        assert_eq!(svg, r#"<polygon points="0,0 10,0 10,10 0,10"/>"#);
+        let triangle = Triangle(
+            Coordinate { x: 0, y: 0 },
+            Coordinate { x: 0, y: 3 },
+            Coordinate { x: 4, y: 0 },
+        );
+        let svg = triangle.to_svg_with_options(&SvgWriteOptions {
+            normalize_winding: Some(Winding::CounterClockwise),
+            ..Default::default()
+        });
+        assert_eq!(svg, r#"<polygon points="0,0 4,0 0,3"/>"#);
+
+        // Rings without area are kept as they are
+        let flat = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 5.0, y: 0.0)];
+        assert_eq!(flat.to_svg_with_options(&cw), flat.to_svg());
+    }
+
+    #[test]
+    fn can_compute_geometry_collection_bounds() {
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(polygon![
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 10.0),
+                (x: 0.0, y: 0.0),
+            ]),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Line(Line::new(
+                Coordinate { x: -5.0, y: 2.0 },
+                Coordinate { x: 3.0, y: 12.5 },
+            ))])),
         ]);
-        let gc = GeometryCollection(vec![line, poly]);
-        let wkt_out = gc.to_svg();
-        let expected = String::from(
-            r#"<polyline points="11,21 34,21 24,54 31.5,34"/>
-<path d="M1 1L4 1L4 4L1 4L1 1"/>"#,
+        assert_eq!(
+            Some(Rect::new(
+                Coordinate { x: -5.0, y: 0.0 },
+                Coordinate { x: 10.0, y: 12.5 }
+            )),
+            geometry_collection_bounds(&gc)
         );
-        assert_eq!(wkt_out, expected);
+        assert!(gc.to_svg_document().contains(r#"viewBox="-5 0 15 12.5""#));
+
+        let empty: GeometryCollection<f32> = GeometryCollection(vec![]);
+        assert_eq!(None, geometry_collection_bounds(&empty));
     }
 
     #[test]
-    fn can_format_empty_geom_collection() {
-        let gc = GeometryCollection(vec![] as Vec<Geometry<f64>>);
-        let wkt_out = gc.to_svg();
-        let expected = String::from("");
-        assert_eq!(wkt_out, expected);
+    fn can_format_svg_document_with_computed_view_box() {
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(-5.0, 3.0)),
+            Geometry::Rect(Rect::new(
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 10.0, y: 20.5 },
+            )),
+        ]);
+        assert_eq!(
+            gc.to_svg_document(),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-5 0 15 20.5">
+<circle cx="-5" cy="3" r="1"/>
+<rect x="0" y="0" width="10" height="20.5"/>
+</svg>"#
+        );
+        assert_eq!(
+            Geometry::Point(Point::new(-5.0, 3.0)).to_svg_document(),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-5 3 0 0">
+<circle cx="-5" cy="3" r="1"/>
+</svg>"#
+        );
+
+        // The document is read back as the same geometries
+        let parsed_svg = svg_document_to_geometry_collection(&gc.to_svg_document());
+        assert!(parsed_svg.is_ok());
+        assert_eq!(2, parsed_svg.ok().unwrap().0.len());
+
+        // Empty geometries have no extent
+        let gc = GeometryCollection(vec![Geometry::LineString(LineString::<f64>(vec![]))]);
+        assert_eq!(
+            gc.to_svg_document(),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 0 0"/>"#
+        );
     }
 
     #[test]
-    fn can_format_multi_polygon() {
-        let poly1 = polygon![
-            (x: 1.0, y: 1.0),
-            (x: 4.0, y: 1.0),
-            (x: 4.0, y: 4.0),
-            (x: 1.0, y: 4.0),
-            (x: 1.0, y: 1.0),
-        ];
-        let poly2 = polygon!(
-        exterior: [
-            (x: 0.0, y: 0.0),
-            (x: 6.0, y: 0.0),
-            (x: 6.0, y: 6.0),
-            (x: 0.0, y: 6.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[[
-            (x: 1.0, y: 1.0),
-            (x: 4.0, y: 1.0),
-            (x: 4.0, y: 4.0),
-            (x: 1.50, y: 4.0),
-            (x: 1.0, y: 1.0),]
-            ]
+    fn can_format_with_style() {
+        let style = SvgStyle {
+            fill: Some("url(#\"a&b\")".into()),
+            stroke_width: Some(2.5),
+            fill_rule: Some(FillRule::EvenOdd),
+            ..Default::default()
+        };
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0)]),
+            Geometry::Point(Point::new(1.0, 2.0)),
+            Geometry::Line(Line::new(
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 1.0 },
+            )),
+        ]);
+        assert_eq!(
+            gc.to_svg_styled(&style),
+            r#"<path d="M0 0L1 0L1 1L0 0" fill="url(#&quot;a&amp;b&quot;)" stroke-width="2.5" fill-rule="evenodd"/>
+<circle cx="1" cy="2" r="1" fill="url(#&quot;a&amp;b&quot;)" stroke-width="2.5" fill-rule="evenodd"/>
+<line x1="0" x2="1" y1="0" y2="1" fill="url(#&quot;a&amp;b&quot;)" stroke-width="2.5" fill-rule="evenodd"/>"#
         );
-        let mp = MultiPolygon(vec![poly1, poly2]);
-        let wkt_out = mp.to_svg();
-        let expected = String::from(
-            r#"<path d="M1 1L4 1L4 4L1 4L1 1"/>
-<path d="M0 0L6 0L6 6L0 6L0 0M1 1L4 1L4 4L1.5 4L1 1"/>"#,
+
+        // An empty style writes no attributes
+        assert_eq!(gc.to_svg_styled(&SvgStyle::default()), gc.to_svg());
+
+        let options = SvgWriteOptions {
+            marker_shape: MarkerShape::Cross,
+            style: SvgStyle {
+                stroke: Some("red".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            Point::new(1.0, 2.0).to_svg_with_options(&options),
+            r#"<path d="M0 2L2 2M1 1L1 3" stroke="red"/>"#
         );
-        assert_eq!(wkt_out, expected);
     }
 
     #[test]
-    fn can_format_multi_polygon_to_d_string() {
-        let poly1 = polygon![
-            (x: 1.0, y: 1.0),
-            (x: 4.0, y: 1.0),
-            (x: 4.0, y: 4.0),
-            (x: 1.0, y: 4.0),
-            (x: 1.0, y: 1.0),
-        ];
-        let poly2 = polygon!(
-        exterior: [
-            (x: 0.0, y: 0.0),
-            (x: 6.0, y: 0.0),
-            (x: 6.0, y: 6.0),
-            (x: 0.0, y: 6.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[[
-            (x: 1.0, y: 1.0),
-            (x: 4.0, y: 1.0),
-            (x: 4.0, y: 4.0),
-            (x: 1.50, y: 4.0),
-            (x: 1.0, y: 1.0),]
-            ]
+    fn can_write_svg_into_buffer() {
+        let gc = GeometryCollection(vec![
+            Geometry::MultiPolygon(MultiPolygon(vec![
+                polygon![(x: 0.0, y: 0.0), (x: 6.0, y: 0.0), (x: 6.0, y: 6.0)],
+                polygon![(x: 1.0, y: 1.0), (x: 4.0, y: 1.0), (x: 4.0, y: 4.0)],
+            ])),
+            Geometry::LineString(line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 5.0)]),
+            Geometry::Point(Point::new(2.0, 3.0)),
+            Geometry::Triangle(Triangle(
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 0.0 },
+                Coordinate { x: 0.0, y: 1.0 },
+            )),
+        ]);
+
+        // Writing appends to what the buffer already holds
+        let mut svg = String::from("<g>\n");
+        assert!(gc.write_svg(&mut svg).is_ok());
+        svg.push_str("\n</g>");
+        assert_eq!(svg, format!("<g>\n{}\n</g>", gc.to_svg()));
+
+        let options = SvgWriteOptions {
+            decimals: Some(0),
+            element_order: ElementOrder::AreaDescending,
+            ..Default::default()
+        };
+        let mut svg = String::new();
+        assert!(gc.write_svg_with_options(&mut svg, &options).is_ok());
+        assert_eq!(svg, gc.to_svg_with_options(&options));
+
+        // Geometries can be written through trait objects
+        let geoms: Vec<Box<dyn ToSvg<f64>>> =
+            vec![Box::new(gc.clone()), Box::new(Point::new(1., 2.))];
+        let mut svg = String::new();
+        for geom in &geoms {
+            assert!(geom.write_svg(&mut svg).is_ok());
+        }
+        assert_eq!(
+            svg,
+            format!("{}{}", gc.to_svg(), Point::new(1., 2.).to_svg())
         );
-        let mp = MultiPolygon(vec![poly1, poly2]);
-        let wkt_out = mp.to_svg_string();
-        let expected =
-            String::from("M1 1L4 1L4 4L1 4L1 1M0 0L6 0L6 6L0 6L0 0M1 1L4 1L4 4L1.5 4L1 1");
-        assert_eq!(wkt_out, expected);
     }
 
     #[test]
-    fn can_format_empty_multi_polygon() {
-        let mp = MultiPolygon(vec![] as Vec<Polygon<f64>>);
-        let wkt_out = mp.to_svg();
-        let expected = String::from("");
-        assert_eq!(wkt_out, expected);
+    fn can_write_svg_of_implementation_with_only_write_svg_with_options() {
+        struct Marker(Point<f64>);
+        impl ToSvg<f64> for Marker {
+            fn write_svg_with_options(
+                &self,
+                w: &mut dyn fmt::Write,
+                options: &SvgWriteOptions<f64>,
+            ) -> fmt::Result {
+                w.write_str("<g>")?;
+                self.0.write_svg_with_options(w, options)?;
+                w.write_str("</g>")
+            }
+        }
+
+        let marker = Marker(Point::new(1.5, 2.0));
+        let expected = r#"<g><circle cx="1.5" cy="2" r="1"/></g>"#;
+        assert_eq!(expected, marker.to_svg());
+        let mut svg = String::new();
+        assert!(marker.write_svg(&mut svg).is_ok());
+        assert_eq!(expected, svg);
+        assert_eq!(expected, marker.svg_display().to_string());
     }
 
     #[test]
-    fn can_format_polygon() {
-        let poly = polygon![
-            (x: 1.0, y: 1.0),
-            (x: 40.0, y: 1.0),
-            (x: 40.0, y: 40.0),
-            (x: 1.0, y: 40.0),
-            (x: 1.0, y: 1.0),
-        ];
-        let wkt_out = poly.to_svg();
-        let expected = String::from(r#"<path d="M1 1L40 1L40 40L1 40L1 1"/>"#);
-        assert_eq!(wkt_out, expected);
+    fn can_format_point_as_circle() {
+        let point = Point::new(10.0, 20.5);
+        let svg_out = point.to_svg();
+        let expected = String::from(r#"<circle cx="10" cy="20.5" r="1"/>"#);
+        assert_eq!(svg_out, expected);
     }
 
     #[test]
-    fn can_format_empty_polygon() {
-        let poly: Polygon<f64> =
-            Polygon::new(LineString::from(vec![] as Vec<Coordinate<f64>>), vec![]);
-        let wkt_out = poly.to_svg();
-        let expected = String::from("");
-        assert_eq!(wkt_out, expected);
+    fn can_format_geometry_with_display() {
+        let poly = polygon![(x: 1.0, y: 1.0), (x: 4.0, y: 1.0), (x: 4.0, y: 4.0)];
+        assert_eq!(poly.to_svg(), format!("{}", poly.svg_display()));
+
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(poly),
+            Geometry::Point(Point::new(2.0, 3.0)),
+        ]);
+        assert_eq!(
+            r#"<g><path d="M1 1L4 1L4 4L1 1"/>
+<circle cx="2" cy="3" r="1"/></g>"#,
+            format!("<g>{}</g>", gc.svg_display())
+        );
+
+        let mut svg = String::new();
+        assert!(write!(svg, "{}", Geometry::Point(Point::new(2, 3)).svg_display()).is_ok());
+        assert_eq!(r#"<circle cx="2" cy="3" r="1"/>"#, svg);
     }
 
     #[test]
-    fn can_format_polygon_with_hole() {
-        let poly = polygon!(
-        exterior: [
-            (x: 0.0, y: 0.0),
-            (x: 0.0, y: 60.0),
-            (x: 60.0, y: 60.0),
-            (x: 60.0, y: 0.0),
-            (x: 0.0, y: 0.0),],
-        interiors:[[
-            (x: 10.0, y: 10.0),
-            (x: 40.0, y: 1.0),
-            (x: 40.0, y: 40.0),
-            (x: 10.50, y: 40.0),
-            (x: 10.0, y: 10.0),]
-            ]
+    fn can_format_point_with_radius() {
+        let options = SvgWriteOptions {
+            point_radius: 2.5,
+            ..Default::default()
+        };
+        let point = Point::new(10.0, 20.5);
+        assert_eq!(
+            r#"<circle cx="10" cy="20.5" r="2.5"/>"#,
+            point.to_svg_with_options(&options)
         );
-        let wkt_out = poly.to_svg();
-        let expected =
-            String::from(r#"<path d="M0 0L0 60L60 60L60 0L0 0M10 10L40 1L40 40L10.5 40L10 10"/>"#);
-        assert_eq!(wkt_out, expected);
+
+        let collection = GeometryCollection(vec![
+            Geometry::Point(point),
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)])),
+        ]);
+        let svg_out = collection.to_svg_with_options(&options);
+        assert_eq!(3, svg_out.matches(r#"r="2.5""#).count());
+        assert!(!svg_out.contains(r#"r="1""#));
     }
 
     #[test]
-    fn can_format_multi_line_string() {
-        let line1 = line_string![
-            (x: 1.0, y: 1.0),
-            (x: 4.0, y: 1.0),
-            (x: 4.0, y: 4.0),
-            (x: 1.50, y: 4.0),
-        ];
-        let line2 = line_string![
-            (x: 11.0, y: 21.0),
-            (x: 34.0, y: 21.0),
-            (x: 24.0, y: 54.0),
-            (x: 31.50, y: 34.0),
-        ];
-        let ml = MultiLineString(vec![line1, line2]);
-        let wkt_out = ml.to_svg();
-        let expected = String::from(
-            r#"<polyline points="1,1 4,1 4,4 1.5,4"/>
-<polyline points="11,21 34,21 24,54 31.5,34"/>"#,
+    fn can_format_point_as_square() {
+        let point = Point::new(10.0, 20.5);
+        let options = SvgWriteOptions {
+            marker_shape: MarkerShape::Square,
+            point_radius: 2.0,
+            ..Default::default()
+        };
+        let svg_out = point.to_svg_with_options(&options);
+        let expected = String::from(r#"<rect x="8" y="18.5" width="4" height="4"/>"#);
+        assert_eq!(svg_out, expected);
+
+        // Corners left of or above the origin do not underflow unsigned coordinates
+        let options = SvgWriteOptions {
+            marker_shape: MarkerShape::Square,
+            ..Default::default()
+        };
+        assert_eq!(
+            Point::new(0u32, 5u32).to_svg_with_options(&options),
+            r#"<rect x="-1" y="4" width="2" height="2"/>"#
         );
-        assert_eq!(wkt_out, expected);
     }
 
     #[test]
-    fn can_format_empty_multi_line_string() {
-        let ml = MultiLineString(vec![] as Vec<LineString<f64>>);
-        let wkt_out = ml.to_svg();
-        let expected = String::from("");
-        assert_eq!(wkt_out, expected);
+    fn can_format_point_as_cross() {
+        let point = Point::new(10.0, 20.5);
+        let options = SvgWriteOptions {
+            marker_shape: MarkerShape::Cross,
+            point_radius: 2.0,
+            ..Default::default()
+        };
+        let svg_out = point.to_svg_with_options(&options);
+        let expected = String::from(r#"<path d="M8 20.5L12 20.5M10 18.5L10 22.5"/>"#);
+        assert_eq!(svg_out, expected);
+
+        // Ends left of or above the origin do not underflow unsigned coordinates
+        let options = SvgWriteOptions {
+            marker_shape: MarkerShape::Cross,
+            ..Default::default()
+        };
+        assert_eq!(
+            Point::new(0u32, 0u32).to_svg_with_options(&options),
+            r#"<path d="M-1 0L1 0M0 -1L0 1"/>"#
+        );
     }
 
     #[test]
-    fn can_format_line_string() {
+    fn can_render_into_template() {
         let line = line_string![
             (x: 1.0, y: 1.0),
             (x: 4.0, y: 1.0),
-            (x: 4.0, y: 4.0),
-            (x: 1.50, y: 4.0),
-            (x: 1.0, y: 1.0),
         ];
-        let wkt_out = line.to_svg();
-        let expected = String::from(r#"<polyline points="1,1 4,1 4,4 1.5,4 1,1"/>"#);
-        assert_eq!(wkt_out, expected);
+        let template = r#"<svg><style>polyline { stroke: red; }</style>$GEOM</svg>"#;
+        let svg_out = render_into_template(template, "$GEOM", &line);
+        assert!(svg_out.is_ok());
+        let expected = String::from(
+            r#"<svg><style>polyline { stroke: red; }</style><polyline points="1,1 4,1"/></svg>"#,
+        );
+        assert_eq!(svg_out.ok().unwrap(), expected);
     }
 
     #[test]
-    fn can_format_empty_line_string() {
-        let line = LineString::from(vec![] as Vec<Coordinate<f64>>);
-        let wkt_out = line.to_svg();
-        let expected = String::from("");
-        assert_eq!(wkt_out, expected);
+    fn render_into_template_errors_without_placeholder() {
+        let line = line_string![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+        ];
+        assert!(render_into_template("<svg></svg>", "$GEOM", &line).is_err());
+        assert!(render_into_template("<svg></svg>", "", &line).is_err());
     }
-
-    //TODO: add tests for Line, Triangle, and Rect
 }
@@ -1,2 +1,6 @@
+pub mod affine;
+pub mod attributes;
 pub mod geo_svg_reader;
-pub mod geo_svg_writer;
\ No newline at end of file
+pub mod geo_svg_writer;
+#[cfg(test)]
+mod round_trip;
@@ -0,0 +1,173 @@
+// Round-trip tests feeding the writer's output for a set of sample geometries back through the
+// reader, which must return the geometries unchanged
+
+use crate::geo_svg_reader::{svg_to_geometry_collection_with_options, ParseOptions};
+use crate::geo_svg_writer::{
+    FillRule, PointSeparator, SvgStyle, SvgWriteOptions, ToSvg, ToSvgString,
+};
+use geo_types::{
+    line_string, polygon, Coordinate, Geometry, GeometryCollection, Line, MultiLineString,
+    MultiPolygon, Rect, Triangle,
+};
+
+// Points are left out, as they are written as markers that are read back as Polygons
+fn sample_geometries() -> Vec<Geometry<f64>> {
+    vec![
+        Geometry::Line(Line::new(
+            Coordinate { x: 0.5, y: -1.0 },
+            Coordinate {
+                x: 10.0,
+                y: 1.0 / 3.0,
+            },
+        )),
+        Geometry::LineString(line_string![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 5.5),
+            (x: 20.0, y: -0.1),
+        ]),
+        Geometry::MultiLineString(MultiLineString(vec![
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0), (x: 2.0, y: 0.0)],
+            line_string![(x: 5.0, y: 5.0), (x: 6.0, y: 7.0), (x: 8.0, y: 5.0)],
+        ])),
+        Geometry::Polygon(polygon![
+            (x: 0.1, y: 0.2),
+            (x: 10.3, y: 0.2),
+            (x: 2.0 / 3.0, y: 7.7),
+        ]),
+        Geometry::Polygon(polygon!(
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 0.0, y: 60.0),
+                (x: 60.0, y: 60.0),
+                (x: 60.0, y: 0.0),
+                (x: 0.0, y: 0.0),],
+            interiors: [[
+                (x: 10.0, y: 10.0),
+                (x: 40.0, y: 1.0),
+                (x: 40.0, y: 40.0),
+                (x: 10.5, y: 40.0),
+                (x: 10.0, y: 10.0),]]
+        )),
+        Geometry::MultiPolygon(MultiPolygon(vec![
+            polygon![(x: 0.0, y: 0.0), (x: 30.0, y: 0.0), (x: 0.0, y: 30.0)],
+            polygon![(x: 50.0, y: 50.0), (x: 60.0, y: 50.0), (x: 50.0, y: 60.0)],
+        ])),
+        Geometry::Rect(Rect::new(
+            Coordinate { x: 1.5, y: 2.0 },
+            Coordinate { x: 10.0, y: 20.25 },
+        )),
+        Geometry::Triangle(Triangle(
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 4.0, y: 0.0 },
+            Coordinate { x: 0.0, y: 3.0 },
+        )),
+    ]
+}
+
+// SVG has no triangles, so a Triangle is read back as its Polygon
+fn expected_geometry(geom: &Geometry<f64>) -> Geometry<f64> {
+    match geom {
+        Geometry::Triangle(triangle) => Geometry::Polygon(triangle.to_polygon()),
+        _ => geom.clone(),
+    }
+}
+
+fn read_svg(svg: &str) -> Vec<Geometry<f64>> {
+    let options = ParseOptions {
+        prefer_rect: true,
+        ..Default::default()
+    };
+    let parsed_svg = svg_to_geometry_collection_with_options(svg, &options);
+    assert!(parsed_svg.is_ok(), "{}", svg);
+    parsed_svg.ok().unwrap().0
+}
+
+fn write_options() -> Vec<SvgWriteOptions<f64>> {
+    vec![
+        SvgWriteOptions::default(),
+        SvgWriteOptions {
+            close_rings: true,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            point_separator: PointSeparator::Space,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            relative_commands: true,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            relative_commands: true,
+            close_rings: true,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            axis_aligned_commands: true,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            axis_aligned_commands: true,
+            relative_commands: true,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            polygon_elements: true,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            integer_when_whole: true,
+            ..Default::default()
+        },
+        SvgWriteOptions {
+            style: SvgStyle {
+                fill: Some("none".into()),
+                stroke: Some("black".into()),
+                stroke_width: Some(0.5),
+                fill_rule: Some(FillRule::EvenOdd),
+            },
+            ..Default::default()
+        },
+    ]
+}
+
+/** Tests */
+
+#[test]
+fn can_round_trip_svg_elements() {
+    for options in write_options() {
+        for geom in sample_geometries() {
+            // Multi geometries are written as one element per member
+            let expected = match expected_geometry(&geom) {
+                Geometry::MultiLineString(multi_line) => {
+                    multi_line.0.into_iter().map(Geometry::LineString).collect()
+                }
+                Geometry::MultiPolygon(multi_polygon) => {
+                    multi_polygon.0.into_iter().map(Geometry::Polygon).collect()
+                }
+                expected => vec![expected],
+            };
+            let svg = geom.to_svg_with_options(&options);
+            let parsed = svg.lines().flat_map(read_svg).collect::<Vec<_>>();
+            if options.relative_commands {
+                // Summing relative offsets of decimals carries float rounding errors
+                assert_eq!(
+                    GeometryCollection(expected).to_svg_with_precision(9),
+                    GeometryCollection(parsed).to_svg_with_precision(9),
+                    "{}",
+                    svg
+                );
+            } else {
+                assert_eq!(expected, parsed, "{}", svg);
+            }
+        }
+    }
+}
+
+#[test]
+fn can_round_trip_svg_strings() {
+    for geom in sample_geometries() {
+        let svg = format!("<path d=\"{}\"/>", geom.to_svg_string());
+        assert_eq!(vec![expected_geometry(&geom)], read_svg(&svg), "{}", svg);
+    }
+}